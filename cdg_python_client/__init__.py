@@ -3,6 +3,37 @@ from .cdg_python_client import *
 
 __all__ = [
     "CDGPythonClient",
+    "AsyncCDGPythonClient",
+    "RetryStats",
+    "RateLimitStatus",
+    "CacheStats",
+    "Page",
+    "PageIterator",
+    "AsyncPageIterator",
+    "to_columns",
+    "to_arrow",
+    "to_pandas",
+    "BillType",
+    "AmendmentType",
+    "Chamber",
+    "LawType",
+    "VoteCast",
+    "PartyCode",
+    "ReportType",
+    "CommunicationType",
+    "BillId",
+    "LawId",
+    "AmendmentId",
+    "ReportId",
+    "CongressApiError",
+    "BadRequestError",
+    "AuthenticationError",
+    "NotFoundError",
+    "RateLimitError",
+    "ServerError",
+    "DecodeError",
+    "NetworkError",
+    "CassetteError",
     "Bill",
     "BillDetail",
     "LatestAction",
@@ -20,6 +51,26 @@ __all__ = [
     "TextVersion",
     "TextFormat",
     "Title",
+    "AmendmentDetail",
+    "AmendedBill",
+    "AmendedTreaty",
     "Congress",
     "Session",
+    "CommitteeMeeting",
+    "CommitteeMeetingDetail",
+    "MeetingLocation",
+    "Witness",
+    "WitnessDocument",
+    "MeetingDocument",
+    "MeetingVideo",
+    "MeetingRelatedItems",
+    "MeetingBill",
+    "MeetingNomination",
+    "MeetingTreaty",
+    "Communication",
+    "CommunicationDetail",
+    "CommunicationTypeInfo",
+    "CommunicationCommittee",
+    "MatchingRequirement",
+    "HouseRequirement",
 ]
@@ -4,23 +4,218 @@ Congress.gov API Python Client
 A Python client for the Congress.gov API, implemented in Rust using PyO3 for high performance.
 """
 
-from typing import Optional, List
+import os
+from datetime import date, datetime
+from enum import Enum
+from typing import Any, AsyncIterator, Callable, Dict, Generic, Iterable, Iterator, List, Optional, Tuple, Type, TypeVar, Union
 
-class LatestAction:
+# Dates come back as ``date``, timestamps as timezone-aware ``datetime``;
+# values that do not parse are left as the original string
+_Date = Union[date, datetime, str]
+
+# Date filters; naive datetimes are taken as UTC and a date covers the whole day
+_DateArg = Union[datetime, date, str]
+
+class BillType(str, Enum):
+    """Type of a bill or resolution.
+
+    Members are strings equal to their canonical ``value``, so
+    ``bill.bill_type == "HR"`` holds. Methods taking a bill type accept a
+    member or any spelling, case-insensitively, and so does calling the
+    class: ``BillType("hr") is BillType.HR``.
+    """
+    HR = "HR"
+    S = "S"
+    HJRES = "HJRES"
+    SJRES = "SJRES"
+    HCONRES = "HCONRES"
+    SCONRES = "SCONRES"
+    HRES = "HRES"
+    SRES = "SRES"
+
+class AmendmentType(str, Enum):
+    """Type of an amendment."""
+    HAMDT = "HAMDT"
+    SAMDT = "SAMDT"
+    SUAMDT = "SUAMDT"
+
+class Chamber(str, Enum):
+    """Chamber of Congress."""
+    HOUSE = "House"
+    SENATE = "Senate"
+    JOINT = "Joint"
+
+class LawType(str, Enum):
+    """Public or private law."""
+    PUBLIC = "Public Law"
+    PRIVATE = "Private Law"
+
+class VoteCast(str, Enum):
+    """How a member voted on a roll call."""
+    YEA = "Yea"
+    NAY = "Nay"
+    AYE = "Aye"
+    NO = "No"
+    PRESENT = "Present"
+    NOT_VOTING = "Not Voting"
+
+class PartyCode(str, Enum):
+    """Party code on a roll call vote, such as "D" or "R"."""
+    D = "D"
+    R = "R"
+    I = "I"
+    ID = "ID"
+    L = "L"
+
+class ReportType(str, Enum):
+    """Type of a committee report."""
+    HRPT = "HRPT"
+    SRPT = "SRPT"
+    ERPT = "ERPT"
+
+class CommunicationType(str, Enum):
+    """Type of a House or Senate communication."""
+    EC = "EC"
+    ML = "ML"
+    PM = "PM"
+    PT = "PT"
+    POM = "POM"
+
+class BillId:
+    """Identifies a bill or resolution.
+
+    Accepted by ``get_bill`` and the other bill methods in place of
+    ``congress, bill_type, bill_number``, as is a citation string.
+    """
+    congress: int
+    bill_type: BillType
+    number: int
+    
+    def __init__(self, congress: int, bill_type: Union[BillType, str], number: int) -> None: ...
+    @staticmethod
+    def parse(text: str, congress: Optional[int] = None) -> BillId:
+        """Parse "H.R. 1234", "hr1234-118", "S.J.Res. 5 (118th Congress)", a
+        congress.gov or API URL and similar forms. ``congress`` fills in a
+        citation that has none."""
+        ...
+    @property
+    def citation(self) -> str:
+        """Citation such as "H.R. 1234"."""
+        ...
+    @property
+    def slug(self) -> str:
+        """Short form such as "hr1234-118"."""
+        ...
+    @property
+    def url(self) -> str:
+        """Page on congress.gov."""
+        ...
+
+class AmendmentId:
+    """Identifies an amendment."""
+    congress: int
+    amendment_type: AmendmentType
+    number: int
+    
+    def __init__(self, congress: int, amendment_type: Union[AmendmentType, str], number: int) -> None: ...
+    @staticmethod
+    def parse(text: str, congress: Optional[int] = None) -> AmendmentId:
+        """Parse "S.Amdt. 45", "H.Amdt. 12 (118th Congress)", "samdt45-118",
+        a congress.gov or API URL and similar forms."""
+        ...
+    @property
+    def citation(self) -> str:
+        """Citation such as "S.Amdt. 45"."""
+        ...
+    @property
+    def slug(self) -> str:
+        """Short form such as "samdt45-118"."""
+        ...
+
+class LawId:
+    """Identifies a public or private law."""
+    congress: int
+    law_type: LawType
+    number: int
+    
+    def __init__(self, congress: int, law_type: Union[LawType, str], number: int) -> None: ...
+    @staticmethod
+    def parse(text: str) -> LawId:
+        """Parse "Pub. L. 118-5", "Public Law 118-5", "P.L. 118-5",
+        "Priv. L. 118-1", an API URL and similar forms."""
+        ...
+    @property
+    def citation(self) -> str:
+        """Citation such as "Pub. L. 118-5"."""
+        ...
+
+class ReportId:
+    """Identifies a committee report.
+
+    Accepted by ``get_committee_report`` and ``get_committee_report_text`` in
+    place of ``congress, report_type, report_number``, as is a citation string.
+    """
+    congress: int
+    report_type: ReportType
+    number: int
+    
+    def __init__(self, congress: int, report_type: Union[ReportType, str], number: int) -> None: ...
+    @staticmethod
+    def parse(text: str) -> ReportId:
+        """Parse "H. Rept. 118-12", "S. Rept. 118-3", "Ex. Rept. 118-1", a
+        congress.gov or API URL and similar forms."""
+        ...
+    @property
+    def citation(self) -> str:
+        """Citation such as "H. Rept. 118-12"."""
+        ...
+
+_M = TypeVar("_M", bound="_Model")
+
+class _Model:
+    """Methods shared by every model class."""
+    def to_dict(self) -> Dict[str, Any]:
+        """Fields as a JSON-compatible dict keyed by the API's names
+        (``latestAction``, ``updateDate``), dates as ISO 8601 strings."""
+        ...
+    def to_json(self, indent: Optional[int] = None) -> str:
+        """Fields as a JSON string keyed by the API's names."""
+        ...
+    @classmethod
+    def from_dict(cls: Type[_M], data: Dict[str, Any]) -> _M:
+        """Build from a dict shaped like the API's JSON, such as ``to_dict()``."""
+        ...
+    @classmethod
+    def from_json(cls: Type[_M], text: str) -> _M:
+        """Build from a JSON string shaped like the API's, such as ``to_json()``."""
+        ...
+    def __getstate__(self) -> str:
+        """The JSON the model was decoded from, or ``to_json()`` when it has none."""
+        ...
+    def __setstate__(self, state: str) -> None:
+        """Replace every field with those decoded from ``__getstate__()``."""
+        ...
+    def __reduce__(self) -> Tuple[Callable[[str], Any], Tuple[str], str]: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+
+class LatestAction(_Model):
     """Represents the latest action taken on a bill."""
-    action_date: Optional[str]
+    action_date: Optional[_Date]
     text: Optional[str]
+    extra: Dict[str, Any]
     
     def __repr__(self) -> str: ...
 
-class Law:
+class Law(_Model):
     """Represents a law number and type."""
     number: Optional[str]
-    law_type: Optional[str]
+    law_type: Optional[Union[LawType, str]]
+    extra: Dict[str, Any]
     
     def __repr__(self) -> str: ...
 
-class Sponsor:
+class Sponsor(_Model):
     """Represents a bill sponsor."""
     bioguide_id: Optional[str]
     first_name: Optional[str]
@@ -29,77 +224,134 @@ class Sponsor:
     state: Optional[str]
     party: Optional[str]
     url: Optional[str]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
     
     def __repr__(self) -> str: ...
 
-class PolicyArea:
+class PolicyArea(_Model):
     """Represents a policy area."""
     name: Optional[str]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
     
     def __repr__(self) -> str: ...
 
-class Bill:
+class Bill(_Model):
     """Represents a bill in Congress."""
     congress: Optional[int]
     latest_action: Optional[LatestAction]
     number: Optional[str]
-    origin_chamber: Optional[str]
+    origin_chamber: Optional[Union[Chamber, str]]
     origin_chamber_code: Optional[str]
     title: Optional[str]
-    bill_type: Optional[str]
-    update_date: Optional[str]
-    update_date_including_text: Optional[str]
+    bill_type: Optional[Union[BillType, str]]
+    update_date: Optional[_Date]
+    update_date_including_text: Optional[_Date]
     url: Optional[str]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
     
     def __repr__(self) -> str: ...
 
-class BillDetail:
+class BillDetail(_Model):
     """Represents detailed information about a bill."""
     congress: Optional[int]
     latest_action: Optional[LatestAction]
     number: Optional[str]
-    origin_chamber: Optional[str]
+    origin_chamber: Optional[Union[Chamber, str]]
     origin_chamber_code: Optional[str]
     title: Optional[str]
-    bill_type: Optional[str]
-    update_date: Optional[str]
-    update_date_including_text: Optional[str]
+    bill_type: Optional[Union[BillType, str]]
+    update_date: Optional[_Date]
+    update_date_including_text: Optional[_Date]
     url: Optional[str]
-    introduced_date: Optional[str]
+    introduced_date: Optional[_Date]
     sponsors: Optional[List[Sponsor]]
     policy_area: Optional[PolicyArea]
     laws: Optional[List[Law]]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
     
     def __repr__(self) -> str: ...
 
-class Action:
+class Action(_Model):
     """Represents an action taken on a bill."""
     action_code: Optional[str]
-    action_date: Optional[str]
+    action_date: Optional[_Date]
     text: Optional[str]
     action_type: Optional[str]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
     
     def __repr__(self) -> str: ...
 
-class Amendment:
+class Amendment(_Model):
     """Represents an amendment to a bill."""
     congress: Optional[int]
     latest_action: Optional[LatestAction]
     number: Optional[str]
-    amendment_type: Optional[str]
+    amendment_type: Optional[Union[AmendmentType, str]]
+    url: Optional[str]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
+    
+    def __repr__(self) -> str: ...
+
+class AmendedBill(_Model):
+    """The bill an amendment amends."""
+    congress: Optional[int]
+    number: Optional[str]
+    bill_type: Optional[Union[BillType, str]]
+    title: Optional[str]
+    origin_chamber: Optional[Union[Chamber, str]]
+    origin_chamber_code: Optional[str]
+    url: Optional[str]
+    extra: Dict[str, Any]
+    
+    def __repr__(self) -> str: ...
+
+class AmendedTreaty(_Model):
+    """The treaty an amendment amends."""
+    congress: Optional[int]
+    treaty_number: Optional[int]
     url: Optional[str]
+    extra: Dict[str, Any]
     
     def __repr__(self) -> str: ...
 
-class Committee:
+class AmendmentDetail(_Model):
+    """Represents detailed information about an amendment."""
+    congress: Optional[int]
+    number: Optional[str]
+    amendment_type: Optional[Union[AmendmentType, str]]
+    chamber: Optional[Union[Chamber, str]]
+    purpose: Optional[str]
+    description: Optional[str]
+    proposed_date: Optional[_Date]
+    submitted_date: Optional[_Date]
+    latest_action: Optional[LatestAction]
+    sponsors: Optional[List[Sponsor]]
+    amended_bill: Optional[AmendedBill]
+    amended_amendment: Optional[Amendment]
+    amended_treaty: Optional[AmendedTreaty]
+    update_date: Optional[_Date]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
+    
+    def __repr__(self) -> str: ...
+
+class Committee(_Model):
     """Represents a congressional committee."""
     name: Optional[str]
     system_code: Optional[str]
     url: Optional[str]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
     
     def __repr__(self) -> str: ...
 
-class Cosponsor:
+class Cosponsor(_Model):
     """Represents a bill cosponsor."""
     bioguide_id: Optional[str]
     first_name: Optional[str]
@@ -107,69 +359,218 @@ class Cosponsor:
     full_name: Optional[str]
     state: Optional[str]
     party: Optional[str]
-    sponsorship_date: Optional[str]
+    sponsorship_date: Optional[_Date]
     is_original_cosponsor: Optional[bool]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
     
     def __repr__(self) -> str: ...
 
-class RelationshipDetail:
+class RelationshipDetail(_Model):
     """Represents details about bill relationships."""
     identified_by: Optional[str]
     relationship_type: Optional[str]
+    extra: Dict[str, Any]
     
     def __repr__(self) -> str: ...
 
-class RelatedBill:
+class RelatedBill(_Model):
     """Represents a related bill."""
     congress: Optional[int]
     number: Optional[str]
-    bill_type: Optional[str]
+    bill_type: Optional[Union[BillType, str]]
     title: Optional[str]
     url: Optional[str]
     relationship_details: Optional[List[RelationshipDetail]]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
     
     def __repr__(self) -> str: ...
 
-class Subject:
+class Subject(_Model):
     """Represents a legislative subject."""
     name: Optional[str]
-    update_date: Optional[str]
+    update_date: Optional[_Date]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
     
     def __repr__(self) -> str: ...
 
-class Summary:
+class Summary(_Model):
     """Represents a bill summary."""
-    action_date: Optional[str]
+    action_date: Optional[_Date]
     action_desc: Optional[str]
     text: Optional[str]
-    update_date: Optional[str]
+    update_date: Optional[_Date]
     version_code: Optional[str]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
     
     def __repr__(self) -> str: ...
 
-class TextFormat:
+class TextFormat(_Model):
     """Represents a text format for bill text."""
     format_type: Optional[str]
     url: Optional[str]
+    extra: Dict[str, Any]
     
     def __repr__(self) -> str: ...
 
-class TextVersion:
+class TextVersion(_Model):
     """Represents a text version of a bill."""
-    date: Optional[str]
+    date: Optional[_Date]
     text_type: Optional[str]
     formats: Optional[List[TextFormat]]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
     
     def __repr__(self) -> str: ...
 
-class Title:
+class Title(_Model):
     """Represents a bill title."""
     title: Optional[str]
     title_type: Optional[str]
     title_type_code: Optional[int]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
+    
+    def __repr__(self) -> str: ...
+
+class CongressApiError(RuntimeError):
+    """
+    Base class for all errors raised by the client.
+    
+    Attributes:
+        status_code: HTTP status of the response, or None if there was none
+        endpoint: API path that was requested (e.g. "/bill/118/hr/1")
+        body: Raw response body returned by the API, if any
+        retry_after: Seconds the server (or local limiter) asked us to wait, if known
+    """
+    status_code: Optional[int]
+    endpoint: Optional[str]
+    body: Optional[str]
+    retry_after: Optional[float]
+
+class BadRequestError(CongressApiError):
+    """The API rejected the request parameters (HTTP 400/422)."""
+
+class AuthenticationError(CongressApiError):
+    """The API key is missing, invalid or expired (HTTP 401/403)."""
+
+class NotFoundError(CongressApiError):
+    """The requested resource does not exist (HTTP 404)."""
+
+class RateLimitError(CongressApiError):
+    """The server (HTTP 429) or the client-side limiter refused the request."""
+
+class ServerError(CongressApiError):
+    """The API failed with a 5xx status."""
+
+class DecodeError(CongressApiError):
+    """The response body could not be decoded into the expected model."""
+
+class NetworkError(CongressApiError):
+    """The request could not be sent or the response could not be read."""
+
+class CassetteError(CongressApiError):
+    """A request has no recorded response in replay mode, or a cassette could not be written."""
+
+class RetryStats:
+    """Snapshot of the client's retry counters."""
+    requests: int
+    retries: int
+    exhausted: int
+    
+    def __repr__(self) -> str: ...
+
+class RateLimitStatus:
+    """Quota reported by the API and the state of the local rate limiter."""
+    limit: Optional[int]
+    remaining: Optional[int]
+    seconds_since_update: Optional[float]
+    requests_sent: int
+    budget: Optional[int]
+    budget_remaining: Optional[int]
+    
+    def __repr__(self) -> str: ...
+
+class CacheStats:
+    """Snapshot of the on-disk response cache."""
+    directory: Optional[str]
+    hits: int
+    misses: int
+    revalidated: int
+    entries: int
+    size_bytes: int
+    
+    def __repr__(self) -> str: ...
+
+_T = TypeVar("_T")
+
+class Page(Generic[_T]):
+    """One page of a list endpoint together with its pagination metadata."""
+    items: List[_T]
+    count: Optional[int]
+    next: Optional[str]
+    prev: Optional[str]
+    next_offset: Optional[int]
+    request: Dict[str, str]
+    
+    def to_arrow(self) -> Any:
+        """The items as a ``pyarrow.RecordBatch``; see ``to_arrow``."""
+        ...
+    def to_pandas(self) -> Any:
+        """The items as a ``pandas.DataFrame``; see ``to_pandas``."""
+        ...
+    def __len__(self) -> int: ...
+    def __iter__(self) -> Iterator[_T]: ...
+    def __repr__(self) -> str: ...
+
+class PageIterator(Generic[_T]):
+    """
+    Iterator over every item of a list endpoint.
+    
+    Pages are requested only as items are consumed, following the API's
+    ``pagination.next`` link until the last page or ``max_items``.
+    """
+    pages_fetched: int
     
+    def __iter__(self) -> Iterator[_T]: ...
+    def __next__(self) -> _T: ...
     def __repr__(self) -> str: ...
 
+class AsyncPageIterator(Generic[_T]):
+    """
+    Async iterator over every item of a list endpoint.
+    
+    Behaves like ``PageIterator`` but is consumed with ``async for``.
+    """
+    pages_fetched: int
+    
+    def __aiter__(self) -> AsyncIterator[_T]: ...
+    async def __anext__(self) -> _T: ...
+    def __repr__(self) -> str: ...
+
+# Table export. Nested objects become dotted columns (``latestAction.actionDate``)
+# and lists are kept as JSON text. ``items`` may be a list result, a ``Page``,
+# a ``PageIterator`` or dicts such as ``get_raw`` results.
+
+def to_columns(items: Iterable[Union[_Model, Dict[str, Any]]]) -> Dict[str, List[Any]]:
+    """Flatten items into column name -> values; needs no extra packages."""
+    ...
+
+def to_arrow(items: Iterable[Union[_Model, Dict[str, Any]]]) -> Any:
+    """
+    Flatten items into a ``pyarrow.RecordBatch`` with typed columns.
+    
+    Requires ``pyarrow``. Use ``polars.from_arrow`` for a polars DataFrame.
+    """
+    ...
+
+def to_pandas(items: Iterable[Union[_Model, Dict[str, Any]]]) -> Any:
+    """``to_arrow(items).to_pandas()``; requires ``pandas`` and ``pyarrow``."""
+    ...
+
 class CDGPythonClient:
     """
     Client for interacting with the Congress.gov API.
@@ -181,12 +582,139 @@ class CDGPythonClient:
         >>> members = client.list_members(limit=10, current_member=True)
     """
     
-    def __init__(self, api_key: str) -> None:
+    def __init__(
+        self,
+        api_key: str,
+        max_retries: int = 3,
+        backoff_factor: float = 0.5,
+        max_backoff: float = 30.0,
+        rate_limit: Optional[int] = None,
+        rate_limit_period: float = 3600.0,
+        rate_limit_mode: str = "block",
+        base_url: Optional[str] = None,
+        timeout: Optional[float] = 30.0,
+        connect_timeout: Optional[float] = 10.0,
+        read_timeout: Optional[float] = None,
+        proxy: Optional[str] = None,
+        ca_bundle: Optional[Union[str, os.PathLike]] = None,
+        user_agent: Optional[str] = None,
+        cache_dir: Optional[Union[str, os.PathLike]] = None,
+        cache_ttl: Optional[float] = 86400.0,
+        cache_ttls: Optional[Dict[str, Optional[float]]] = None,
+        mode: str = "live",
+        cassette_dir: Optional[Union[str, os.PathLike]] = None,
+    ) -> None:
         """
         Initialize the Congress.gov API client.
         
+        Requests failing with HTTP 429, 500, 502, 503 or 504, or with a
+        connection error or timeout, are retried with exponential backoff
+        and jitter. A ``Retry-After`` header from the server is honoured;
+        if it asks for a wait longer than ``max_backoff`` the error is
+        raised immediately instead.
+        
+        Setting ``rate_limit`` enables a client-side token bucket that allows
+        that many HTTP requests (retries included) per ``rate_limit_period``
+        seconds. Once the budget is spent, requests either wait for it to
+        refill (``rate_limit_mode="block"``) or fail immediately
+        (``rate_limit_mode="raise"``).
+        
+        The API key is sent in the ``X-Api-Key`` header and never appears
+        in URLs or error messages.
+        
+        Setting ``cache_dir`` stores successful responses on disk, keyed on
+        endpoint and parameters (never the API key). Stale entries are
+        revalidated with ``If-None-Match``/``If-Modified-Since`` when the API
+        sent an ``ETag`` or ``Last-Modified`` header.
+        
+        ``mode="record"`` writes every response (API key redacted) to
+        ``cassette_dir``; ``mode="replay"`` serves them back without touching
+        the network, raising ``CassetteError`` for any request not on tape.
+        
         Args:
             api_key: Your Congress.gov API key
+            max_retries: Retries after the first attempt (0 disables retrying)
+            backoff_factor: Base delay in seconds; retry n waits about backoff_factor * 2**n
+            max_backoff: Maximum delay in seconds between two attempts
+            rate_limit: Local request budget per period (None disables the limiter)
+            rate_limit_period: Length of the budget period in seconds
+            rate_limit_mode: "block" to wait for budget, "raise" to fail fast
+            base_url: API root (default "https://api.congress.gov/v3"), e.g. a local mock server
+            timeout: Seconds allowed for a whole attempt, None for no limit
+            connect_timeout: Seconds allowed to establish a connection, None for no limit
+            read_timeout: Seconds allowed between two reads from the socket, None for no limit
+            proxy: Proxy URL for all requests; overrides the HTTP(S)_PROXY variables
+            ca_bundle: PEM file with additional root certificates to trust
+            user_agent: User-Agent header (default "cdg_python_client/<version>")
+            cache_dir: Directory for the response cache (None disables caching)
+            cache_ttl: Seconds a cached response stays fresh, None for ever
+            cache_ttls: Per-endpoint TTLs keyed by path prefix, e.g. {"/law": None}
+            mode: "live", "record" or "replay"
+            cassette_dir: Directory of recorded responses for record/replay
+        
+        Raises:
+            ValueError: If an option is invalid
+            OSError: If ``ca_bundle`` cannot be read or ``cache_dir`` created
+        """
+        ...
+    
+    def retry_stats(self) -> RetryStats:
+        """
+        Get counters describing how many requests were retried so far.
+        
+        Returns:
+            RetryStats with the number of requests, retries and requests
+            that failed after exhausting their retries
+        """
+        ...
+    
+    def rate_limit_status(self) -> RateLimitStatus:
+        """
+        Get the API quota and the state of the local rate limiter.
+        
+        ``limit`` and ``remaining`` come from the ``X-RateLimit-Limit`` and
+        ``X-RateLimit-Remaining`` headers of the most recent response that
+        carried them, and are None until such a response has been seen.
+        
+        Returns:
+            RateLimitStatus snapshot
+        """
+        ...
+    
+    def cache_stats(self) -> CacheStats:
+        """
+        Get hit/miss counters and the size of the response cache.
+        
+        Returns:
+            CacheStats snapshot (all zero when caching is disabled)
+        """
+        ...
+    
+    def clear_cache(self) -> int:
+        """
+        Delete every cached response.
+        
+        Returns:
+            Number of entries removed
+        """
+        ...
+    
+    def get_raw(self, path: str, params: Optional[Dict[str, Any]] = None) -> Any:
+        """
+        Fetch any endpoint and return its JSON as plain dicts and lists.
+        
+        Authentication, rate limiting, retries, caching and error mapping
+        work exactly as for the typed methods.
+        
+        Args:
+            path: Endpoint path relative to base_url (e.g., "/bill/118/hr/1/actions")
+            params: Query parameters; booleans are sent as "true"/"false" and None values are dropped
+            
+        Returns:
+            The decoded JSON response
+            
+        Raises:
+            ValueError: If path is a full URL or contains a query string
         """
         ...
     
@@ -195,8 +723,8 @@ class CDGPythonClient:
         format: Optional[str] = None,
         offset: Optional[int] = None,
         limit: Optional[int] = None,
-        from_date_time: Optional[str] = None,
-        to_date_time: Optional[str] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
     ) -> List[Bill]:
         """
         Get a list of bills sorted by date of latest action.
@@ -205,304 +733,551 @@ class CDGPythonClient:
             format: Response format (json or xml)
             offset: Offset for pagination
             limit: Number of results to return (max 250)
-            from_date_time: Start date-time filter (ISO 8601)
-            to_date_time: End date-time filter (ISO 8601)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
             
         Returns:
             List of Bill objects
         """
         ...
     
-    def list_bills_by_congress(
+    def iter_bills(
         self,
-        congress: int,
-        format: Optional[str] = None,
         offset: Optional[int] = None,
-        limit: Optional[int] = None,
-        from_date_time: Optional[str] = None,
-        to_date_time: Optional[str] = None,
-    ) -> List[Bill]:
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[Bill]:
         """
-        Get bills filtered by congress number.
+        Iterate over all bills sorted by date of latest action, fetching pages lazily.
         
         Args:
-            congress: Congress number (e.g., 118)
-            format: Response format (json or xml)
-            offset: Offset for pagination
-            limit: Number of results to return (max 250)
-            from_date_time: Start date-time filter (ISO 8601)
-            to_date_time: End date-time filter (ISO 8601)
+            offset: Offset of the first item (default 0)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
             
         Returns:
-            List of Bill objects
+            Lazy iterator over Bill objects
         """
         ...
     
-    def list_bills_by_type(
+    def list_bills_page(
         self,
-        congress: int,
-        bill_type: str,
         format: Optional[str] = None,
         offset: Optional[int] = None,
         limit: Optional[int] = None,
-        from_date_time: Optional[str] = None,
-        to_date_time: Optional[str] = None,
-    ) -> List[Bill]:
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> Page[Bill]:
         """
-        Get bills filtered by congress and bill type.
+        Get a list of bills sorted by date of latest action, returning a ``Page`` with pagination metadata.
         
         Args:
-            congress: Congress number (e.g., 118)
-            bill_type: Bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
             format: Response format (json or xml)
             offset: Offset for pagination
             limit: Number of results to return (max 250)
-            from_date_time: Start date-time filter (ISO 8601)
-            to_date_time: End date-time filter (ISO 8601)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
             
         Returns:
-            List of Bill objects
+            Page of Bill objects with the total count and next/prev links
         """
         ...
     
-    def get_bill(
+    def count_bills(
         self,
-        congress: int,
-        bill_type: str,
-        bill_number: int,
-    ) -> BillDetail:
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> int:
         """
-        Get detailed information for a specific bill.
+        Count bills with a single ``limit=1`` request.
         
         Args:
-            congress: Congress number (e.g., 118)
-            bill_type: Bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
-            bill_number: Bill number
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
             
         Returns:
-            BillDetail object with comprehensive bill information
+            Total number of matching items
         """
         ...
     
-    def get_bill_actions(
+    def list_bills_by_congress(
         self,
         congress: int,
-        bill_type: str,
-        bill_number: int,
         format: Optional[str] = None,
         offset: Optional[int] = None,
         limit: Optional[int] = None,
-    ) -> List[Action]:
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> List[Bill]:
         """
-        Get the list of actions on a specified bill.
+        Get bills filtered by congress number.
         
         Args:
             congress: Congress number (e.g., 118)
-            bill_type: Bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
-            bill_number: Bill number
             format: Response format (json or xml)
             offset: Offset for pagination
             limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
             
         Returns:
-            List of Action objects
+            List of Bill objects
         """
         ...
     
-    def get_bill_amendments(
+    def iter_bills_by_congress(
         self,
         congress: int,
-        bill_type: str,
-        bill_number: int,
-        format: Optional[str] = None,
         offset: Optional[int] = None,
-        limit: Optional[int] = None,
-    ) -> List[Amendment]:
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[Bill]:
         """
-        Get the list of amendments to a specified bill.
+        Iterate over all bills filtered by congress number, fetching pages lazily.
         
         Args:
             congress: Congress number (e.g., 118)
-            bill_type: Bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
-            bill_number: Bill number
-            format: Response format (json or xml)
-            offset: Offset for pagination
-            limit: Number of results to return (max 250)
+            offset: Offset of the first item (default 0)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
             
         Returns:
-            List of Amendment objects
+            Lazy iterator over Bill objects
         """
         ...
     
-    def get_bill_committees(
+    def list_bills_by_congress_page(
         self,
         congress: int,
-        bill_type: str,
-        bill_number: int,
         format: Optional[str] = None,
         offset: Optional[int] = None,
         limit: Optional[int] = None,
-    ) -> List[Committee]:
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> Page[Bill]:
         """
-        Get the list of committees associated with a specified bill.
+        Get bills filtered by congress number, returning a ``Page`` with pagination metadata.
         
         Args:
             congress: Congress number (e.g., 118)
-            bill_type: Bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
-            bill_number: Bill number
             format: Response format (json or xml)
             offset: Offset for pagination
             limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
             
         Returns:
-            List of Committee objects
+            Page of Bill objects with the total count and next/prev links
         """
         ...
     
-    def get_bill_cosponsors(
+    def count_bills_by_congress(
         self,
         congress: int,
-        bill_type: str,
-        bill_number: int,
-        format: Optional[str] = None,
-        offset: Optional[int] = None,
-        limit: Optional[int] = None,
-    ) -> List[Cosponsor]:
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> int:
         """
-        Get the list of cosponsors on a specified bill.
+        Count bills filtered by congress number with a single ``limit=1`` request.
         
         Args:
             congress: Congress number (e.g., 118)
-            bill_type: Bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
-            bill_number: Bill number
-            format: Response format (json or xml)
-            offset: Offset for pagination
-            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
             
         Returns:
-            List of Cosponsor objects
+            Total number of matching items
         """
         ...
     
-    def get_related_bills(
+    def list_bills_by_type(
         self,
         congress: int,
-        bill_type: str,
-        bill_number: int,
+        bill_type: Union[BillType, str],
         format: Optional[str] = None,
         offset: Optional[int] = None,
         limit: Optional[int] = None,
-    ) -> List[RelatedBill]:
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> List[Bill]:
         """
-        Get the list of related bills to a specified bill.
+        Get bills filtered by congress and bill type.
         
         Args:
             congress: Congress number (e.g., 118)
-            bill_type: Bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
-            bill_number: Bill number
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
             format: Response format (json or xml)
             offset: Offset for pagination
             limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
             
         Returns:
-            List of RelatedBill objects
+            List of Bill objects
         """
         ...
     
-    def get_bill_subjects(
+    def iter_bills_by_type(
         self,
         congress: int,
-        bill_type: str,
-        bill_number: int,
-        format: Optional[str] = None,
+        bill_type: Union[BillType, str],
         offset: Optional[int] = None,
-        limit: Optional[int] = None,
-    ) -> List[Subject]:
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[Bill]:
         """
-        Get the list of legislative subjects on a specified bill.
+        Iterate over all bills filtered by congress and bill type, fetching pages lazily.
         
         Args:
             congress: Congress number (e.g., 118)
-            bill_type: Bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
-            bill_number: Bill number
-            format: Response format (json or xml)
-            offset: Offset for pagination
-            limit: Number of results to return (max 250)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            offset: Offset of the first item (default 0)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
             
         Returns:
-            List of Subject objects
+            Lazy iterator over Bill objects
         """
         ...
     
-    def get_bill_summaries(
+    def list_bills_by_type_page(
         self,
         congress: int,
-        bill_type: str,
-        bill_number: int,
+        bill_type: Union[BillType, str],
         format: Optional[str] = None,
         offset: Optional[int] = None,
         limit: Optional[int] = None,
-    ) -> List[Summary]:
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> Page[Bill]:
         """
-        Get the list of summaries for a specified bill.
+        Get bills filtered by congress and bill type, returning a ``Page`` with pagination metadata.
         
         Args:
             congress: Congress number (e.g., 118)
-            bill_type: Bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
-            bill_number: Bill number
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
             format: Response format (json or xml)
             offset: Offset for pagination
             limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
             
         Returns:
-            List of Summary objects
+            Page of Bill objects with the total count and next/prev links
         """
         ...
     
-    def get_bill_text(
+    def count_bills_by_type(
         self,
         congress: int,
-        bill_type: str,
-        bill_number: int,
-        format: Optional[str] = None,
-        offset: Optional[int] = None,
-        limit: Optional[int] = None,
-    ) -> List[TextVersion]:
+        bill_type: Union[BillType, str],
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> int:
         """
-        Get the list of text versions for a specified bill.
+        Count bills filtered by congress and bill type with a single ``limit=1`` request.
         
         Args:
             congress: Congress number (e.g., 118)
-            bill_type: Bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
-            bill_number: Bill number
-            format: Response format (json or xml)
-            offset: Offset for pagination
-            limit: Number of results to return (max 250)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
             
         Returns:
-            List of TextVersion objects
+            Total number of matching items
         """
         ...
     
-    def get_bill_titles(
+    def get_bill(
         self,
-        congress: int,
-        bill_type: str,
-        bill_number: int,
-        format: Optional[str] = None,
-        offset: Optional[int] = None,
-        limit: Optional[int] = None,
-    ) -> List[Title]:
+        congress: Optional[int] = None,
+        bill_type: Optional[Union[BillType, str]] = None,
+        bill_number: Optional[int] = None,
+        *,
+        id: Optional[Union[BillId, str]] = None,
+    ) -> BillDetail:
         """
-        Get the list of titles for a specified bill.
+        Get detailed information for a specific bill.
         
         Args:
             congress: Congress number (e.g., 118)
-            bill_type: Bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
             bill_number: Bill number
-            format: Response format (json or xml)
+            id: A BillId or bill citation such as "H.R. 1 (118th Congress)", in place of
+                congress, type and number
+            
+        Returns:
+            BillDetail object with comprehensive bill information
+        """
+        ...
+    
+    def get_bill_actions(
+        self,
+        congress: Optional[int] = None,
+        bill_type: Optional[Union[BillType, str]] = None,
+        bill_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        *,
+        id: Optional[Union[BillId, str]] = None,
+    ) -> List[Action]:
+        """
+        Get the list of actions on a specified bill.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            bill_number: Bill number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            id: A BillId or bill citation such as "H.R. 1 (118th Congress)", in place of
+                congress, type and number
+            
+        Returns:
+            List of Action objects
+        """
+        ...
+    
+    def get_bill_amendments(
+        self,
+        congress: Optional[int] = None,
+        bill_type: Optional[Union[BillType, str]] = None,
+        bill_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        *,
+        id: Optional[Union[BillId, str]] = None,
+    ) -> List[Amendment]:
+        """
+        Get the list of amendments to a specified bill.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            bill_number: Bill number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            id: A BillId or bill citation such as "H.R. 1 (118th Congress)", in place of
+                congress, type and number
+            
+        Returns:
+            List of Amendment objects
+        """
+        ...
+    
+    def get_bill_committees(
+        self,
+        congress: Optional[int] = None,
+        bill_type: Optional[Union[BillType, str]] = None,
+        bill_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        *,
+        id: Optional[Union[BillId, str]] = None,
+    ) -> List[Committee]:
+        """
+        Get the list of committees associated with a specified bill.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            bill_number: Bill number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            id: A BillId or bill citation such as "H.R. 1 (118th Congress)", in place of
+                congress, type and number
+            
+        Returns:
+            List of Committee objects
+        """
+        ...
+    
+    def get_bill_cosponsors(
+        self,
+        congress: Optional[int] = None,
+        bill_type: Optional[Union[BillType, str]] = None,
+        bill_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        *,
+        id: Optional[Union[BillId, str]] = None,
+    ) -> List[Cosponsor]:
+        """
+        Get the list of cosponsors on a specified bill.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            bill_number: Bill number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            id: A BillId or bill citation such as "H.R. 1 (118th Congress)", in place of
+                congress, type and number
+            
+        Returns:
+            List of Cosponsor objects
+        """
+        ...
+    
+    def get_related_bills(
+        self,
+        congress: Optional[int] = None,
+        bill_type: Optional[Union[BillType, str]] = None,
+        bill_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        *,
+        id: Optional[Union[BillId, str]] = None,
+    ) -> List[RelatedBill]:
+        """
+        Get the list of related bills to a specified bill.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            bill_number: Bill number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            id: A BillId or bill citation such as "H.R. 1 (118th Congress)", in place of
+                congress, type and number
+            
+        Returns:
+            List of RelatedBill objects
+        """
+        ...
+    
+    def get_bill_subjects(
+        self,
+        congress: Optional[int] = None,
+        bill_type: Optional[Union[BillType, str]] = None,
+        bill_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        *,
+        id: Optional[Union[BillId, str]] = None,
+    ) -> List[Subject]:
+        """
+        Get the list of legislative subjects on a specified bill.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            bill_number: Bill number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            id: A BillId or bill citation such as "H.R. 1 (118th Congress)", in place of
+                congress, type and number
+            
+        Returns:
+            List of Subject objects
+        """
+        ...
+    
+    def get_bill_summaries(
+        self,
+        congress: Optional[int] = None,
+        bill_type: Optional[Union[BillType, str]] = None,
+        bill_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        *,
+        id: Optional[Union[BillId, str]] = None,
+    ) -> List[Summary]:
+        """
+        Get the list of summaries for a specified bill.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            bill_number: Bill number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            id: A BillId or bill citation such as "H.R. 1 (118th Congress)", in place of
+                congress, type and number
+            
+        Returns:
+            List of Summary objects
+        """
+        ...
+    
+    def get_bill_text(
+        self,
+        congress: Optional[int] = None,
+        bill_type: Optional[Union[BillType, str]] = None,
+        bill_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        *,
+        id: Optional[Union[BillId, str]] = None,
+    ) -> List[TextVersion]:
+        """
+        Get the list of text versions for a specified bill.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            bill_number: Bill number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            id: A BillId or bill citation such as "H.R. 1 (118th Congress)", in place of
+                congress, type and number
+            
+        Returns:
+            List of TextVersion objects
+        """
+        ...
+    
+    def get_bill_titles(
+        self,
+        congress: Optional[int] = None,
+        bill_type: Optional[Union[BillType, str]] = None,
+        bill_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        *,
+        id: Optional[Union[BillId, str]] = None,
+    ) -> List[Title]:
+        """
+        Get the list of titles for a specified bill.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            bill_number: Bill number
+            format: Response format (json or xml)
             offset: Offset for pagination
             limit: Number of results to return (max 250)
+            id: A BillId or bill citation such as "H.R. 1 (118th Congress)", in place of
+                congress, type and number
             
         Returns:
             List of Title objects
@@ -516,8 +1291,8 @@ class CDGPythonClient:
         format: Optional[str] = None,
         offset: Optional[int] = None,
         limit: Optional[int] = None,
-        from_date_time: Optional[str] = None,
-        to_date_time: Optional[str] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
     ) -> List[Amendment]:
         """
         Get a list of amendments sorted by date of latest action.
@@ -526,278 +1301,5298 @@ class CDGPythonClient:
             format: Response format (json or xml)
             offset: Offset for pagination
             limit: Number of results to return (max 250)
-            from_date_time: Start date-time filter (ISO 8601)
-            to_date_time: End date-time filter (ISO 8601)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
             
         Returns:
             List of Amendment objects
         """
         ...
     
-    def list_amendments_by_congress(
+    def iter_amendments(
+        self,
+        offset: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[Amendment]:
+        """
+        Iterate over all amendments sorted by date of latest action, fetching pages lazily.
+        
+        Args:
+            offset: Offset of the first item (default 0)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over Amendment objects
+        """
+        ...
+    
+    def list_amendments_page(
         self,
-        congress: int,
         format: Optional[str] = None,
         offset: Optional[int] = None,
         limit: Optional[int] = None,
-        from_date_time: Optional[str] = None,
-        to_date_time: Optional[str] = None,
-    ) -> List[Amendment]:
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> Page[Amendment]:
         """
-        Get amendments filtered by congress number.
+        Get a list of amendments sorted by date of latest action, returning a ``Page`` with pagination metadata.
         
         Args:
-            congress: Congress number (e.g., 118)
             format: Response format (json or xml)
             offset: Offset for pagination
             limit: Number of results to return (max 250)
-            from_date_time: Start date-time filter (ISO 8601)
-            to_date_time: End date-time filter (ISO 8601)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
             
         Returns:
-            List of Amendment objects
+            Page of Amendment objects with the total count and next/prev links
         """
         ...
     
-    # Member endpoints
+    def count_amendments(
+        self,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> int:
+        """
+        Count amendments with a single ``limit=1`` request.
+        
+        Args:
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
     
-    def list_members(
+    def list_amendments_by_congress(
         self,
+        congress: int,
         format: Optional[str] = None,
         offset: Optional[int] = None,
         limit: Optional[int] = None,
-        from_date_time: Optional[str] = None,
-        to_date_time: Optional[str] = None,
-        current_member: Optional[bool] = None,
-    ) -> List[Sponsor]:
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> List[Amendment]:
         """
-        Get a list of congressional members.
+        Get amendments filtered by congress number.
         
         Args:
+            congress: Congress number (e.g., 118)
             format: Response format (json or xml)
             offset: Offset for pagination
             limit: Number of results to return (max 250)
-            from_date_time: Start date-time filter (ISO 8601)
-            to_date_time: End date-time filter (ISO 8601)
-            current_member: Filter for current members only
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
             
         Returns:
-            List of Sponsor objects (representing members)
+            List of Amendment objects
         """
         ...
     
-    def get_member(self, bioguide_id: str) -> Sponsor:
+    def iter_amendments_by_congress(
+        self,
+        congress: int,
+        offset: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[Amendment]:
         """
-        Get detailed information for a specified congressional member.
+        Iterate over all amendments filtered by congress number, fetching pages lazily.
         
         Args:
-            bioguide_id: The Bioguide ID of the member
+            congress: Congress number (e.g., 118)
+            offset: Offset of the first item (default 0)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
             
         Returns:
-            Sponsor object with member information
+            Lazy iterator over Amendment objects
         """
         ...
     
-    def list_members_by_congress(
+    def list_amendments_by_congress_page(
         self,
         congress: int,
         format: Optional[str] = None,
         offset: Optional[int] = None,
         limit: Optional[int] = None,
-        current_member: Optional[bool] = None,
-    ) -> List[Sponsor]:
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> Page[Amendment]:
         """
-        Get the list of members by congress.
+        Get amendments filtered by congress number, returning a ``Page`` with pagination metadata.
         
         Args:
             congress: Congress number (e.g., 118)
             format: Response format (json or xml)
             offset: Offset for pagination
             limit: Number of results to return (max 250)
-            current_member: Filter for current members only
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
             
         Returns:
-            List of Sponsor objects (representing members)
+            Page of Amendment objects with the total count and next/prev links
         """
         ...
     
-    def get_member_sponsored_legislation(
+    def count_amendments_by_congress(
         self,
-        bioguide_id: str,
+        congress: int,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> int:
+        """
+        Count amendments filtered by congress number with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    def list_amendments_by_type(
+        self,
+        congress: int,
+        amendment_type: Union[AmendmentType, str],
         format: Optional[str] = None,
         offset: Optional[int] = None,
         limit: Optional[int] = None,
-    ) -> List[Bill]:
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> List[Amendment]:
         """
-        Get legislation sponsored by a specified member.
+        Get amendments filtered by congress and amendment type.
         
         Args:
-            bioguide_id: The Bioguide ID of the member
+            congress: Congress number (e.g., 118)
+            amendment_type: An AmendmentType or amendment type (hamdt, samdt, suamdt)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            List of Amendment objects
+        """
+        ...
+    
+    def iter_amendments_by_type(
+        self,
+        congress: int,
+        amendment_type: Union[AmendmentType, str],
+        offset: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[Amendment]:
+        """
+        Iterate over all amendments filtered by congress and amendment type, fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            amendment_type: An AmendmentType or amendment type (hamdt, samdt, suamdt)
+            offset: Offset of the first item (default 0)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over Amendment objects
+        """
+        ...
+    
+    def list_amendments_by_type_page(
+        self,
+        congress: int,
+        amendment_type: Union[AmendmentType, str],
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> Page[Amendment]:
+        """
+        Get amendments filtered by congress and amendment type, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            amendment_type: An AmendmentType or amendment type (hamdt, samdt, suamdt)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Page of Amendment objects with the total count and next/prev links
+        """
+        ...
+    
+    def count_amendments_by_type(
+        self,
+        congress: int,
+        amendment_type: Union[AmendmentType, str],
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> int:
+        """
+        Count amendments filtered by congress and amendment type with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            amendment_type: An AmendmentType or amendment type (hamdt, samdt, suamdt)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    def get_amendment(
+        self,
+        congress: Optional[int] = None,
+        amendment_type: Optional[Union[AmendmentType, str]] = None,
+        amendment_number: Optional[int] = None,
+        *,
+        id: Optional[Union[AmendmentId, str]] = None,
+    ) -> AmendmentDetail:
+        """
+        Get detailed information for a specific amendment.
+        
+        Args:
+            congress: Congress number (e.g., 118), or an AmendmentId or amendment citation
+                such as "S.Amdt. 2137 (117th Congress)" in place of all three arguments
+            amendment_type: An AmendmentType or amendment type (hamdt, samdt, suamdt)
+            amendment_number: Amendment number
+            
+        Returns:
+            AmendmentDetail with the purpose, sponsors, dates and amended bill or treaty
+        """
+        ...
+    
+    def get_amendment_actions(
+        self,
+        congress: Union[int, AmendmentId, str],
+        amendment_type: Optional[Union[AmendmentType, str]] = None,
+        amendment_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Action]:
+        """
+        Get the list of actions on a specified amendment.
+        
+        Args:
+            congress: Congress number (e.g., 118), or an AmendmentId or amendment citation
+                such as "S.Amdt. 2137 (117th Congress)" in place of all three arguments
+            amendment_type: An AmendmentType or amendment type (hamdt, samdt, suamdt)
+            amendment_number: Amendment number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Action objects
+        """
+        ...
+    
+    def get_amendment_cosponsors(
+        self,
+        congress: Union[int, AmendmentId, str],
+        amendment_type: Optional[Union[AmendmentType, str]] = None,
+        amendment_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Cosponsor]:
+        """
+        Get the list of cosponsors on a specified amendment.
+        
+        Args:
+            congress: Congress number (e.g., 118), or an AmendmentId or amendment citation
+                such as "S.Amdt. 2137 (117th Congress)" in place of all three arguments
+            amendment_type: An AmendmentType or amendment type (hamdt, samdt, suamdt)
+            amendment_number: Amendment number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Cosponsor objects
+        """
+        ...
+    
+    def get_amendment_amendments(
+        self,
+        congress: Union[int, AmendmentId, str],
+        amendment_type: Optional[Union[AmendmentType, str]] = None,
+        amendment_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Amendment]:
+        """
+        Get the list of amendments to a specified amendment.
+        
+        Args:
+            congress: Congress number (e.g., 118), or an AmendmentId or amendment citation
+                such as "S.Amdt. 2137 (117th Congress)" in place of all three arguments
+            amendment_type: An AmendmentType or amendment type (hamdt, samdt, suamdt)
+            amendment_number: Amendment number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Amendment objects
+        """
+        ...
+    
+    def get_amendment_text(
+        self,
+        congress: Union[int, AmendmentId, str],
+        amendment_type: Optional[Union[AmendmentType, str]] = None,
+        amendment_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[TextVersion]:
+        """
+        Get the list of text versions for a specified amendment (117th Congress onwards).
+        
+        Args:
+            congress: Congress number (e.g., 118), or an AmendmentId or amendment citation
+                such as "S.Amdt. 2137 (117th Congress)" in place of all three arguments
+            amendment_type: An AmendmentType or amendment type (hamdt, samdt, suamdt)
+            amendment_number: Amendment number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of TextVersion objects
+        """
+        ...
+    
+    # Member endpoints
+    
+    def list_members(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        current_member: Optional[bool] = None,
+    ) -> List[Sponsor]:
+        """
+        Get a list of congressional members.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            current_member: Filter for current members only
+            
+        Returns:
+            List of Sponsor objects (representing members)
+        """
+        ...
+    
+    def iter_members(
+        self,
+        offset: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        current_member: Optional[bool] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[Sponsor]:
+        """
+        Iterate over all congressional members, fetching pages lazily.
+        
+        Args:
+            offset: Offset of the first item (default 0)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            current_member: Filter for current members only
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over Sponsor objects (representing members)
+        """
+        ...
+    
+    def list_members_page(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        current_member: Optional[bool] = None,
+    ) -> Page[Sponsor]:
+        """
+        Get a list of congressional members, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            current_member: Filter for current members only
+            
+        Returns:
+            Page of Sponsor objects with the total count and next/prev links (representing members)
+        """
+        ...
+    
+    def count_members(
+        self,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        current_member: Optional[bool] = None,
+    ) -> int:
+        """
+        Count congressional members with a single ``limit=1`` request.
+        
+        Args:
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            current_member: Filter for current members only
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    def get_member(self, bioguide_id: str) -> Sponsor:
+        """
+        Get detailed information for a specified congressional member.
+        
+        Args:
+            bioguide_id: The Bioguide ID of the member
+            
+        Returns:
+            Sponsor object with member information
+        """
+        ...
+    
+    def list_members_by_congress(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        current_member: Optional[bool] = None,
+    ) -> List[Sponsor]:
+        """
+        Get the list of members by congress.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            current_member: Filter for current members only
+            
+        Returns:
+            List of Sponsor objects (representing members)
+        """
+        ...
+    
+    def iter_members_by_congress(
+        self,
+        congress: int,
+        offset: Optional[int] = None,
+        current_member: Optional[bool] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[Sponsor]:
+        """
+        Iterate over all members by congress, fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            offset: Offset of the first item (default 0)
+            current_member: Filter for current members only
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over Sponsor objects (representing members)
+        """
+        ...
+    
+    def list_members_by_congress_page(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        current_member: Optional[bool] = None,
+    ) -> Page[Sponsor]:
+        """
+        Get the list of members by congress, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            current_member: Filter for current members only
+            
+        Returns:
+            Page of Sponsor objects with the total count and next/prev links (representing members)
+        """
+        ...
+    
+    def count_members_by_congress(
+        self,
+        congress: int,
+        current_member: Optional[bool] = None,
+    ) -> int:
+        """
+        Count members by congress with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            current_member: Filter for current members only
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    def get_member_sponsored_legislation(
+        self,
+        bioguide_id: str,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Bill]:
+        """
+        Get legislation sponsored by a specified member.
+        
+        Args:
+            bioguide_id: The Bioguide ID of the member
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Bill objects
+        """
+        ...
+    
+    def get_member_cosponsored_legislation(
+        self,
+        bioguide_id: str,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Bill]:
+        """
+        Get legislation cosponsored by a specified member.
+        
+        Args:
+            bioguide_id: The Bioguide ID of the member
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Bill objects
+        """
+        ...
+    
+    def list_members_by_state(
+        self,
+        state_code: str,
+        format: Optional[str] = None,
+        limit: Optional[int] = None,
+        current_member: Optional[bool] = None,
+    ) -> List[Sponsor]:
+        """
+        Get the list of members by state.
+        
+        Args:
+            state_code: Two-letter state code (e.g., 'CA', 'NY')
+            format: Response format (json or xml)
+            limit: Number of results to return (max 250)
+            current_member: Filter for current members only
+            
+        Returns:
+            List of Sponsor objects (representing members)
+        """
+        ...
+    
+    def iter_members_by_state(
+        self,
+        state_code: str,
+        current_member: Optional[bool] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[Sponsor]:
+        """
+        Iterate over all members by state, fetching pages lazily.
+        
+        Args:
+            state_code: Two-letter state code (e.g., 'CA', 'NY')
+            current_member: Filter for current members only
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over Sponsor objects (representing members)
+        """
+        ...
+    
+    def list_members_by_state_page(
+        self,
+        state_code: str,
+        format: Optional[str] = None,
+        limit: Optional[int] = None,
+        current_member: Optional[bool] = None,
+    ) -> Page[Sponsor]:
+        """
+        Get the list of members by state, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            state_code: Two-letter state code (e.g., 'CA', 'NY')
+            format: Response format (json or xml)
+            limit: Number of results to return (max 250)
+            current_member: Filter for current members only
+            
+        Returns:
+            Page of Sponsor objects with the total count and next/prev links (representing members)
+        """
+        ...
+    
+    def count_members_by_state(
+        self,
+        state_code: str,
+        current_member: Optional[bool] = None,
+    ) -> int:
+        """
+        Count members by state with a single ``limit=1`` request.
+        
+        Args:
+            state_code: Two-letter state code (e.g., 'CA', 'NY')
+            current_member: Filter for current members only
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    def list_members_by_state_district(
+        self,
+        state_code: str,
+        district: int,
+        format: Optional[str] = None,
+        current_member: Optional[bool] = None,
+    ) -> List[Sponsor]:
+        """
+        Get the list of members by state and district.
+        
+        Args:
+            state_code: Two-letter state code (e.g., 'CA', 'NY')
+            district: Congressional district number
+            format: Response format (json or xml)
+            current_member: Filter for current members only
+            
+        Returns:
+            List of Sponsor objects (representing members)
+        """
+        ...
+    
+    def iter_members_by_state_district(
+        self,
+        state_code: str,
+        district: int,
+        current_member: Optional[bool] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[Sponsor]:
+        """
+        Iterate over all members by state and district, fetching pages lazily.
+        
+        Args:
+            state_code: Two-letter state code (e.g., 'CA', 'NY')
+            district: Congressional district number
+            current_member: Filter for current members only
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over Sponsor objects (representing members)
+        """
+        ...
+    
+    def list_members_by_state_district_page(
+        self,
+        state_code: str,
+        district: int,
+        format: Optional[str] = None,
+        current_member: Optional[bool] = None,
+    ) -> Page[Sponsor]:
+        """
+        Get the list of members by state and district, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            state_code: Two-letter state code (e.g., 'CA', 'NY')
+            district: Congressional district number
+            format: Response format (json or xml)
+            current_member: Filter for current members only
+            
+        Returns:
+            Page of Sponsor objects with the total count and next/prev links (representing members)
+        """
+        ...
+    
+    def count_members_by_state_district(
+        self,
+        state_code: str,
+        district: int,
+        current_member: Optional[bool] = None,
+    ) -> int:
+        """
+        Count members by state and district with a single ``limit=1`` request.
+        
+        Args:
+            state_code: Two-letter state code (e.g., 'CA', 'NY')
+            district: Congressional district number
+            current_member: Filter for current members only
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    # Committee endpoints
+    
+    def list_committees(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Committee]:
+        """
+        Get a list of committees.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Committee objects
+        """
+        ...
+    
+    def iter_committees(
+        self,
+        offset: Optional[int] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[Committee]:
+        """
+        Iterate over all committees, fetching pages lazily.
+        
+        Args:
+            offset: Offset of the first item (default 0)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over Committee objects
+        """
+        ...
+    
+    def list_committees_page(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> Page[Committee]:
+        """
+        Get a list of committees, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            Page of Committee objects with the total count and next/prev links
+        """
+        ...
+    
+    def count_committees(self) -> int:
+        """
+        Count committees with a single ``limit=1`` request.
+        
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    def get_committee_by_congress(
+        self,
+        congress: int,
+        chamber: Union[Chamber, str],
+        committee_code: str,
+        format: Optional[str] = None,
+    ) -> CommitteeDetailInfo:
+        """
+        Get detailed information about a committee as it stood in a given congress.
+        
+        Args:
+            congress: Congress number (e.g., 117)
+            chamber: A Chamber or chamber name (house, senate, joint)
+            committee_code: Committee system code (e.g., "hspw00")
+            format: Response format (json or xml)
+            
+        Returns:
+            CommitteeDetailInfo with history, subcommittees and links to
+            bills, reports and communications
+        """
+        ...
+    
+    def get_committee_reports(
+        self,
+        chamber: Union[Chamber, str],
+        committee_code: str,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        format: Optional[str] = None,
+    ) -> List[CommitteeReportItem]:
+        """
+        Get committee reports associated with a committee.
+        
+        Args:
+            chamber: A Chamber or chamber name (house, senate, joint)
+            committee_code: Committee system code (e.g., "hspw00")
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            format: Response format (json or xml)
+            
+        Returns:
+            List of CommitteeReportItem objects
+        """
+        ...
+    
+    def get_committee_nominations(
+        self,
+        chamber: Union[Chamber, str],
+        committee_code: str,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        format: Optional[str] = None,
+    ) -> List[Nomination]:
+        """
+        Get nominations referred to a committee.
+        
+        Args:
+            chamber: A Chamber or chamber name (house, senate, joint)
+            committee_code: Committee system code (e.g., "hspw00")
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            format: Response format (json or xml)
+            
+        Returns:
+            List of Nomination objects
+        """
+        ...
+    
+    def get_committee_house_communications(
+        self,
+        committee_code: str,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        format: Optional[str] = None,
+    ) -> List[Communication]:
+        """
+        Get House communications referred to a House committee.
+        
+        Args:
+            committee_code: Committee system code of a House committee (e.g., "hsif00")
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            format: Response format (json or xml)
+            
+        Returns:
+            List of Communication objects
+        """
+        ...
+    
+    def get_committee_senate_communications(
+        self,
+        committee_code: str,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        format: Optional[str] = None,
+    ) -> List[Communication]:
+        """
+        Get Senate communications referred to a Senate committee.
+        
+        Args:
+            committee_code: Committee system code of a Senate committee (e.g., "hsif00")
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            format: Response format (json or xml)
+            
+        Returns:
+            List of Communication objects
+        """
+        ...
+    
+    # Committee meeting endpoints
+    
+    def list_committee_meetings(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> List[CommitteeMeeting]:
+        """
+        Get a list of committee meetings.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            List of CommitteeMeeting objects
+        """
+        ...
+    
+    def iter_committee_meetings(
+        self,
+        offset: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[CommitteeMeeting]:
+        """
+        Iterate over all committee meetings, fetching pages lazily.
+        
+        Args:
+            offset: Offset of the first item (default 0)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over CommitteeMeeting objects
+        """
+        ...
+    
+    def list_committee_meetings_page(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> Page[CommitteeMeeting]:
+        """
+        Get a list of committee meetings, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Page of CommitteeMeeting objects with the total count and next/prev links
+        """
+        ...
+    
+    def count_committee_meetings(
+        self,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> int:
+        """
+        Count committee meetings with a single ``limit=1`` request.
+        
+        Args:
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    def list_committee_meetings_by_congress(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> List[CommitteeMeeting]:
+        """
+        Get committee meetings filtered by congress number.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            List of CommitteeMeeting objects
+        """
+        ...
+    
+    def iter_committee_meetings_by_congress(
+        self,
+        congress: int,
+        offset: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[CommitteeMeeting]:
+        """
+        Iterate over all committee meetings filtered by congress number, fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            offset: Offset of the first item (default 0)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over CommitteeMeeting objects
+        """
+        ...
+    
+    def list_committee_meetings_by_congress_page(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> Page[CommitteeMeeting]:
+        """
+        Get committee meetings filtered by congress number, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Page of CommitteeMeeting objects with the total count and next/prev links
+        """
+        ...
+    
+    def count_committee_meetings_by_congress(
+        self,
+        congress: int,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> int:
+        """
+        Count committee meetings filtered by congress number with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    def list_committee_meetings_by_chamber(
+        self,
+        congress: int,
+        chamber: Union[Chamber, str],
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> List[CommitteeMeeting]:
+        """
+        Get committee meetings filtered by congress and chamber.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            chamber: A Chamber or chamber name (house, senate, joint)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            List of CommitteeMeeting objects
+        """
+        ...
+    
+    def iter_committee_meetings_by_chamber(
+        self,
+        congress: int,
+        chamber: Union[Chamber, str],
+        offset: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[CommitteeMeeting]:
+        """
+        Iterate over all committee meetings filtered by congress and chamber, fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            chamber: A Chamber or chamber name (house, senate, joint)
+            offset: Offset of the first item (default 0)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over CommitteeMeeting objects
+        """
+        ...
+    
+    def list_committee_meetings_by_chamber_page(
+        self,
+        congress: int,
+        chamber: Union[Chamber, str],
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> Page[CommitteeMeeting]:
+        """
+        Get committee meetings filtered by congress and chamber, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            chamber: A Chamber or chamber name (house, senate, joint)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Page of CommitteeMeeting objects with the total count and next/prev links
+        """
+        ...
+    
+    def count_committee_meetings_by_chamber(
+        self,
+        congress: int,
+        chamber: Union[Chamber, str],
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> int:
+        """
+        Count committee meetings filtered by congress and chamber with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            chamber: A Chamber or chamber name (house, senate, joint)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    def get_committee_meeting(
+        self,
+        congress: int,
+        chamber: Union[Chamber, str],
+        event_id: Union[int, str],
+        format: Optional[str] = None,
+    ) -> CommitteeMeetingDetail:
+        """
+        Get detailed information for a specified committee meeting.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            chamber: A Chamber or chamber name (house, senate, joint)
+            event_id: Event id, such as ``hearing.associated_meeting.event_id``
+            format: Response format (json or xml)
+            
+        Returns:
+            CommitteeMeetingDetail with status, location, witnesses, documents,
+            related items and videos
+        """
+        ...
+    
+    # House communication endpoints
+    
+    def list_house_communications(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Communication]:
+        """
+        Get a list of House communications.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Communication objects
+        """
+        ...
+    
+    def iter_house_communications(
+        self,
+        offset: Optional[int] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[Communication]:
+        """
+        Iterate over all House communications, fetching pages lazily.
+        
+        Args:
+            offset: Offset of the first item (default 0)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over Communication objects
+        """
+        ...
+    
+    def list_house_communications_page(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> Page[Communication]:
+        """
+        Get a list of House communications, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            Page of Communication objects with the total count and next/prev links
+        """
+        ...
+    
+    def count_house_communications(self) -> int:
+        """
+        Count House communications with a single ``limit=1`` request.
+        
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    def list_house_communications_by_congress(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Communication]:
+        """
+        Get House communications filtered by congress number.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Communication objects
+        """
+        ...
+    
+    def iter_house_communications_by_congress(
+        self,
+        congress: int,
+        offset: Optional[int] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[Communication]:
+        """
+        Iterate over all House communications filtered by congress number, fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            offset: Offset of the first item (default 0)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over Communication objects
+        """
+        ...
+    
+    def list_house_communications_by_congress_page(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> Page[Communication]:
+        """
+        Get House communications filtered by congress number, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            Page of Communication objects with the total count and next/prev links
+        """
+        ...
+    
+    def count_house_communications_by_congress(
+        self,
+        congress: int,
+    ) -> int:
+        """
+        Count House communications filtered by congress number with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    def list_house_communications_by_type(
+        self,
+        congress: int,
+        communication_type: Union[CommunicationType, str],
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Communication]:
+        """
+        Get House communications filtered by congress and communication type.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            communication_type: A CommunicationType or type code (ec, ml, pm, pt)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Communication objects
+        """
+        ...
+    
+    def iter_house_communications_by_type(
+        self,
+        congress: int,
+        communication_type: Union[CommunicationType, str],
+        offset: Optional[int] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[Communication]:
+        """
+        Iterate over all House communications filtered by congress and communication type, fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            communication_type: A CommunicationType or type code (ec, ml, pm, pt)
+            offset: Offset of the first item (default 0)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over Communication objects
+        """
+        ...
+    
+    def list_house_communications_by_type_page(
+        self,
+        congress: int,
+        communication_type: Union[CommunicationType, str],
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> Page[Communication]:
+        """
+        Get House communications filtered by congress and communication type, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            communication_type: A CommunicationType or type code (ec, ml, pm, pt)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            Page of Communication objects with the total count and next/prev links
+        """
+        ...
+    
+    def count_house_communications_by_type(
+        self,
+        congress: int,
+        communication_type: Union[CommunicationType, str],
+    ) -> int:
+        """
+        Count House communications filtered by congress and communication type with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            communication_type: A CommunicationType or type code (ec, ml, pm, pt)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    def get_house_communication(
+        self,
+        congress: int,
+        communication_type: Union[CommunicationType, str],
+        communication_number: int,
+        format: Optional[str] = None,
+    ) -> CommunicationDetail:
+        """
+        Get detailed information for a specified House communication.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            communication_type: A CommunicationType or type code (ec, ml, pm, pt)
+            communication_number: Communication number (e.g., 3324)
+            format: Response format (json or xml)
+            
+        Returns:
+            CommunicationDetail with the abstract, referral committees and
+            congressional record date
+        """
+        ...
+    
+    # Senate communication endpoints
+    
+    def list_senate_communications(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Communication]:
+        """
+        Get a list of Senate communications.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Communication objects
+        """
+        ...
+    
+    def iter_senate_communications(
+        self,
+        offset: Optional[int] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[Communication]:
+        """
+        Iterate over all Senate communications, fetching pages lazily.
+        
+        Args:
+            offset: Offset of the first item (default 0)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over Communication objects
+        """
+        ...
+    
+    def list_senate_communications_page(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> Page[Communication]:
+        """
+        Get a list of Senate communications, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            Page of Communication objects with the total count and next/prev links
+        """
+        ...
+    
+    def count_senate_communications(self) -> int:
+        """
+        Count Senate communications with a single ``limit=1`` request.
+        
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    def list_senate_communications_by_congress(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Communication]:
+        """
+        Get Senate communications filtered by congress number.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Communication objects
+        """
+        ...
+    
+    def iter_senate_communications_by_congress(
+        self,
+        congress: int,
+        offset: Optional[int] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[Communication]:
+        """
+        Iterate over all Senate communications filtered by congress number, fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            offset: Offset of the first item (default 0)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over Communication objects
+        """
+        ...
+    
+    def list_senate_communications_by_congress_page(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> Page[Communication]:
+        """
+        Get Senate communications filtered by congress number, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            Page of Communication objects with the total count and next/prev links
+        """
+        ...
+    
+    def count_senate_communications_by_congress(
+        self,
+        congress: int,
+    ) -> int:
+        """
+        Count Senate communications filtered by congress number with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    def list_senate_communications_by_type(
+        self,
+        congress: int,
+        communication_type: Union[CommunicationType, str],
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Communication]:
+        """
+        Get Senate communications filtered by congress and communication type.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            communication_type: A CommunicationType or type code (ec, pm, pom)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Communication objects
+        """
+        ...
+    
+    def iter_senate_communications_by_type(
+        self,
+        congress: int,
+        communication_type: Union[CommunicationType, str],
+        offset: Optional[int] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[Communication]:
+        """
+        Iterate over all Senate communications filtered by congress and communication type, fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            communication_type: A CommunicationType or type code (ec, pm, pom)
+            offset: Offset of the first item (default 0)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over Communication objects
+        """
+        ...
+    
+    def list_senate_communications_by_type_page(
+        self,
+        congress: int,
+        communication_type: Union[CommunicationType, str],
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> Page[Communication]:
+        """
+        Get Senate communications filtered by congress and communication type, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            communication_type: A CommunicationType or type code (ec, pm, pom)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            Page of Communication objects with the total count and next/prev links
+        """
+        ...
+    
+    def count_senate_communications_by_type(
+        self,
+        congress: int,
+        communication_type: Union[CommunicationType, str],
+    ) -> int:
+        """
+        Count Senate communications filtered by congress and communication type with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            communication_type: A CommunicationType or type code (ec, pm, pom)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    def get_senate_communication(
+        self,
+        congress: int,
+        communication_type: Union[CommunicationType, str],
+        communication_number: int,
+        format: Optional[str] = None,
+    ) -> CommunicationDetail:
+        """
+        Get detailed information for a specified Senate communication.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            communication_type: A CommunicationType or type code (ec, pm, pom)
+            communication_number: Communication number (e.g., 3324)
+            format: Response format (json or xml)
+            
+        Returns:
+            CommunicationDetail with the abstract, referral committees and
+            congressional record date
+        """
+        ...
+    
+    # House requirement endpoints
+    
+    def list_house_requirements(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[HouseRequirement]:
+        """
+        Get a list of House requirements.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of HouseRequirement objects
+        """
+        ...
+    
+    def iter_house_requirements(
+        self,
+        offset: Optional[int] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[HouseRequirement]:
+        """
+        Iterate over all House requirements, fetching pages lazily.
+        
+        Args:
+            offset: Offset of the first item (default 0)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over HouseRequirement objects
+        """
+        ...
+    
+    def list_house_requirements_page(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> Page[HouseRequirement]:
+        """
+        Get a list of House requirements, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            Page of HouseRequirement objects with the total count and next/prev links
+        """
+        ...
+    
+    def count_house_requirements(self) -> int:
+        """
+        Count House requirements with a single ``limit=1`` request.
+        
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    def get_house_requirement(
+        self,
+        requirement_number: int,
+        format: Optional[str] = None,
+    ) -> HouseRequirement:
+        """
+        Get detailed information for a specified House requirement.
+        
+        Args:
+            requirement_number: Requirement number (e.g., 8070)
+            format: Response format (json or xml)
+            
+        Returns:
+            HouseRequirement with legal authority, frequency, nature and
+            submitting and parent agencies
+        """
+        ...
+    
+    def get_house_requirement_matching_communications(
+        self,
+        requirement_number: int,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        format: Optional[str] = None,
+    ) -> List[Communication]:
+        """
+        Get the communications filed under a House requirement.
+        
+        Args:
+            requirement_number: Requirement number (e.g., 8070)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            format: Response format (json or xml)
+            
+        Returns:
+            List of Communication objects
+        """
+        ...
+    
+    # Congress/Session endpoints
+    
+    def list_congresses(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Congress]:
+        """
+        Get a list of congresses and congressional sessions.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Congress objects
+        """
+        ...
+    
+    def iter_congresses(
+        self,
+        offset: Optional[int] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[Congress]:
+        """
+        Iterate over all congresses and congressional sessions, fetching pages lazily.
+        
+        Args:
+            offset: Offset of the first item (default 0)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over Congress objects
+        """
+        ...
+    
+    def list_congresses_page(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> Page[Congress]:
+        """
+        Get a list of congresses and congressional sessions, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            Page of Congress objects with the total count and next/prev links
+        """
+        ...
+    
+    def count_congresses(self) -> int:
+        """
+        Count congresses and congressional sessions with a single ``limit=1`` request.
+        
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    def get_congress(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+    ) -> Congress:
+        """
+        Get information about a specific congress.
+        
+        Args:
+            congress: The congress number (e.g., 117)
+            format: Response format (json or xml)
+            
+        Returns:
+            Congress object
+        """
+        ...
+    
+    def get_current_congress(
+        self,
+        format: Optional[str] = None,
+    ) -> Congress:
+        """
+        Get information about the current congress.
+        
+        Args:
+            format: Response format (json or xml)
+            
+        Returns:
+            Congress object
+        """
+        ...
+    
+    # House Vote Operations (BETA)
+    
+    def list_house_votes(
+        self,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
+        format: Optional[str] = None,
+    ) -> List[HouseVote]:
+        """
+        Get a list of house votes (BETA).
+        
+        Args:
+            offset: Offset for pagination
+            limit: Maximum number of results
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
+            format: Response format (json or xml)
+            
+        Returns:
+            List of HouseVote objects
+        """
+        ...
+    
+    def iter_house_votes(
+        self,
+        offset: Optional[int] = None,
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[HouseVote]:
+        """
+        Iterate over all house votes (BETA), fetching pages lazily.
+        
+        Args:
+            offset: Offset of the first item (default 0)
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over HouseVote objects
+        """
+        ...
+    
+    def list_house_votes_page(
+        self,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
+        format: Optional[str] = None,
+    ) -> Page[HouseVote]:
+        """
+        Get a list of house votes (BETA), returning a ``Page`` with pagination metadata.
+        
+        Args:
+            offset: Offset for pagination
+            limit: Maximum number of results
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
+            format: Response format (json or xml)
+            
+        Returns:
+            Page of HouseVote objects with the total count and next/prev links
+        """
+        ...
+    
+    def count_house_votes(
+        self,
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
+    ) -> int:
+        """
+        Count house votes (BETA) with a single ``limit=1`` request.
+        
+        Args:
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    def list_house_votes_by_congress(
+        self,
+        congress: int,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
+        format: Optional[str] = None,
+    ) -> List[HouseVote]:
+        """
+        Get house votes for a specific congress (BETA).
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            offset: Offset for pagination
+            limit: Maximum number of results
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
+            format: Response format (json or xml)
+            
+        Returns:
+            List of HouseVote objects
+        """
+        ...
+    
+    def iter_house_votes_by_congress(
+        self,
+        congress: int,
+        offset: Optional[int] = None,
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[HouseVote]:
+        """
+        Iterate over all house votes for a specific congress (BETA), fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            offset: Offset of the first item (default 0)
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over HouseVote objects
+        """
+        ...
+    
+    def list_house_votes_by_congress_page(
+        self,
+        congress: int,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
+        format: Optional[str] = None,
+    ) -> Page[HouseVote]:
+        """
+        Get house votes for a specific congress (BETA), returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            offset: Offset for pagination
+            limit: Maximum number of results
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
+            format: Response format (json or xml)
+            
+        Returns:
+            Page of HouseVote objects with the total count and next/prev links
+        """
+        ...
+    
+    def count_house_votes_by_congress(
+        self,
+        congress: int,
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
+    ) -> int:
+        """
+        Count house votes for a specific congress (BETA) with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    def list_house_votes_by_session(
+        self,
+        congress: int,
+        session: int,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
+        format: Optional[str] = None,
+    ) -> List[HouseVote]:
+        """
+        Get house votes for a specific congress and session (BETA).
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            session: Session number (1 or 2)
+            offset: Offset for pagination
+            limit: Maximum number of results
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
+            format: Response format (json or xml)
+            
+        Returns:
+            List of HouseVote objects
+        """
+        ...
+    
+    def iter_house_votes_by_session(
+        self,
+        congress: int,
+        session: int,
+        offset: Optional[int] = None,
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> PageIterator[HouseVote]:
+        """
+        Iterate over all house votes for a specific congress and session (BETA), fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            session: Session number (1 or 2)
+            offset: Offset of the first item (default 0)
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy iterator over HouseVote objects
+        """
+        ...
+    
+    def list_house_votes_by_session_page(
+        self,
+        congress: int,
+        session: int,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
+        format: Optional[str] = None,
+    ) -> Page[HouseVote]:
+        """
+        Get house votes for a specific congress and session (BETA), returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            session: Session number (1 or 2)
+            offset: Offset for pagination
+            limit: Maximum number of results
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
+            format: Response format (json or xml)
+            
+        Returns:
+            Page of HouseVote objects with the total count and next/prev links
+        """
+        ...
+    
+    def count_house_votes_by_session(
+        self,
+        congress: int,
+        session: int,
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
+    ) -> int:
+        """
+        Count house votes for a specific congress and session (BETA) with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            session: Session number (1 or 2)
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    def get_house_vote(
+        self,
+        congress: int,
+        session: int,
+        vote_number: int,
+        format: Optional[str] = None,
+    ) -> HouseVoteDetail:
+        """
+        Get detailed information about a specific house vote (BETA).
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            session: Session number (1 or 2)
+            vote_number: Roll call vote number
+            format: Response format (json or xml)
+            
+        Returns:
+            HouseVoteDetail object with party totals
+        """
+        ...
+    
+    def get_house_vote_members(
+        self,
+        congress: int,
+        session: int,
+        vote_number: int,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        format: Optional[str] = None,
+    ) -> HouseVoteMembers:
+        """
+        Get how members voted on a specific house vote (BETA).
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            session: Session number (1 or 2)
+            vote_number: Roll call vote number
+            offset: Offset for pagination
+            limit: Maximum number of results
+            format: Response format (json or xml)
+            
+        Returns:
+            HouseVoteMembers object with individual member votes
+        """
+        ...
+
+class AsyncCDGPythonClient:
+    """
+    Asyncio client for the Congress.gov API.
+    
+    Mirrors ``CDGPythonClient``: every endpoint method is a coroutine
+    returning the same model types, and ``iter_*`` methods return async
+    iterators. Methods must be called while an event loop is running.
+    
+    Example:
+        >>> client = AsyncCDGPythonClient(api_key="your_api_key")
+        >>> bills, members = await asyncio.gather(
+        ...     client.list_bills(limit=10),
+        ...     client.list_members(limit=10, current_member=True),
+        ... )
+        >>> async for bill in client.iter_bills(max_items=500):
+        ...     print(bill.title)
+    """
+    
+    def __init__(
+        self,
+        api_key: str,
+        max_retries: int = 3,
+        backoff_factor: float = 0.5,
+        max_backoff: float = 30.0,
+        rate_limit: Optional[int] = None,
+        rate_limit_period: float = 3600.0,
+        rate_limit_mode: str = "block",
+        base_url: Optional[str] = None,
+        timeout: Optional[float] = 30.0,
+        connect_timeout: Optional[float] = 10.0,
+        read_timeout: Optional[float] = None,
+        proxy: Optional[str] = None,
+        ca_bundle: Optional[Union[str, os.PathLike]] = None,
+        user_agent: Optional[str] = None,
+        max_concurrency: Optional[int] = 10,
+        cache_dir: Optional[Union[str, os.PathLike]] = None,
+        cache_ttl: Optional[float] = 86400.0,
+        cache_ttls: Optional[Dict[str, Optional[float]]] = None,
+        mode: str = "live",
+        cassette_dir: Optional[Union[str, os.PathLike]] = None,
+    ) -> None:
+        """
+        Initialize the asyncio Congress.gov API client.
+        
+        Requests failing with HTTP 429, 500, 502, 503 or 504, or with a
+        connection error or timeout, are retried with exponential backoff
+        and jitter. A ``Retry-After`` header from the server is honoured;
+        if it asks for a wait longer than ``max_backoff`` the error is
+        raised immediately instead.
+        
+        Setting ``rate_limit`` enables a client-side token bucket that allows
+        that many HTTP requests (retries included) per ``rate_limit_period``
+        seconds. Once the budget is spent, requests either wait for it to
+        refill (``rate_limit_mode="block"``) or fail immediately
+        (``rate_limit_mode="raise"``).
+        
+        The API key is sent in the ``X-Api-Key`` header and never appears
+        in URLs or error messages.
+        
+        Setting ``cache_dir`` stores successful responses on disk, keyed on
+        endpoint and parameters (never the API key). Stale entries are
+        revalidated with ``If-None-Match``/``If-Modified-Since`` when the API
+        sent an ``ETag`` or ``Last-Modified`` header.
+        
+        ``mode="record"`` writes every response (API key redacted) to
+        ``cassette_dir``; ``mode="replay"`` serves them back without touching
+        the network, raising ``CassetteError`` for any request not on tape.
+        
+        Args:
+            api_key: Your Congress.gov API key
+            max_retries: Retries after the first attempt (0 disables retrying)
+            backoff_factor: Base delay in seconds; retry n waits about backoff_factor * 2**n
+            max_backoff: Maximum delay in seconds between two attempts
+            rate_limit: Local request budget per period (None disables the limiter)
+            rate_limit_period: Length of the budget period in seconds
+            rate_limit_mode: "block" to wait for budget, "raise" to fail fast
+            base_url: API root (default "https://api.congress.gov/v3"), e.g. a local mock server
+            timeout: Seconds allowed for a whole attempt, None for no limit
+            connect_timeout: Seconds allowed to establish a connection, None for no limit
+            read_timeout: Seconds allowed between two reads from the socket, None for no limit
+            proxy: Proxy URL for all requests; overrides the HTTP(S)_PROXY variables
+            ca_bundle: PEM file with additional root certificates to trust
+            user_agent: User-Agent header (default "cdg_python_client/<version>")
+            cache_dir: Directory for the response cache (None disables caching)
+            cache_ttl: Seconds a cached response stays fresh, None for ever
+            cache_ttls: Per-endpoint TTLs keyed by path prefix, e.g. {"/law": None}
+            mode: "live", "record" or "replay"
+            cassette_dir: Directory of recorded responses for record/replay
+            max_concurrency: Most requests in flight at once (None for no cap)
+        
+        Raises:
+            ValueError: If an option is invalid
+            OSError: If ``ca_bundle`` cannot be read or ``cache_dir`` created
+        """
+        ...
+    
+    def retry_stats(self) -> RetryStats:
+        """
+        Get counters describing how many requests were retried so far.
+        
+        Returns:
+            RetryStats with the number of requests, retries and requests
+            that failed after exhausting their retries
+        """
+        ...
+    
+    def rate_limit_status(self) -> RateLimitStatus:
+        """
+        Get the API quota and the state of the local rate limiter.
+        
+        ``limit`` and ``remaining`` come from the ``X-RateLimit-Limit`` and
+        ``X-RateLimit-Remaining`` headers of the most recent response that
+        carried them, and are None until such a response has been seen.
+        
+        Returns:
+            RateLimitStatus snapshot
+        """
+        ...
+    
+    def cache_stats(self) -> CacheStats:
+        """
+        Get hit/miss counters and the size of the response cache.
+        
+        Returns:
+            CacheStats snapshot (all zero when caching is disabled)
+        """
+        ...
+    
+    def clear_cache(self) -> int:
+        """
+        Delete every cached response.
+        
+        Returns:
+            Number of entries removed
+        """
+        ...
+    
+    async def get_raw(self, path: str, params: Optional[Dict[str, Any]] = None) -> Any:
+        """
+        Fetch any endpoint and return its JSON as plain dicts and lists.
+        
+        Authentication, rate limiting, retries, caching and error mapping
+        work exactly as for the typed methods.
+        
+        Args:
+            path: Endpoint path relative to base_url (e.g., "/bill/118/hr/1/actions")
+            params: Query parameters; booleans are sent as "true"/"false" and None values are dropped
+            
+        Returns:
+            The decoded JSON response
+            
+        Raises:
+            ValueError: If path is a full URL or contains a query string
+        """
+        ...
+    
+    async def list_bills(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> List[Bill]:
+        """
+        Get a list of bills sorted by date of latest action.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            List of Bill objects
+        """
+        ...
+    
+    def iter_bills(
+        self,
+        offset: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[Bill]:
+        """
+        Iterate over all bills sorted by date of latest action, fetching pages lazily.
+        
+        Args:
+            offset: Offset of the first item (default 0)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over Bill objects
+        """
+        ...
+    
+    async def list_bills_page(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> Page[Bill]:
+        """
+        Get a list of bills sorted by date of latest action, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Page of Bill objects with the total count and next/prev links
+        """
+        ...
+    
+    async def count_bills(
+        self,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> int:
+        """
+        Count bills with a single ``limit=1`` request.
+        
+        Args:
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def list_bills_by_congress(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> List[Bill]:
+        """
+        Get bills filtered by congress number.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            List of Bill objects
+        """
+        ...
+    
+    def iter_bills_by_congress(
+        self,
+        congress: int,
+        offset: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[Bill]:
+        """
+        Iterate over all bills filtered by congress number, fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            offset: Offset of the first item (default 0)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over Bill objects
+        """
+        ...
+    
+    async def list_bills_by_congress_page(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> Page[Bill]:
+        """
+        Get bills filtered by congress number, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Page of Bill objects with the total count and next/prev links
+        """
+        ...
+    
+    async def count_bills_by_congress(
+        self,
+        congress: int,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> int:
+        """
+        Count bills filtered by congress number with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def list_bills_by_type(
+        self,
+        congress: int,
+        bill_type: Union[BillType, str],
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> List[Bill]:
+        """
+        Get bills filtered by congress and bill type.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            List of Bill objects
+        """
+        ...
+    
+    def iter_bills_by_type(
+        self,
+        congress: int,
+        bill_type: Union[BillType, str],
+        offset: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[Bill]:
+        """
+        Iterate over all bills filtered by congress and bill type, fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            offset: Offset of the first item (default 0)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over Bill objects
+        """
+        ...
+    
+    async def list_bills_by_type_page(
+        self,
+        congress: int,
+        bill_type: Union[BillType, str],
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> Page[Bill]:
+        """
+        Get bills filtered by congress and bill type, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Page of Bill objects with the total count and next/prev links
+        """
+        ...
+    
+    async def count_bills_by_type(
+        self,
+        congress: int,
+        bill_type: Union[BillType, str],
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> int:
+        """
+        Count bills filtered by congress and bill type with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def get_bill(
+        self,
+        congress: Union[int, BillId, str],
+        bill_type: Optional[Union[BillType, str]] = None,
+        bill_number: Optional[int] = None,
+    ) -> BillDetail:
+        """
+        Get detailed information for a specific bill.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            bill_number: Bill number
+            id: A AmendmentId or amendment citation such as "H.R. 1 (118th Congress)", in place of
+                congress, type and number
+            
+        Returns:
+            BillDetail object with comprehensive bill information
+        """
+        ...
+    
+    async def get_bill_actions(
+        self,
+        congress: Optional[int] = None,
+        bill_type: Optional[Union[BillType, str]] = None,
+        bill_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        *,
+        id: Optional[Union[BillId, str]] = None,
+    ) -> List[Action]:
+        """
+        Get the list of actions on a specified bill.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            bill_number: Bill number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            id: A BillId or bill citation such as "H.R. 1 (118th Congress)", in place of
+                congress, type and number
+            
+        Returns:
+            List of Action objects
+        """
+        ...
+    
+    async def get_bill_amendments(
+        self,
+        congress: Optional[int] = None,
+        bill_type: Optional[Union[BillType, str]] = None,
+        bill_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        *,
+        id: Optional[Union[BillId, str]] = None,
+    ) -> List[Amendment]:
+        """
+        Get the list of amendments to a specified bill.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            bill_number: Bill number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            id: A BillId or bill citation such as "H.R. 1 (118th Congress)", in place of
+                congress, type and number
+            
+        Returns:
+            List of Amendment objects
+        """
+        ...
+    
+    async def get_bill_committees(
+        self,
+        congress: Optional[int] = None,
+        bill_type: Optional[Union[BillType, str]] = None,
+        bill_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        *,
+        id: Optional[Union[BillId, str]] = None,
+    ) -> List[Committee]:
+        """
+        Get the list of committees associated with a specified bill.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            bill_number: Bill number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            id: A BillId or bill citation such as "H.R. 1 (118th Congress)", in place of
+                congress, type and number
+            
+        Returns:
+            List of Committee objects
+        """
+        ...
+    
+    async def get_bill_cosponsors(
+        self,
+        congress: Optional[int] = None,
+        bill_type: Optional[Union[BillType, str]] = None,
+        bill_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        *,
+        id: Optional[Union[BillId, str]] = None,
+    ) -> List[Cosponsor]:
+        """
+        Get the list of cosponsors on a specified bill.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            bill_number: Bill number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            id: A BillId or bill citation such as "H.R. 1 (118th Congress)", in place of
+                congress, type and number
+            
+        Returns:
+            List of Cosponsor objects
+        """
+        ...
+    
+    async def get_related_bills(
+        self,
+        congress: Optional[int] = None,
+        bill_type: Optional[Union[BillType, str]] = None,
+        bill_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        *,
+        id: Optional[Union[BillId, str]] = None,
+    ) -> List[RelatedBill]:
+        """
+        Get the list of related bills to a specified bill.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            bill_number: Bill number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            id: A BillId or bill citation such as "H.R. 1 (118th Congress)", in place of
+                congress, type and number
+            
+        Returns:
+            List of RelatedBill objects
+        """
+        ...
+    
+    async def get_bill_subjects(
+        self,
+        congress: Optional[int] = None,
+        bill_type: Optional[Union[BillType, str]] = None,
+        bill_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        *,
+        id: Optional[Union[BillId, str]] = None,
+    ) -> List[Subject]:
+        """
+        Get the list of legislative subjects on a specified bill.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            bill_number: Bill number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            id: A BillId or bill citation such as "H.R. 1 (118th Congress)", in place of
+                congress, type and number
+            
+        Returns:
+            List of Subject objects
+        """
+        ...
+    
+    async def get_bill_summaries(
+        self,
+        congress: Optional[int] = None,
+        bill_type: Optional[Union[BillType, str]] = None,
+        bill_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        *,
+        id: Optional[Union[BillId, str]] = None,
+    ) -> List[Summary]:
+        """
+        Get the list of summaries for a specified bill.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            bill_number: Bill number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            id: A BillId or bill citation such as "H.R. 1 (118th Congress)", in place of
+                congress, type and number
+            
+        Returns:
+            List of Summary objects
+        """
+        ...
+    
+    async def get_bill_text(
+        self,
+        congress: Optional[int] = None,
+        bill_type: Optional[Union[BillType, str]] = None,
+        bill_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        *,
+        id: Optional[Union[BillId, str]] = None,
+    ) -> List[TextVersion]:
+        """
+        Get the list of text versions for a specified bill.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            bill_number: Bill number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            id: A BillId or bill citation such as "H.R. 1 (118th Congress)", in place of
+                congress, type and number
+            
+        Returns:
+            List of TextVersion objects
+        """
+        ...
+    
+    async def get_bill_titles(
+        self,
+        congress: Optional[int] = None,
+        bill_type: Optional[Union[BillType, str]] = None,
+        bill_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        *,
+        id: Optional[Union[BillId, str]] = None,
+    ) -> List[Title]:
+        """
+        Get the list of titles for a specified bill.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            bill_type: A BillType or bill type (hr, s, hjres, sjres, hconres, sconres, hres, sres)
+            bill_number: Bill number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            id: A BillId or bill citation such as "H.R. 1 (118th Congress)", in place of
+                congress, type and number
+            
+        Returns:
+            List of Title objects
+        """
+        ...
+    
+    # Amendment endpoints
+    
+    async def list_amendments(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> List[Amendment]:
+        """
+        Get a list of amendments sorted by date of latest action.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            List of Amendment objects
+        """
+        ...
+    
+    def iter_amendments(
+        self,
+        offset: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[Amendment]:
+        """
+        Iterate over all amendments sorted by date of latest action, fetching pages lazily.
+        
+        Args:
+            offset: Offset of the first item (default 0)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over Amendment objects
+        """
+        ...
+    
+    async def list_amendments_page(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> Page[Amendment]:
+        """
+        Get a list of amendments sorted by date of latest action, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Page of Amendment objects with the total count and next/prev links
+        """
+        ...
+    
+    async def count_amendments(
+        self,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> int:
+        """
+        Count amendments with a single ``limit=1`` request.
+        
+        Args:
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def list_amendments_by_congress(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> List[Amendment]:
+        """
+        Get amendments filtered by congress number.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            List of Amendment objects
+        """
+        ...
+    
+    def iter_amendments_by_congress(
+        self,
+        congress: int,
+        offset: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[Amendment]:
+        """
+        Iterate over all amendments filtered by congress number, fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            offset: Offset of the first item (default 0)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over Amendment objects
+        """
+        ...
+    
+    async def list_amendments_by_congress_page(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> Page[Amendment]:
+        """
+        Get amendments filtered by congress number, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Page of Amendment objects with the total count and next/prev links
+        """
+        ...
+    
+    async def count_amendments_by_congress(
+        self,
+        congress: int,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> int:
+        """
+        Count amendments filtered by congress number with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def list_amendments_by_type(
+        self,
+        congress: int,
+        amendment_type: Union[AmendmentType, str],
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> List[Amendment]:
+        """
+        Get amendments filtered by congress and amendment type.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            amendment_type: An AmendmentType or amendment type (hamdt, samdt, suamdt)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            List of Amendment objects
+        """
+        ...
+    
+    def iter_amendments_by_type(
+        self,
+        congress: int,
+        amendment_type: Union[AmendmentType, str],
+        offset: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[Amendment]:
+        """
+        Iterate over all amendments filtered by congress and amendment type, fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            amendment_type: An AmendmentType or amendment type (hamdt, samdt, suamdt)
+            offset: Offset of the first item (default 0)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over Amendment objects
+        """
+        ...
+    
+    async def list_amendments_by_type_page(
+        self,
+        congress: int,
+        amendment_type: Union[AmendmentType, str],
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> Page[Amendment]:
+        """
+        Get amendments filtered by congress and amendment type, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            amendment_type: An AmendmentType or amendment type (hamdt, samdt, suamdt)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Page of Amendment objects with the total count and next/prev links
+        """
+        ...
+    
+    async def count_amendments_by_type(
+        self,
+        congress: int,
+        amendment_type: Union[AmendmentType, str],
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> int:
+        """
+        Count amendments filtered by congress and amendment type with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            amendment_type: An AmendmentType or amendment type (hamdt, samdt, suamdt)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def get_amendment(
+        self,
+        congress: Union[int, AmendmentId, str],
+        amendment_type: Optional[Union[AmendmentType, str]] = None,
+        amendment_number: Optional[int] = None,
+    ) -> AmendmentDetail:
+        """
+        Get detailed information for a specific amendment.
+        
+        Args:
+            congress: Congress number (e.g., 118), or an AmendmentId or amendment citation
+                such as "S.Amdt. 2137 (117th Congress)" in place of all three arguments
+            amendment_type: An AmendmentType or amendment type (hamdt, samdt, suamdt)
+            amendment_number: Amendment number
+            
+        Returns:
+            AmendmentDetail with the purpose, sponsors, dates and amended bill or treaty
+        """
+        ...
+    
+    async def get_amendment_actions(
+        self,
+        congress: Union[int, AmendmentId, str],
+        amendment_type: Optional[Union[AmendmentType, str]] = None,
+        amendment_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Action]:
+        """
+        Get the list of actions on a specified amendment.
+        
+        Args:
+            congress: Congress number (e.g., 118), or an AmendmentId or amendment citation
+                such as "S.Amdt. 2137 (117th Congress)" in place of all three arguments
+            amendment_type: An AmendmentType or amendment type (hamdt, samdt, suamdt)
+            amendment_number: Amendment number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Action objects
+        """
+        ...
+    
+    async def get_amendment_cosponsors(
+        self,
+        congress: Union[int, AmendmentId, str],
+        amendment_type: Optional[Union[AmendmentType, str]] = None,
+        amendment_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Cosponsor]:
+        """
+        Get the list of cosponsors on a specified amendment.
+        
+        Args:
+            congress: Congress number (e.g., 118), or an AmendmentId or amendment citation
+                such as "S.Amdt. 2137 (117th Congress)" in place of all three arguments
+            amendment_type: An AmendmentType or amendment type (hamdt, samdt, suamdt)
+            amendment_number: Amendment number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Cosponsor objects
+        """
+        ...
+    
+    async def get_amendment_amendments(
+        self,
+        congress: Union[int, AmendmentId, str],
+        amendment_type: Optional[Union[AmendmentType, str]] = None,
+        amendment_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Amendment]:
+        """
+        Get the list of amendments to a specified amendment.
+        
+        Args:
+            congress: Congress number (e.g., 118), or an AmendmentId or amendment citation
+                such as "S.Amdt. 2137 (117th Congress)" in place of all three arguments
+            amendment_type: An AmendmentType or amendment type (hamdt, samdt, suamdt)
+            amendment_number: Amendment number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Amendment objects
+        """
+        ...
+    
+    async def get_amendment_text(
+        self,
+        congress: Union[int, AmendmentId, str],
+        amendment_type: Optional[Union[AmendmentType, str]] = None,
+        amendment_number: Optional[int] = None,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[TextVersion]:
+        """
+        Get the list of text versions for a specified amendment (117th Congress onwards).
+        
+        Args:
+            congress: Congress number (e.g., 118), or an AmendmentId or amendment citation
+                such as "S.Amdt. 2137 (117th Congress)" in place of all three arguments
+            amendment_type: An AmendmentType or amendment type (hamdt, samdt, suamdt)
+            amendment_number: Amendment number
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of TextVersion objects
+        """
+        ...
+    
+    # Member endpoints
+    
+    async def list_members(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        current_member: Optional[bool] = None,
+    ) -> List[Sponsor]:
+        """
+        Get a list of congressional members.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            current_member: Filter for current members only
+            
+        Returns:
+            List of Sponsor objects (representing members)
+        """
+        ...
+    
+    def iter_members(
+        self,
+        offset: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        current_member: Optional[bool] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[Sponsor]:
+        """
+        Iterate over all congressional members, fetching pages lazily.
+        
+        Args:
+            offset: Offset of the first item (default 0)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            current_member: Filter for current members only
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over Sponsor objects (representing members)
+        """
+        ...
+    
+    async def list_members_page(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        current_member: Optional[bool] = None,
+    ) -> Page[Sponsor]:
+        """
+        Get a list of congressional members, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            current_member: Filter for current members only
+            
+        Returns:
+            Page of Sponsor objects with the total count and next/prev links (representing members)
+        """
+        ...
+    
+    async def count_members(
+        self,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        current_member: Optional[bool] = None,
+    ) -> int:
+        """
+        Count congressional members with a single ``limit=1`` request.
+        
+        Args:
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            current_member: Filter for current members only
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def get_member(self, bioguide_id: str) -> Sponsor:
+        """
+        Get detailed information for a specified congressional member.
+        
+        Args:
+            bioguide_id: The Bioguide ID of the member
+            
+        Returns:
+            Sponsor object with member information
+        """
+        ...
+    
+    async def list_members_by_congress(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        current_member: Optional[bool] = None,
+    ) -> List[Sponsor]:
+        """
+        Get the list of members by congress.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            current_member: Filter for current members only
+            
+        Returns:
+            List of Sponsor objects (representing members)
+        """
+        ...
+    
+    def iter_members_by_congress(
+        self,
+        congress: int,
+        offset: Optional[int] = None,
+        current_member: Optional[bool] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[Sponsor]:
+        """
+        Iterate over all members by congress, fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            offset: Offset of the first item (default 0)
+            current_member: Filter for current members only
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over Sponsor objects (representing members)
+        """
+        ...
+    
+    async def list_members_by_congress_page(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        current_member: Optional[bool] = None,
+    ) -> Page[Sponsor]:
+        """
+        Get the list of members by congress, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            current_member: Filter for current members only
+            
+        Returns:
+            Page of Sponsor objects with the total count and next/prev links (representing members)
+        """
+        ...
+    
+    async def count_members_by_congress(
+        self,
+        congress: int,
+        current_member: Optional[bool] = None,
+    ) -> int:
+        """
+        Count members by congress with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            current_member: Filter for current members only
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def get_member_sponsored_legislation(
+        self,
+        bioguide_id: str,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Bill]:
+        """
+        Get legislation sponsored by a specified member.
+        
+        Args:
+            bioguide_id: The Bioguide ID of the member
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Bill objects
+        """
+        ...
+    
+    async def get_member_cosponsored_legislation(
+        self,
+        bioguide_id: str,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Bill]:
+        """
+        Get legislation cosponsored by a specified member.
+        
+        Args:
+            bioguide_id: The Bioguide ID of the member
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Bill objects
+        """
+        ...
+    
+    async def list_members_by_state(
+        self,
+        state_code: str,
+        format: Optional[str] = None,
+        limit: Optional[int] = None,
+        current_member: Optional[bool] = None,
+    ) -> List[Sponsor]:
+        """
+        Get the list of members by state.
+        
+        Args:
+            state_code: Two-letter state code (e.g., 'CA', 'NY')
+            format: Response format (json or xml)
+            limit: Number of results to return (max 250)
+            current_member: Filter for current members only
+            
+        Returns:
+            List of Sponsor objects (representing members)
+        """
+        ...
+    
+    def iter_members_by_state(
+        self,
+        state_code: str,
+        current_member: Optional[bool] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[Sponsor]:
+        """
+        Iterate over all members by state, fetching pages lazily.
+        
+        Args:
+            state_code: Two-letter state code (e.g., 'CA', 'NY')
+            current_member: Filter for current members only
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over Sponsor objects (representing members)
+        """
+        ...
+    
+    async def list_members_by_state_page(
+        self,
+        state_code: str,
+        format: Optional[str] = None,
+        limit: Optional[int] = None,
+        current_member: Optional[bool] = None,
+    ) -> Page[Sponsor]:
+        """
+        Get the list of members by state, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            state_code: Two-letter state code (e.g., 'CA', 'NY')
+            format: Response format (json or xml)
+            limit: Number of results to return (max 250)
+            current_member: Filter for current members only
+            
+        Returns:
+            Page of Sponsor objects with the total count and next/prev links (representing members)
+        """
+        ...
+    
+    async def count_members_by_state(
+        self,
+        state_code: str,
+        current_member: Optional[bool] = None,
+    ) -> int:
+        """
+        Count members by state with a single ``limit=1`` request.
+        
+        Args:
+            state_code: Two-letter state code (e.g., 'CA', 'NY')
+            current_member: Filter for current members only
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def list_members_by_state_district(
+        self,
+        state_code: str,
+        district: int,
+        format: Optional[str] = None,
+        current_member: Optional[bool] = None,
+    ) -> List[Sponsor]:
+        """
+        Get the list of members by state and district.
+        
+        Args:
+            state_code: Two-letter state code (e.g., 'CA', 'NY')
+            district: Congressional district number
+            format: Response format (json or xml)
+            current_member: Filter for current members only
+            
+        Returns:
+            List of Sponsor objects (representing members)
+        """
+        ...
+    
+    def iter_members_by_state_district(
+        self,
+        state_code: str,
+        district: int,
+        current_member: Optional[bool] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[Sponsor]:
+        """
+        Iterate over all members by state and district, fetching pages lazily.
+        
+        Args:
+            state_code: Two-letter state code (e.g., 'CA', 'NY')
+            district: Congressional district number
+            current_member: Filter for current members only
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over Sponsor objects (representing members)
+        """
+        ...
+    
+    async def list_members_by_state_district_page(
+        self,
+        state_code: str,
+        district: int,
+        format: Optional[str] = None,
+        current_member: Optional[bool] = None,
+    ) -> Page[Sponsor]:
+        """
+        Get the list of members by state and district, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            state_code: Two-letter state code (e.g., 'CA', 'NY')
+            district: Congressional district number
+            format: Response format (json or xml)
+            current_member: Filter for current members only
+            
+        Returns:
+            Page of Sponsor objects with the total count and next/prev links (representing members)
+        """
+        ...
+    
+    async def count_members_by_state_district(
+        self,
+        state_code: str,
+        district: int,
+        current_member: Optional[bool] = None,
+    ) -> int:
+        """
+        Count members by state and district with a single ``limit=1`` request.
+        
+        Args:
+            state_code: Two-letter state code (e.g., 'CA', 'NY')
+            district: Congressional district number
+            current_member: Filter for current members only
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    # Committee endpoints
+    
+    async def list_committees(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Committee]:
+        """
+        Get a list of committees.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Committee objects
+        """
+        ...
+    
+    def iter_committees(
+        self,
+        offset: Optional[int] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[Committee]:
+        """
+        Iterate over all committees, fetching pages lazily.
+        
+        Args:
+            offset: Offset of the first item (default 0)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over Committee objects
+        """
+        ...
+    
+    async def list_committees_page(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> Page[Committee]:
+        """
+        Get a list of committees, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            Page of Committee objects with the total count and next/prev links
+        """
+        ...
+    
+    async def count_committees(self) -> int:
+        """
+        Count committees with a single ``limit=1`` request.
+        
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def get_committee_by_congress(
+        self,
+        congress: int,
+        chamber: Union[Chamber, str],
+        committee_code: str,
+        format: Optional[str] = None,
+    ) -> CommitteeDetailInfo:
+        """
+        Get detailed information about a committee as it stood in a given congress.
+        
+        Args:
+            congress: Congress number (e.g., 117)
+            chamber: A Chamber or chamber name (house, senate, joint)
+            committee_code: Committee system code (e.g., "hspw00")
+            format: Response format (json or xml)
+            
+        Returns:
+            CommitteeDetailInfo with history, subcommittees and links to
+            bills, reports and communications
+        """
+        ...
+    
+    async def get_committee_reports(
+        self,
+        chamber: Union[Chamber, str],
+        committee_code: str,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        format: Optional[str] = None,
+    ) -> List[CommitteeReportItem]:
+        """
+        Get committee reports associated with a committee.
+        
+        Args:
+            chamber: A Chamber or chamber name (house, senate, joint)
+            committee_code: Committee system code (e.g., "hspw00")
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            format: Response format (json or xml)
+            
+        Returns:
+            List of CommitteeReportItem objects
+        """
+        ...
+    
+    async def get_committee_nominations(
+        self,
+        chamber: Union[Chamber, str],
+        committee_code: str,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        format: Optional[str] = None,
+    ) -> List[Nomination]:
+        """
+        Get nominations referred to a committee.
+        
+        Args:
+            chamber: A Chamber or chamber name (house, senate, joint)
+            committee_code: Committee system code (e.g., "hspw00")
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            format: Response format (json or xml)
+            
+        Returns:
+            List of Nomination objects
+        """
+        ...
+    
+    async def get_committee_house_communications(
+        self,
+        committee_code: str,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        format: Optional[str] = None,
+    ) -> List[Communication]:
+        """
+        Get House communications referred to a House committee.
+        
+        Args:
+            committee_code: Committee system code of a House committee (e.g., "hsif00")
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            format: Response format (json or xml)
+            
+        Returns:
+            List of Communication objects
+        """
+        ...
+    
+    async def get_committee_senate_communications(
+        self,
+        committee_code: str,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        format: Optional[str] = None,
+    ) -> List[Communication]:
+        """
+        Get Senate communications referred to a Senate committee.
+        
+        Args:
+            committee_code: Committee system code of a Senate committee (e.g., "hsif00")
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            format: Response format (json or xml)
+            
+        Returns:
+            List of Communication objects
+        """
+        ...
+    
+    # Committee meeting endpoints
+    
+    async def list_committee_meetings(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> List[CommitteeMeeting]:
+        """
+        Get a list of committee meetings.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            List of CommitteeMeeting objects
+        """
+        ...
+    
+    def iter_committee_meetings(
+        self,
+        offset: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[CommitteeMeeting]:
+        """
+        Iterate over all committee meetings, fetching pages lazily.
+        
+        Args:
+            offset: Offset of the first item (default 0)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over CommitteeMeeting objects
+        """
+        ...
+    
+    async def list_committee_meetings_page(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> Page[CommitteeMeeting]:
+        """
+        Get a list of committee meetings, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Page of CommitteeMeeting objects with the total count and next/prev links
+        """
+        ...
+    
+    async def count_committee_meetings(
+        self,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> int:
+        """
+        Count committee meetings with a single ``limit=1`` request.
+        
+        Args:
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def list_committee_meetings_by_congress(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> List[CommitteeMeeting]:
+        """
+        Get committee meetings filtered by congress number.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            List of CommitteeMeeting objects
+        """
+        ...
+    
+    def iter_committee_meetings_by_congress(
+        self,
+        congress: int,
+        offset: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[CommitteeMeeting]:
+        """
+        Iterate over all committee meetings filtered by congress number, fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            offset: Offset of the first item (default 0)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over CommitteeMeeting objects
+        """
+        ...
+    
+    async def list_committee_meetings_by_congress_page(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> Page[CommitteeMeeting]:
+        """
+        Get committee meetings filtered by congress number, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Page of CommitteeMeeting objects with the total count and next/prev links
+        """
+        ...
+    
+    async def count_committee_meetings_by_congress(
+        self,
+        congress: int,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> int:
+        """
+        Count committee meetings filtered by congress number with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def list_committee_meetings_by_chamber(
+        self,
+        congress: int,
+        chamber: Union[Chamber, str],
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> List[CommitteeMeeting]:
+        """
+        Get committee meetings filtered by congress and chamber.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            chamber: A Chamber or chamber name (house, senate, joint)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            List of CommitteeMeeting objects
+        """
+        ...
+    
+    def iter_committee_meetings_by_chamber(
+        self,
+        congress: int,
+        chamber: Union[Chamber, str],
+        offset: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[CommitteeMeeting]:
+        """
+        Iterate over all committee meetings filtered by congress and chamber, fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            chamber: A Chamber or chamber name (house, senate, joint)
+            offset: Offset of the first item (default 0)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over CommitteeMeeting objects
+        """
+        ...
+    
+    async def list_committee_meetings_by_chamber_page(
+        self,
+        congress: int,
+        chamber: Union[Chamber, str],
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> Page[CommitteeMeeting]:
+        """
+        Get committee meetings filtered by congress and chamber, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            chamber: A Chamber or chamber name (house, senate, joint)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Page of CommitteeMeeting objects with the total count and next/prev links
+        """
+        ...
+    
+    async def count_committee_meetings_by_chamber(
+        self,
+        congress: int,
+        chamber: Union[Chamber, str],
+        from_date_time: Optional[_DateArg] = None,
+        to_date_time: Optional[_DateArg] = None,
+    ) -> int:
+        """
+        Count committee meetings filtered by congress and chamber with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            chamber: A Chamber or chamber name (house, senate, joint)
+            from_date_time: Start of the range (datetime, date or ISO 8601 string)
+            to_date_time: End of the range, inclusive (datetime, date or ISO 8601 string)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def get_committee_meeting(
+        self,
+        congress: int,
+        chamber: Union[Chamber, str],
+        event_id: Union[int, str],
+        format: Optional[str] = None,
+    ) -> CommitteeMeetingDetail:
+        """
+        Get detailed information for a specified committee meeting.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            chamber: A Chamber or chamber name (house, senate, joint)
+            event_id: Event id, such as ``hearing.associated_meeting.event_id``
+            format: Response format (json or xml)
+            
+        Returns:
+            CommitteeMeetingDetail with status, location, witnesses, documents,
+            related items and videos
+        """
+        ...
+    
+    # House communication endpoints
+    
+    async def list_house_communications(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Communication]:
+        """
+        Get a list of House communications.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Communication objects
+        """
+        ...
+    
+    def iter_house_communications(
+        self,
+        offset: Optional[int] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[Communication]:
+        """
+        Iterate over all House communications, fetching pages lazily.
+        
+        Args:
+            offset: Offset of the first item (default 0)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over Communication objects
+        """
+        ...
+    
+    async def list_house_communications_page(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> Page[Communication]:
+        """
+        Get a list of House communications, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            Page of Communication objects with the total count and next/prev links
+        """
+        ...
+    
+    async def count_house_communications(self) -> int:
+        """
+        Count House communications with a single ``limit=1`` request.
+        
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def list_house_communications_by_congress(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Communication]:
+        """
+        Get House communications filtered by congress number.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Communication objects
+        """
+        ...
+    
+    def iter_house_communications_by_congress(
+        self,
+        congress: int,
+        offset: Optional[int] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[Communication]:
+        """
+        Iterate over all House communications filtered by congress number, fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            offset: Offset of the first item (default 0)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over Communication objects
+        """
+        ...
+    
+    async def list_house_communications_by_congress_page(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> Page[Communication]:
+        """
+        Get House communications filtered by congress number, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            Page of Communication objects with the total count and next/prev links
+        """
+        ...
+    
+    async def count_house_communications_by_congress(
+        self,
+        congress: int,
+    ) -> int:
+        """
+        Count House communications filtered by congress number with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def list_house_communications_by_type(
+        self,
+        congress: int,
+        communication_type: Union[CommunicationType, str],
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Communication]:
+        """
+        Get House communications filtered by congress and communication type.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            communication_type: A CommunicationType or type code (ec, ml, pm, pt)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Communication objects
+        """
+        ...
+    
+    def iter_house_communications_by_type(
+        self,
+        congress: int,
+        communication_type: Union[CommunicationType, str],
+        offset: Optional[int] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[Communication]:
+        """
+        Iterate over all House communications filtered by congress and communication type, fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            communication_type: A CommunicationType or type code (ec, ml, pm, pt)
+            offset: Offset of the first item (default 0)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over Communication objects
+        """
+        ...
+    
+    async def list_house_communications_by_type_page(
+        self,
+        congress: int,
+        communication_type: Union[CommunicationType, str],
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> Page[Communication]:
+        """
+        Get House communications filtered by congress and communication type, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            communication_type: A CommunicationType or type code (ec, ml, pm, pt)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            Page of Communication objects with the total count and next/prev links
+        """
+        ...
+    
+    async def count_house_communications_by_type(
+        self,
+        congress: int,
+        communication_type: Union[CommunicationType, str],
+    ) -> int:
+        """
+        Count House communications filtered by congress and communication type with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            communication_type: A CommunicationType or type code (ec, ml, pm, pt)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def get_house_communication(
+        self,
+        congress: int,
+        communication_type: Union[CommunicationType, str],
+        communication_number: int,
+        format: Optional[str] = None,
+    ) -> CommunicationDetail:
+        """
+        Get detailed information for a specified House communication.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            communication_type: A CommunicationType or type code (ec, ml, pm, pt)
+            communication_number: Communication number (e.g., 3324)
+            format: Response format (json or xml)
+            
+        Returns:
+            CommunicationDetail with the abstract, referral committees and
+            congressional record date
+        """
+        ...
+    
+    # Senate communication endpoints
+    
+    async def list_senate_communications(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Communication]:
+        """
+        Get a list of Senate communications.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Communication objects
+        """
+        ...
+    
+    def iter_senate_communications(
+        self,
+        offset: Optional[int] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[Communication]:
+        """
+        Iterate over all Senate communications, fetching pages lazily.
+        
+        Args:
+            offset: Offset of the first item (default 0)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over Communication objects
+        """
+        ...
+    
+    async def list_senate_communications_page(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> Page[Communication]:
+        """
+        Get a list of Senate communications, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            Page of Communication objects with the total count and next/prev links
+        """
+        ...
+    
+    async def count_senate_communications(self) -> int:
+        """
+        Count Senate communications with a single ``limit=1`` request.
+        
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def list_senate_communications_by_congress(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Communication]:
+        """
+        Get Senate communications filtered by congress number.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Communication objects
+        """
+        ...
+    
+    def iter_senate_communications_by_congress(
+        self,
+        congress: int,
+        offset: Optional[int] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[Communication]:
+        """
+        Iterate over all Senate communications filtered by congress number, fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            offset: Offset of the first item (default 0)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over Communication objects
+        """
+        ...
+    
+    async def list_senate_communications_by_congress_page(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> Page[Communication]:
+        """
+        Get Senate communications filtered by congress number, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            Page of Communication objects with the total count and next/prev links
+        """
+        ...
+    
+    async def count_senate_communications_by_congress(
+        self,
+        congress: int,
+    ) -> int:
+        """
+        Count Senate communications filtered by congress number with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def list_senate_communications_by_type(
+        self,
+        congress: int,
+        communication_type: Union[CommunicationType, str],
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Communication]:
+        """
+        Get Senate communications filtered by congress and communication type.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            communication_type: A CommunicationType or type code (ec, pm, pom)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Communication objects
+        """
+        ...
+    
+    def iter_senate_communications_by_type(
+        self,
+        congress: int,
+        communication_type: Union[CommunicationType, str],
+        offset: Optional[int] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[Communication]:
+        """
+        Iterate over all Senate communications filtered by congress and communication type, fetching pages lazily.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            communication_type: A CommunicationType or type code (ec, pm, pom)
+            offset: Offset of the first item (default 0)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over Communication objects
+        """
+        ...
+    
+    async def list_senate_communications_by_type_page(
+        self,
+        congress: int,
+        communication_type: Union[CommunicationType, str],
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> Page[Communication]:
+        """
+        Get Senate communications filtered by congress and communication type, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            communication_type: A CommunicationType or type code (ec, pm, pom)
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            Page of Communication objects with the total count and next/prev links
+        """
+        ...
+    
+    async def count_senate_communications_by_type(
+        self,
+        congress: int,
+        communication_type: Union[CommunicationType, str],
+    ) -> int:
+        """
+        Count Senate communications filtered by congress and communication type with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            communication_type: A CommunicationType or type code (ec, pm, pom)
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def get_senate_communication(
+        self,
+        congress: int,
+        communication_type: Union[CommunicationType, str],
+        communication_number: int,
+        format: Optional[str] = None,
+    ) -> CommunicationDetail:
+        """
+        Get detailed information for a specified Senate communication.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            communication_type: A CommunicationType or type code (ec, pm, pom)
+            communication_number: Communication number (e.g., 3324)
+            format: Response format (json or xml)
+            
+        Returns:
+            CommunicationDetail with the abstract, referral committees and
+            congressional record date
+        """
+        ...
+    
+    # House requirement endpoints
+    
+    async def list_house_requirements(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[HouseRequirement]:
+        """
+        Get a list of House requirements.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of HouseRequirement objects
+        """
+        ...
+    
+    def iter_house_requirements(
+        self,
+        offset: Optional[int] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[HouseRequirement]:
+        """
+        Iterate over all House requirements, fetching pages lazily.
+        
+        Args:
+            offset: Offset of the first item (default 0)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over HouseRequirement objects
+        """
+        ...
+    
+    async def list_house_requirements_page(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> Page[HouseRequirement]:
+        """
+        Get a list of House requirements, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            Page of HouseRequirement objects with the total count and next/prev links
+        """
+        ...
+    
+    async def count_house_requirements(self) -> int:
+        """
+        Count House requirements with a single ``limit=1`` request.
+        
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def get_house_requirement(
+        self,
+        requirement_number: int,
+        format: Optional[str] = None,
+    ) -> HouseRequirement:
+        """
+        Get detailed information for a specified House requirement.
+        
+        Args:
+            requirement_number: Requirement number (e.g., 8070)
+            format: Response format (json or xml)
+            
+        Returns:
+            HouseRequirement with legal authority, frequency, nature and
+            submitting and parent agencies
+        """
+        ...
+    
+    async def get_house_requirement_matching_communications(
+        self,
+        requirement_number: int,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        format: Optional[str] = None,
+    ) -> List[Communication]:
+        """
+        Get the communications filed under a House requirement.
+        
+        Args:
+            requirement_number: Requirement number (e.g., 8070)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            format: Response format (json or xml)
+            
+        Returns:
+            List of Communication objects
+        """
+        ...
+    
+    # Congress/Session endpoints
+    
+    async def list_congresses(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> List[Congress]:
+        """
+        Get a list of congresses and congressional sessions.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            List of Congress objects
+        """
+        ...
+    
+    def iter_congresses(
+        self,
+        offset: Optional[int] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[Congress]:
+        """
+        Iterate over all congresses and congressional sessions, fetching pages lazily.
+        
+        Args:
+            offset: Offset of the first item (default 0)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
+            
+        Returns:
+            Lazy async iterator over Congress objects
+        """
+        ...
+    
+    async def list_congresses_page(
+        self,
+        format: Optional[str] = None,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+    ) -> Page[Congress]:
+        """
+        Get a list of congresses and congressional sessions, returning a ``Page`` with pagination metadata.
+        
+        Args:
+            format: Response format (json or xml)
+            offset: Offset for pagination
+            limit: Number of results to return (max 250)
+            
+        Returns:
+            Page of Congress objects with the total count and next/prev links
+        """
+        ...
+    
+    async def count_congresses(self) -> int:
+        """
+        Count congresses and congressional sessions with a single ``limit=1`` request.
+        
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def get_congress(
+        self,
+        congress: int,
+        format: Optional[str] = None,
+    ) -> Congress:
+        """
+        Get information about a specific congress.
+        
+        Args:
+            congress: The congress number (e.g., 117)
             format: Response format (json or xml)
+            
+        Returns:
+            Congress object
+        """
+        ...
+    
+    async def get_current_congress(
+        self,
+        format: Optional[str] = None,
+    ) -> Congress:
+        """
+        Get information about the current congress.
+        
+        Args:
+            format: Response format (json or xml)
+            
+        Returns:
+            Congress object
+        """
+        ...
+    
+    # House Vote Operations (BETA)
+    
+    async def list_house_votes(
+        self,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
+        format: Optional[str] = None,
+    ) -> List[HouseVote]:
+        """
+        Get a list of house votes (BETA).
+        
+        Args:
             offset: Offset for pagination
-            limit: Number of results to return (max 250)
+            limit: Maximum number of results
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
+            format: Response format (json or xml)
             
         Returns:
-            List of Bill objects
+            List of HouseVote objects
         """
         ...
     
-    def get_member_cosponsored_legislation(
+    def iter_house_votes(
         self,
-        bioguide_id: str,
-        format: Optional[str] = None,
         offset: Optional[int] = None,
-        limit: Optional[int] = None,
-    ) -> List[Bill]:
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[HouseVote]:
         """
-        Get legislation cosponsored by a specified member.
+        Iterate over all house votes (BETA), fetching pages lazily.
         
         Args:
-            bioguide_id: The Bioguide ID of the member
-            format: Response format (json or xml)
-            offset: Offset for pagination
-            limit: Number of results to return (max 250)
+            offset: Offset of the first item (default 0)
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
             
         Returns:
-            List of Bill objects
+            Lazy async iterator over HouseVote objects
         """
         ...
     
-    def list_members_by_state(
+    async def list_house_votes_page(
         self,
-        state_code: str,
-        format: Optional[str] = None,
+        offset: Optional[int] = None,
         limit: Optional[int] = None,
-        current_member: Optional[bool] = None,
-    ) -> List[Sponsor]:
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
+        format: Optional[str] = None,
+    ) -> Page[HouseVote]:
         """
-        Get the list of members by state.
+        Get a list of house votes (BETA), returning a ``Page`` with pagination metadata.
         
         Args:
-            state_code: Two-letter state code (e.g., 'CA', 'NY')
+            offset: Offset for pagination
+            limit: Maximum number of results
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
             format: Response format (json or xml)
-            limit: Number of results to return (max 250)
-            current_member: Filter for current members only
             
         Returns:
-            List of Sponsor objects (representing members)
+            Page of HouseVote objects with the total count and next/prev links
         """
         ...
     
-    def list_members_by_state_district(
+    async def count_house_votes(
         self,
-        state_code: str,
-        district: int,
-        format: Optional[str] = None,
-        current_member: Optional[bool] = None,
-    ) -> List[Sponsor]:
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
+    ) -> int:
         """
-        Get the list of members by state and district.
+        Count house votes (BETA) with a single ``limit=1`` request.
         
         Args:
-            state_code: Two-letter state code (e.g., 'CA', 'NY')
-            district: Congressional district number
-            format: Response format (json or xml)
-            current_member: Filter for current members only
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
             
         Returns:
-            List of Sponsor objects (representing members)
+            Total number of matching items
         """
         ...
     
-    # Committee endpoints
-    
-    def list_committees(
+    async def list_house_votes_by_congress(
         self,
-        format: Optional[str] = None,
+        congress: int,
         offset: Optional[int] = None,
         limit: Optional[int] = None,
-    ) -> List[Committee]:
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
+        format: Optional[str] = None,
+    ) -> List[HouseVote]:
         """
-        Get a list of committees.
+        Get house votes for a specific congress (BETA).
         
         Args:
-            format: Response format (json or xml)
+            congress: Congress number (e.g., 118)
             offset: Offset for pagination
-            limit: Number of results to return (max 250)
+            limit: Maximum number of results
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
+            format: Response format (json or xml)
             
         Returns:
-            List of Committee objects
+            List of HouseVote objects
         """
         ...
     
-    # Congress/Session endpoints
-    
-    def list_congresses(
+    def iter_house_votes_by_congress(
         self,
-        format: Optional[str] = None,
+        congress: int,
         offset: Optional[int] = None,
-        limit: Optional[int] = None,
-    ) -> List[Congress]:
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[HouseVote]:
         """
-        Get a list of congresses and congressional sessions.
+        Iterate over all house votes for a specific congress (BETA), fetching pages lazily.
         
         Args:
-            format: Response format (json or xml)
-            offset: Offset for pagination
-            limit: Number of results to return (max 250)
+            congress: Congress number (e.g., 118)
+            offset: Offset of the first item (default 0)
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
             
         Returns:
-            List of Congress objects
+            Lazy async iterator over HouseVote objects
         """
         ...
     
-    def get_congress(
+    async def list_house_votes_by_congress_page(
         self,
         congress: int,
+        offset: Optional[int] = None,
+        limit: Optional[int] = None,
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
         format: Optional[str] = None,
-    ) -> Congress:
+    ) -> Page[HouseVote]:
         """
-        Get information about a specific congress.
+        Get house votes for a specific congress (BETA), returning a ``Page`` with pagination metadata.
         
         Args:
-            congress: The congress number (e.g., 117)
+            congress: Congress number (e.g., 118)
+            offset: Offset for pagination
+            limit: Maximum number of results
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
             format: Response format (json or xml)
             
         Returns:
-            Congress object
+            Page of HouseVote objects with the total count and next/prev links
         """
         ...
     
-    def get_current_congress(
+    async def count_house_votes_by_congress(
         self,
-        format: Optional[str] = None,
-    ) -> Congress:
+        congress: int,
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
+    ) -> int:
         """
-        Get information about the current congress.
+        Count house votes for a specific congress (BETA) with a single ``limit=1`` request.
         
         Args:
-            format: Response format (json or xml)
+            congress: Congress number (e.g., 118)
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
             
         Returns:
-            Congress object
+            Total number of matching items
         """
         ...
     
-    # House Vote Operations (BETA)
-    
-    def list_house_votes(
+    async def list_house_votes_by_session(
         self,
+        congress: int,
+        session: int,
         offset: Optional[int] = None,
         limit: Optional[int] = None,
-        from_date: Optional[str] = None,
-        to_date: Optional[str] = None,
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
         sort: Optional[str] = None,
         format: Optional[str] = None,
     ) -> List[HouseVote]:
         """
-        Get a list of house votes (BETA).
+        Get house votes for a specific congress and session (BETA).
         
         Args:
+            congress: Congress number (e.g., 118)
+            session: Session number (1 or 2)
             offset: Offset for pagination
             limit: Maximum number of results
-            from_date: Filter votes from this date (ISO format)
-            to_date: Filter votes to this date (ISO format)
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
             sort: Sort order
             format: Response format (json or xml)
             
@@ -806,63 +6601,88 @@ class CDGPythonClient:
         """
         ...
     
-    def list_house_votes_by_congress(
+    def iter_house_votes_by_session(
         self,
         congress: int,
+        session: int,
         offset: Optional[int] = None,
-        limit: Optional[int] = None,
-        from_date: Optional[str] = None,
-        to_date: Optional[str] = None,
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
         sort: Optional[str] = None,
-        format: Optional[str] = None,
-    ) -> List[HouseVote]:
+        page_size: Optional[int] = None,
+        max_items: Optional[int] = None,
+    ) -> AsyncPageIterator[HouseVote]:
         """
-        Get house votes for a specific congress (BETA).
+        Iterate over all house votes for a specific congress and session (BETA), fetching pages lazily.
         
         Args:
             congress: Congress number (e.g., 118)
-            offset: Offset for pagination
-            limit: Maximum number of results
-            from_date: Filter votes from this date (ISO format)
-            to_date: Filter votes to this date (ISO format)
+            session: Session number (1 or 2)
+            offset: Offset of the first item (default 0)
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
             sort: Sort order
-            format: Response format (json or xml)
+            page_size: Items requested per page (1-250, default 250)
+            max_items: Stop after this many items (default: no limit)
             
         Returns:
-            List of HouseVote objects
+            Lazy async iterator over HouseVote objects
         """
         ...
     
-    def list_house_votes_by_session(
+    async def list_house_votes_by_session_page(
         self,
         congress: int,
         session: int,
         offset: Optional[int] = None,
         limit: Optional[int] = None,
-        from_date: Optional[str] = None,
-        to_date: Optional[str] = None,
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
         sort: Optional[str] = None,
         format: Optional[str] = None,
-    ) -> List[HouseVote]:
+    ) -> Page[HouseVote]:
         """
-        Get house votes for a specific congress and session (BETA).
+        Get house votes for a specific congress and session (BETA), returning a ``Page`` with pagination metadata.
         
         Args:
             congress: Congress number (e.g., 118)
             session: Session number (1 or 2)
             offset: Offset for pagination
             limit: Maximum number of results
-            from_date: Filter votes from this date (ISO format)
-            to_date: Filter votes to this date (ISO format)
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
             sort: Sort order
             format: Response format (json or xml)
             
         Returns:
-            List of HouseVote objects
+            Page of HouseVote objects with the total count and next/prev links
         """
         ...
     
-    def get_house_vote(
+    async def count_house_votes_by_session(
+        self,
+        congress: int,
+        session: int,
+        from_date: Optional[_DateArg] = None,
+        to_date: Optional[_DateArg] = None,
+        sort: Optional[str] = None,
+    ) -> int:
+        """
+        Count house votes for a specific congress and session (BETA) with a single ``limit=1`` request.
+        
+        Args:
+            congress: Congress number (e.g., 118)
+            session: Session number (1 or 2)
+            from_date: Filter votes from this date (datetime, date or ISO 8601 string)
+            to_date: Filter votes to this date, inclusive (datetime, date or ISO 8601 string)
+            sort: Sort order
+            
+        Returns:
+            Total number of matching items
+        """
+        ...
+    
+    async def get_house_vote(
         self,
         congress: int,
         session: int,
@@ -883,7 +6703,7 @@ class CDGPythonClient:
         """
         ...
     
-    def get_house_vote_members(
+    async def get_house_vote_members(
         self,
         congress: int,
         session: int,
@@ -908,44 +6728,49 @@ class CDGPythonClient:
         """
         ...
 
-class Session:
+class Session(_Model):
     """Represents a Congressional session."""
-    chamber: Optional[str]
+    chamber: Optional[Union[Chamber, str]]
     number: Optional[int]
-    start_date: Optional[str]
-    end_date: Optional[str]
+    start_date: Optional[_Date]
+    end_date: Optional[_Date]
+    extra: Dict[str, Any]
     
     def __repr__(self) -> str: ...
 
-class Congress:
+class Congress(_Model):
     """Represents a Congress with its sessions."""
     end_year: Optional[str]
     name: Optional[str]
     sessions: Optional[List[Session]]
     start_year: Optional[str]
     url: Optional[str]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
     
     def __repr__(self) -> str: ...
 
-class Party:
+class Party(_Model):
     """Represents a political party."""
     name: Optional[str]
-    party_type: Optional[str]
+    party_type: Optional[Union[PartyCode, str]]
+    extra: Dict[str, Any]
     
     def __repr__(self) -> str: ...
 
-class VoteParty:
+class VoteParty(_Model):
     """Represents vote totals by party."""
     nay_total: Optional[int]
     not_voting_total: Optional[int]
     present_total: Optional[int]
-    vote_party: Optional[str]
+    vote_party: Optional[Union[PartyCode, str]]
     yea_total: Optional[int]
     party: Optional[Party]
+    extra: Dict[str, Any]
     
     def __repr__(self) -> str: ...
 
-class HouseVote:
+class HouseVote(_Model):
     """Represents a House of Representatives roll call vote."""
     congress: Optional[int]
     identifier: Optional[int]
@@ -956,14 +6781,16 @@ class HouseVote:
     roll_call_number: Optional[int]
     session_number: Optional[int]
     source_data_url: Optional[str]
-    start_date: Optional[str]
-    update_date: Optional[str]
+    start_date: Optional[_Date]
+    update_date: Optional[_Date]
     url: Optional[str]
     vote_type: Optional[str]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
     
     def __repr__(self) -> str: ...
 
-class HouseVoteDetail:
+class HouseVoteDetail(_Model):
     """Represents detailed house vote information with party totals."""
     congress: Optional[int]
     identifier: Optional[int]
@@ -974,26 +6801,29 @@ class HouseVoteDetail:
     roll_call_number: Optional[int]
     session_number: Optional[int]
     source_data_url: Optional[str]
-    start_date: Optional[str]
-    update_date: Optional[str]
+    start_date: Optional[_Date]
+    update_date: Optional[_Date]
     vote_type: Optional[str]
     vote_party_total: Optional[List[VoteParty]]
     vote_question: Optional[str]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
     
     def __repr__(self) -> str: ...
 
-class MemberVote:
+class MemberVote(_Model):
     """Represents how a member voted."""
     bioguide_id: Optional[str]
     first_name: Optional[str]
     last_name: Optional[str]
-    vote_cast: Optional[str]
-    vote_party: Optional[str]
+    vote_cast: Optional[Union[VoteCast, str]]
+    vote_party: Optional[Union[PartyCode, str]]
     vote_state: Optional[str]
+    extra: Dict[str, Any]
     
     def __repr__(self) -> str: ...
 
-class HouseVoteMembers:
+class HouseVoteMembers(_Model):
     """Represents house vote with member voting details."""
     congress: Optional[int]
     identifier: Optional[int]
@@ -1004,16 +6834,288 @@ class HouseVoteMembers:
     roll_call_number: Optional[int]
     session_number: Optional[int]
     source_data_url: Optional[str]
-    start_date: Optional[str]
-    update_date: Optional[str]
+    start_date: Optional[_Date]
+    update_date: Optional[_Date]
     vote_type: Optional[str]
     results: Optional[List[MemberVote]]
     vote_question: Optional[str]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
+    
+    def __repr__(self) -> str: ...
+
+class HearingCommittee(_Model):
+    """A committee or subcommittee holding a hearing or meeting."""
+    name: Optional[str]
+    system_code: Optional[str]
+    url: Optional[str]
+    extra: Dict[str, Any]
+    
+    def __repr__(self) -> str: ...
+
+class CommitteeMeeting(_Model):
+    """Represents a committee meeting in list responses."""
+    chamber: Optional[Union[Chamber, str]]
+    congress: Optional[int]
+    event_id: Optional[str]
+    update_date: Optional[_Date]
+    url: Optional[str]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
+    
+    def __repr__(self) -> str: ...
+
+class MeetingLocation(_Model):
+    """Where a committee meeting is held."""
+    building: Optional[str]
+    room: Optional[str]
+    extra: Dict[str, Any]
+    
+    def __repr__(self) -> str: ...
+
+class Witness(_Model):
+    """A witness at a committee meeting."""
+    name: Optional[str]
+    organization: Optional[str]
+    position: Optional[str]
+    extra: Dict[str, Any]
+    
+    def __repr__(self) -> str: ...
+
+class WitnessDocument(_Model):
+    """A statement or other document submitted by a witness."""
+    document_type: Optional[str]
+    format: Optional[str]
+    url: Optional[str]
+    extra: Dict[str, Any]
+    
+    def __repr__(self) -> str: ...
+
+class MeetingDocument(_Model):
+    """A notice, agenda or other document posted for a committee meeting."""
+    name: Optional[str]
+    description: Optional[str]
+    document_type: Optional[str]
+    format: Optional[str]
+    url: Optional[str]
+    extra: Dict[str, Any]
+    
+    def __repr__(self) -> str: ...
+
+class MeetingVideo(_Model):
+    """A video recording of a committee meeting."""
+    name: Optional[str]
+    url: Optional[str]
+    extra: Dict[str, Any]
+    
+    def __repr__(self) -> str: ...
+
+class MeetingBill(_Model):
+    """A bill considered at a committee meeting."""
+    congress: Optional[int]
+    number: Optional[int]
+    bill_type: Optional[Union[BillType, str]]
+    url: Optional[str]
+    extra: Dict[str, Any]
+    
+    def __repr__(self) -> str: ...
+
+class MeetingNomination(_Model):
+    """A nomination considered at a committee meeting."""
+    congress: Optional[int]
+    number: Optional[int]
+    url: Optional[str]
+    extra: Dict[str, Any]
+    
+    def __repr__(self) -> str: ...
+
+class MeetingTreaty(_Model):
+    """A treaty considered at a committee meeting."""
+    congress: Optional[int]
+    number: Optional[int]
+    url: Optional[str]
+    extra: Dict[str, Any]
+    
+    def __repr__(self) -> str: ...
+
+class MeetingRelatedItems(_Model):
+    """Bills, nominations and treaties considered at a committee meeting."""
+    bills: Optional[List[MeetingBill]]
+    nominations: Optional[List[MeetingNomination]]
+    treaties: Optional[List[MeetingTreaty]]
+    extra: Dict[str, Any]
+    
+    def __repr__(self) -> str: ...
+
+class CommitteeMeetingDetail(_Model):
+    """Represents detailed information about a committee meeting."""
+    chamber: Optional[Union[Chamber, str]]
+    congress: Optional[int]
+    event_id: Optional[str]
+    title: Optional[str]
+    meeting_type: Optional[str]
+    meeting_status: Optional[str]
+    date: Optional[_Date]
+    update_date: Optional[_Date]
+    location: Optional[MeetingLocation]
+    committees: Optional[List[HearingCommittee]]
+    witnesses: Optional[List[Witness]]
+    witness_documents: Optional[List[WitnessDocument]]
+    meeting_documents: Optional[List[MeetingDocument]]
+    related_items: Optional[MeetingRelatedItems]
+    videos: Optional[List[MeetingVideo]]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
+    
+    def __repr__(self) -> str: ...
+
+class CommunicationTypeInfo(_Model):
+    """The code and name of a communication's type."""
+    code: Optional[Union[CommunicationType, str]]
+    name: Optional[str]
+    extra: Dict[str, Any]
+    
+    def __repr__(self) -> str: ...
+
+class Communication(_Model):
+    """Represents a House or Senate communication in list responses."""
+    chamber: Optional[Union[Chamber, str]]
+    communication_type: Optional[CommunicationTypeInfo]
+    congress: Optional[int]
+    number: Optional[int]
+    referral_date: Optional[_Date]
+    update_date: Optional[_Date]
+    url: Optional[str]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
+    
+    def __repr__(self) -> str: ...
+
+class CommunicationCommittee(_Model):
+    """A committee a communication was referred to."""
+    name: Optional[str]
+    system_code: Optional[str]
+    referral_date: Optional[_Date]
+    url: Optional[str]
+    extra: Dict[str, Any]
+    
+    def __repr__(self) -> str: ...
+
+class MatchingRequirement(_Model):
+    """A House requirement a communication was filed under."""
+    number: Optional[int]
+    url: Optional[str]
+    extra: Dict[str, Any]
+    
+    def __repr__(self) -> str: ...
+
+class CommunicationDetail(_Model):
+    """Detailed information for a House or Senate communication."""
+    abstract_text: Optional[str]
+    chamber: Optional[Union[Chamber, str]]
+    committees: Optional[List[CommunicationCommittee]]
+    communication_type: Optional[CommunicationTypeInfo]
+    congress: Optional[int]
+    congressional_record_date: Optional[_Date]
+    is_rulemaking: Optional[bool]
+    legal_authority: Optional[str]
+    matching_requirements: Optional[List[MatchingRequirement]]
+    number: Optional[int]
+    report_nature: Optional[str]
+    session_number: Optional[int]
+    submitting_agency: Optional[str]
+    submitting_official: Optional[str]
+    update_date: Optional[_Date]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
+    
+    def __repr__(self) -> str: ...
+
+class ResourceCount(_Model):
+    """The size of a related list and the URL that returns it."""
+    count: Optional[int]
+    url: Optional[str]
+    extra: Dict[str, Any]
+    
+    def __repr__(self) -> str: ...
+
+class HouseRequirement(_Model):
+    """A reporting requirement the House places on executive agencies."""
+    number: Optional[int]
+    active_record: Optional[bool]
+    frequency: Optional[str]
+    legal_authority: Optional[str]
+    nature: Optional[str]
+    submitting_agency: Optional[str]
+    parent_agency: Optional[str]
+    matching_communications: Optional[ResourceCount]
+    update_date: Optional[_Date]
+    url: Optional[str]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
+    
+    def __repr__(self) -> str: ...
+
+class CommitteeDetailInfo(_Model):
+    """Represents detailed committee information."""
+    bills: Optional[ResourceCount]
+    communications: Optional[ResourceCount]
+    history: Optional[List[Any]]
+    is_current: Optional[bool]
+    reports: Optional[ResourceCount]
+    subcommittees: Optional[List[Any]]
+    system_code: Optional[str]
+    committee_type: Optional[str]
+    update_date: Optional[_Date]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
+    
+    def __repr__(self) -> str: ...
+
+class CommitteeReportItem(_Model):
+    """Represents a committee report."""
+    citation: Optional[str]
+    congress: Optional[int]
+    number: Optional[str]
+    part: Optional[int]
+    report_type: Optional[Union[ReportType, str]]
+    update_date: Optional[_Date]
+    url: Optional[str]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
+    
+    def __repr__(self) -> str: ...
+
+class Nomination(_Model):
+    """Represents a nomination."""
+    congress: Optional[int]
+    number: Optional[str]
+    part_number: Optional[str]
+    citation: Optional[str]
+    description: Optional[str]
+    received_date: Optional[_Date]
+    update_date: Optional[_Date]
+    url: Optional[str]
+    extra: Dict[str, Any]
+    raw: Optional[Dict[str, Any]]
     
     def __repr__(self) -> str: ...
 
 __all__ = [
     "CDGPythonClient",
+    "AsyncCDGPythonClient",
+    "RetryStats",
+    "RateLimitStatus",
+    "CacheStats",
+    "CongressApiError",
+    "BadRequestError",
+    "AuthenticationError",
+    "NotFoundError",
+    "RateLimitError",
+    "ServerError",
+    "DecodeError",
+    "NetworkError",
+    "CassetteError",
     "Bill",
     "BillDetail",
     "LatestAction",
@@ -0,0 +1,156 @@
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::bills::{Amendment, LatestAction};
+use crate::dates::ApiDate;
+use crate::enums::{AmendmentType, BillType, Chamber, Code};
+use crate::members::Sponsor;
+use crate::serialization::model_methods;
+
+/// Detailed information for a single amendment
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
+pub struct AmendmentDetail {
+    #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub congress: Option<i32>,
+    
+    #[pyo3(get)]
+    pub number: Option<String>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "type")]
+    pub amendment_type: Option<Code<AmendmentType>>,
+    
+    #[pyo3(get)]
+    pub chamber: Option<Code<Chamber>>,
+    
+    #[pyo3(get)]
+    pub purpose: Option<String>,
+    
+    #[pyo3(get)]
+    pub description: Option<String>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "proposedDate")]
+    pub proposed_date: Option<ApiDate>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "submittedDate")]
+    pub submitted_date: Option<ApiDate>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "latestAction")]
+    pub latest_action: Option<LatestAction>,
+    
+    #[pyo3(get)]
+    pub sponsors: Option<Vec<Sponsor>>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "amendedBill")]
+    pub amended_bill: Option<AmendedBill>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "amendedAmendment")]
+    pub amended_amendment: Option<Amendment>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "amendedTreaty")]
+    pub amended_treaty: Option<AmendedTreaty>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "updateDate")]
+    pub update_date: Option<ApiDate>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
+}
+
+model_methods!(raw AmendmentDetail {
+    fn __repr__(&self) -> String {
+        format!(
+            "AmendmentDetail(congress={:?}, number={:?}, type={:?}, purpose={:?})",
+            self.congress, self.number, self.amendment_type, self.purpose
+        )
+    }
+});
+
+/// The bill an amendment amends
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
+pub struct AmendedBill {
+    #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub congress: Option<i32>,
+    
+    #[pyo3(get)]
+    pub number: Option<String>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "type")]
+    pub bill_type: Option<Code<BillType>>,
+    
+    #[pyo3(get)]
+    pub title: Option<String>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "originChamber")]
+    pub origin_chamber: Option<Code<Chamber>>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "originChamberCode")]
+    pub origin_chamber_code: Option<String>,
+    
+    #[pyo3(get)]
+    pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+model_methods!(AmendedBill {
+    fn __repr__(&self) -> String {
+        format!(
+            "AmendedBill(congress={:?}, number={:?}, type={:?})",
+            self.congress, self.number, self.bill_type
+        )
+    }
+});
+
+/// The treaty an amendment amends
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
+pub struct AmendedTreaty {
+    #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub congress: Option<i32>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "treatyNumber")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub treaty_number: Option<i32>,
+    
+    #[pyo3(get)]
+    pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+model_methods!(AmendedTreaty {
+    fn __repr__(&self) -> String {
+        format!(
+            "AmendedTreaty(congress={:?}, treaty_number={:?})",
+            self.congress, self.treaty_number
+        )
+    }
+});
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmendmentDetailResponse {
+    #[serde(deserialize_with = "crate::raw::one")]
+    pub amendment: AmendmentDetail,
+}
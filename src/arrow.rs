@@ -0,0 +1,339 @@
+use arrow_array::ffi::{to_ffi, FFI_ArrowSchema};
+use arrow_array::types::Date32Type;
+use arrow_array::{
+    Array, ArrayRef, BooleanArray, Date32Array, Float64Array, Int64Array, NullArray, StringArray,
+    TimestampMicrosecondArray,
+};
+use chrono::{DateTime, Utc};
+use pyo3::exceptions::{PyImportError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyCapsule, PyDict, PyType};
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::dates::ApiDate;
+use crate::raw;
+use crate::serialization::{self, ReadModel};
+
+/// Column type, inferred from the values seen in it
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Kind {
+    Null,
+    Bool,
+    Int,
+    Float,
+    Date,
+    Timestamp,
+    Text,
+}
+
+/// One value in a column. Strings that parse as dates keep the parsed form
+/// next to the text, so each is parsed at most once.
+enum Cell {
+    Json(Value),
+    Date(String, ApiDate),
+}
+
+impl Cell {
+    fn json(&self) -> Option<&Value> {
+        match self {
+            Cell::Json(value) => Some(value),
+            Cell::Date(..) => None,
+        }
+    }
+
+    /// The cell as a UTC instant; plain dates are taken as midnight UTC
+    fn utc(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Cell::Date(_, ApiDate::Date(day)) => day.and_hms_opt(0, 0, 0).map(|midnight| midnight.and_utc()),
+            Cell::Date(_, ApiDate::DateTime(datetime)) => Some(datetime.with_timezone(&Utc)),
+            _ => None,
+        }
+    }
+
+    fn text(&self) -> Option<Cow<'_, str>> {
+        match self {
+            Cell::Json(Value::Null) => None,
+            Cell::Json(Value::String(text)) | Cell::Date(text, _) => Some(Cow::Borrowed(text)),
+            Cell::Json(other) => Some(Cow::Owned(other.to_string())),
+        }
+    }
+}
+
+impl Kind {
+    fn of(cell: &Cell) -> Self {
+        match cell {
+            Cell::Json(Value::Null) => Kind::Null,
+            Cell::Json(Value::Bool(_)) => Kind::Bool,
+            Cell::Json(Value::Number(n)) if n.is_i64() => Kind::Int,
+            Cell::Json(Value::Number(_)) => Kind::Float,
+            Cell::Date(_, ApiDate::Date(_)) => Kind::Date,
+            Cell::Date(..) => Kind::Timestamp,
+            Cell::Json(_) => Kind::Text,
+        }
+    }
+
+    /// The narrowest kind that holds values of both kinds
+    fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (a, b) if a == b => a,
+            (Kind::Null, kind) | (kind, Kind::Null) => kind,
+            (Kind::Int, Kind::Float) | (Kind::Float, Kind::Int) => Kind::Float,
+            // Plain dates in a timestamp column are taken as midnight UTC
+            (Kind::Date, Kind::Timestamp) | (Kind::Timestamp, Kind::Date) => Kind::Timestamp,
+            _ => Kind::Text,
+        }
+    }
+
+    fn to_py(self, py: Python<'_>, cell: &Cell) -> PyResult<PyObject> {
+        Ok(match (self, cell) {
+            (_, Cell::Json(Value::Null)) => py.None(),
+            (Kind::Text, cell) => cell.text().into_pyobject(py)?.into_any().unbind(),
+            (Kind::Timestamp, cell) => cell.utc().into_pyobject(py)?.into_any().unbind(),
+            (_, Cell::Date(_, date)) => date.into_pyobject(py)?.unbind(),
+            (_, Cell::Json(value)) => raw::to_py(py, value)?,
+        })
+    }
+}
+
+/// Models flattened into columns, in the order fields are first seen.
+/// Nested objects become dotted names (`latestAction.actionDate`); lists
+/// are kept as JSON text.
+#[derive(Default)]
+struct Columns {
+    names: Vec<String>,
+    kinds: Vec<Kind>,
+    values: Vec<Vec<Cell>>,
+    index: HashMap<String, usize>,
+    rows: usize,
+}
+
+impl Columns {
+    fn collect(items: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let mut columns = Self::default();
+        // Lists hold one model type, so the reader is looked up again only
+        // when the type changes
+        let mut reader: Option<(Bound<'_, PyType>, Option<ReadModel>)> = None;
+        for item in items.try_iter()? {
+            let item = item?;
+            let ty = item.get_type();
+            let read = match &reader {
+                Some((seen, read)) if seen.is(&ty) => *read,
+                _ => {
+                    let read = serialization::model_reader(&ty);
+                    reader = Some((ty, read));
+                    read
+                }
+            };
+            columns.push(row(&item, read)?);
+        }
+        columns.drop_empty_parents();
+        Ok(columns)
+    }
+
+    fn push(&mut self, row: Map<String, Value>) {
+        let mut fields = Vec::new();
+        flatten(None, row, &mut fields);
+        for (name, value) in fields {
+            let column = match self.index.get(&name) {
+                Some(&column) => column,
+                None => {
+                    self.index.insert(name.clone(), self.names.len());
+                    self.names.push(name);
+                    self.kinds.push(Kind::Null);
+                    self.values.push(Vec::new());
+                    self.values.last_mut().unwrap().resize_with(self.rows, || Cell::Json(Value::Null));
+                    self.names.len() - 1
+                }
+            };
+            // A literal dotted key can collide with a flattened one; the
+            // first wins
+            if self.values[column].len() > self.rows {
+                continue;
+            }
+            // Once a column is text its strings stay text, so they need no
+            // date parsing
+            let cell = match value {
+                Value::String(text) if self.kinds[column] != Kind::Text => match ApiDate::parse(&text) {
+                    ApiDate::Text(_) => Cell::Json(Value::String(text)),
+                    date => Cell::Date(text, date),
+                },
+                value => Cell::Json(value),
+            };
+            if self.kinds[column] != Kind::Text {
+                self.kinds[column] = self.kinds[column].merge(Kind::of(&cell));
+            }
+            self.values[column].push(cell);
+        }
+        self.rows += 1;
+        for values in &mut self.values {
+            values.resize_with(self.rows, || Cell::Json(Value::Null));
+        }
+    }
+
+    /// Drop columns like `latestAction` that were only ever null while
+    /// other rows filled in `latestAction.*`
+    fn drop_empty_parents(&mut self) {
+        let parents: Vec<usize> = (0..self.names.len())
+            .filter(|&column| {
+                let prefix = format!("{}.", self.names[column]);
+                self.kinds[column] == Kind::Null && self.names.iter().any(|name| name.starts_with(&prefix))
+            })
+            .collect();
+        for &column in parents.iter().rev() {
+            self.names.remove(column);
+            self.kinds.remove(column);
+            self.values.remove(column);
+        }
+        self.index.clear();
+    }
+
+    fn column(&self, py: Python<'_>, column: usize) -> PyResult<Vec<PyObject>> {
+        let kind = self.kinds[column];
+        self.values[column].iter().map(|cell| kind.to_py(py, cell)).collect()
+    }
+
+    /// The column as an Arrow array, built without Python objects per value
+    fn array(&self, column: usize) -> ArrayRef {
+        let cells = &self.values[column];
+        match self.kinds[column] {
+            Kind::Null => Arc::new(NullArray::new(cells.len())),
+            Kind::Bool => Arc::new(cells.iter().map(|cell| cell.json().and_then(Value::as_bool)).collect::<BooleanArray>()),
+            Kind::Int => Arc::new(cells.iter().map(|cell| cell.json().and_then(Value::as_i64)).collect::<Int64Array>()),
+            Kind::Float => Arc::new(cells.iter().map(|cell| cell.json().and_then(Value::as_f64)).collect::<Float64Array>()),
+            Kind::Date => Arc::new(
+                cells
+                    .iter()
+                    .map(|cell| match cell {
+                        Cell::Date(_, ApiDate::Date(day)) => Some(Date32Type::from_naive_date(*day)),
+                        _ => None,
+                    })
+                    .collect::<Date32Array>(),
+            ),
+            Kind::Timestamp => Arc::new(
+                cells
+                    .iter()
+                    .map(|cell| cell.utc().map(|instant| instant.timestamp_micros()))
+                    .collect::<TimestampMicrosecondArray>()
+                    .with_timezone("UTC"),
+            ),
+            Kind::Text => Arc::new(cells.iter().map(Cell::text).collect::<StringArray>()),
+        }
+    }
+}
+
+fn flatten(prefix: Option<&str>, fields: Map<String, Value>, out: &mut Vec<(String, Value)>) {
+    for (key, value) in fields {
+        let name = match prefix {
+            Some(prefix) => format!("{}.{}", prefix, key),
+            None => key,
+        };
+        match value {
+            Value::Object(fields) => flatten(Some(&name), fields, out),
+            value => out.push((name, value)),
+        }
+    }
+}
+
+fn row(item: &Bound<'_, PyAny>, read: Option<ReadModel>) -> PyResult<Map<String, Value>> {
+    let value = match read {
+        Some(read) => read(item)?,
+        None if item.is_instance_of::<PyDict>() => raw::from_py(item)?,
+        None => Value::Null,
+    };
+    match value {
+        Value::Object(fields) => Ok(fields),
+        _ => Err(PyTypeError::new_err(format!(
+            "expected a model or dict, got {}",
+            item.get_type().name()?
+        ))),
+    }
+}
+
+/// One column exported through the Arrow PyCapsule interface, which
+/// `pyarrow.array()` and other Arrow libraries read without copying
+#[pyclass(module = "cdg_python_client", frozen)]
+struct ArrowColumn {
+    array: ArrayRef,
+}
+
+#[pymethods]
+impl ArrowColumn {
+    /// The column's type as an `arrow_schema` capsule
+    fn __arrow_c_schema__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyCapsule>> {
+        let schema = FFI_ArrowSchema::try_from(self.array.data_type()).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        PyCapsule::new(py, schema, Some(c"arrow_schema".to_owned()))
+    }
+
+    /// The column as `arrow_schema` and `arrow_array` capsules. The data is
+    /// always sent in its own type; consumers cast if they asked for another.
+    /// A consumer moves the structs out of the capsules, and whatever it
+    /// leaves is released when they are freed.
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_array__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<&Bound<'py, PyAny>>,
+    ) -> PyResult<(Bound<'py, PyCapsule>, Bound<'py, PyCapsule>)> {
+        let _ = requested_schema;
+        let (array, schema) = to_ffi(&self.array.to_data()).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok((
+            PyCapsule::new(py, schema, Some(c"arrow_schema".to_owned()))?,
+            PyCapsule::new(py, array, Some(c"arrow_array".to_owned()))?,
+        ))
+    }
+}
+
+/// Hand an array to pyarrow through the Arrow PyCapsule interface
+fn export<'py>(pa: &Bound<'py, PyModule>, array: ArrayRef) -> PyResult<Bound<'py, PyAny>> {
+    pa.getattr("array")?.call1((ArrowColumn { array },))
+}
+
+/// Import an optional dependency, naming the helper that needs it
+fn require<'py>(py: Python<'py>, module: &str, helper: &str) -> PyResult<Bound<'py, PyModule>> {
+    py.import(module).map_err(|e| {
+        if e.is_instance_of::<PyImportError>(py) {
+            PyImportError::new_err(format!(
+                "{}() requires {}; install it with `pip install {}`",
+                helper, module, module
+            ))
+        } else {
+            e
+        }
+    })
+}
+
+/// Flatten models (or dicts) into a dict of column name -> list of values.
+/// Needs no extra packages; `polars.DataFrame` takes the result directly.
+#[pyfunction]
+pub fn to_columns<'py>(py: Python<'py>, items: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyDict>> {
+    let columns = Columns::collect(items)?;
+    let dict = PyDict::new(py);
+    for (column, name) in columns.names.iter().enumerate() {
+        dict.set_item(name, columns.column(py, column)?)?;
+    }
+    Ok(dict)
+}
+
+/// Flatten models (or dicts) into a `pyarrow.RecordBatch` with typed
+/// columns: integers, floats, booleans, `date32`, UTC timestamps and strings
+#[pyfunction]
+pub fn to_arrow<'py>(py: Python<'py>, items: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let pa = require(py, "pyarrow", "to_arrow")?;
+    let columns = Columns::collect(items)?;
+    let arrays = (0..columns.names.len())
+        .map(|column| export(&pa, columns.array(column)))
+        .collect::<PyResult<Vec<_>>>()?;
+    pa.getattr("RecordBatch")?
+        .call_method1("from_arrays", (arrays, columns.names))
+}
+
+/// `to_arrow(items).to_pandas()`
+#[pyfunction]
+pub fn to_pandas<'py>(py: Python<'py>, items: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    require(py, "pandas", "to_pandas")?;
+    require(py, "pyarrow", "to_pandas")?;
+    to_arrow(py, items)?.call_method0("to_pandas")
+}
@@ -0,0 +1,137 @@
+use pyo3::prelude::*;
+use pyo3_async_runtimes::tokio::future_into_py;
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::client::CongressApiClient;
+use crate::endpoints::{endpoint_methods, Dispatch};
+use crate::pagination::{fetch_page_async, AsyncPageIterator, Paginated};
+
+// PyO3 wrapper class for Congress.gov API, for use with asyncio
+#[pyclass(frozen)]
+pub struct AsyncCDGPythonClient {
+    client: Arc<CongressApiClient>,
+}
+
+impl Dispatch for AsyncCDGPythonClient {
+    type Output<'py, T: 'py> = Bound<'py, PyAny>;
+    type Iter = AsyncPageIterator;
+
+    fn api(&self) -> Arc<CongressApiClient> {
+        Arc::clone(&self.client)
+    }
+
+    /// Hands the request to the Tokio runtime as an awaitable
+    fn run<'py, T>(
+        &self,
+        py: Python<'py>,
+        request: impl Future<Output = PyResult<T>> + Send + 'static,
+    ) -> PyResult<Bound<'py, PyAny>>
+    where
+        T: for<'a> IntoPyObject<'a> + Send + 'static,
+    {
+        future_into_py(py, request)
+    }
+
+    fn iter<R: Paginated>(
+        &self,
+        endpoint: &str,
+        params: HashMap<String, String>,
+        offset: Option<u64>,
+        page_size: Option<u32>,
+        max_items: Option<usize>,
+    ) -> PyResult<AsyncPageIterator> {
+        AsyncPageIterator::new(
+            self.api(),
+            endpoint.to_string(),
+            params,
+            offset,
+            page_size,
+            max_items,
+            fetch_page_async::<R>,
+        )
+    }
+}
+
+endpoint_methods!(AsyncCDGPythonClient {
+    /// Create an asyncio client.
+    ///
+    /// Takes the same options as `CDGPythonClient`; every endpoint method
+    /// returns an awaitable instead of blocking. `max_concurrency` caps how
+    /// many requests may be in flight at once across all tasks sharing this
+    /// client (`None` for no cap).
+    #[new]
+    #[pyo3(signature = (
+        api_key,
+        max_retries=3,
+        backoff_factor=0.5,
+        max_backoff=30.0,
+        rate_limit=None,
+        rate_limit_period=3600.0,
+        rate_limit_mode="block",
+        base_url=None,
+        timeout=Some(30.0),
+        connect_timeout=Some(10.0),
+        read_timeout=None,
+        proxy=None,
+        ca_bundle=None,
+        user_agent=None,
+        max_concurrency=Some(10),
+        cache_dir=None,
+        cache_ttl=Some(86400.0),
+        cache_ttls=None,
+        mode="live",
+        cassette_dir=None,
+    ))]
+    pub fn new(
+        api_key: String,
+        max_retries: u32,
+        backoff_factor: f64,
+        max_backoff: f64,
+        rate_limit: Option<u32>,
+        rate_limit_period: f64,
+        rate_limit_mode: &str,
+        base_url: Option<&str>,
+        timeout: Option<f64>,
+        connect_timeout: Option<f64>,
+        read_timeout: Option<f64>,
+        proxy: Option<String>,
+        ca_bundle: Option<PathBuf>,
+        user_agent: Option<String>,
+        max_concurrency: Option<usize>,
+        cache_dir: Option<PathBuf>,
+        cache_ttl: Option<f64>,
+        cache_ttls: Option<HashMap<String, Option<f64>>>,
+        mode: &str,
+        cassette_dir: Option<PathBuf>,
+    ) -> PyResult<Self> {
+        let client = CongressApiClient::from_options(
+            api_key,
+            max_retries,
+            backoff_factor,
+            max_backoff,
+            rate_limit,
+            rate_limit_period,
+            rate_limit_mode,
+            base_url,
+            timeout,
+            connect_timeout,
+            read_timeout,
+            proxy,
+            ca_bundle,
+            user_agent,
+            max_concurrency,
+            cache_dir,
+            cache_ttl,
+            cache_ttls,
+            mode,
+            cassette_dir,
+        )?;
+        
+        Ok(Self {
+            client: Arc::new(client),
+        })
+    }
+});
@@ -1,34 +1,41 @@
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::dates::ApiDate;
+use crate::enums::{AmendmentType, BillType, Chamber, Code, LawType};
 use crate::members::Sponsor;
+use crate::pagination::{Pagination, RequestEcho};
+use crate::serialization::model_methods;
 
 // Response structures
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct LatestAction {
     #[pyo3(get)]
     #[serde(rename = "actionDate")]
-    pub action_date: Option<String>,
+    pub action_date: Option<ApiDate>,
     
     #[pyo3(get)]
     pub text: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[pymethods]
-impl LatestAction {
+model_methods!(LatestAction {
     fn __repr__(&self) -> String {
         format!(
             "LatestAction(action_date={:?}, text={:?})",
             self.action_date, self.text
         )
     }
-}
+});
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct Bill {
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub congress: Option<i32>,
     
     #[pyo3(get)]
@@ -40,7 +47,7 @@ pub struct Bill {
     
     #[pyo3(get)]
     #[serde(rename = "originChamber")]
-    pub origin_chamber: Option<String>,
+    pub origin_chamber: Option<Code<Chamber>>,
     
     #[pyo3(get)]
     #[serde(rename = "originChamberCode")]
@@ -51,78 +58,95 @@ pub struct Bill {
     
     #[pyo3(get)]
     #[serde(rename = "type")]
-    pub bill_type: Option<String>,
+    pub bill_type: Option<Code<BillType>>,
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "updateDateIncludingText")]
-    pub update_date_including_text: Option<String>,
+    pub update_date_including_text: Option<ApiDate>,
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl Bill {
+model_methods!(raw Bill {
     fn __repr__(&self) -> String {
         format!(
             "Bill(congress={:?}, number={:?}, title={:?}, type={:?})",
             self.congress, self.number, self.title, self.bill_type
         )
     }
-}
+});
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BillsResponse {
+    #[serde(deserialize_with = "crate::raw::list")]
     pub bills: Vec<Bill>,
+    pub pagination: Option<Pagination>,
+    pub request: Option<RequestEcho>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct Law {
     #[pyo3(get)]
     pub number: Option<String>,
     
     #[pyo3(get)]
     #[serde(rename = "type")]
-    pub law_type: Option<String>,
+    pub law_type: Option<Code<LawType>>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[pymethods]
-impl Law {
+model_methods!(Law {
     fn __repr__(&self) -> String {
         format!("Law(number={:?}, type={:?})", self.number, self.law_type)
     }
-}
+});
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct PolicyArea {
     #[pyo3(get)]
     pub name: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl PolicyArea {
+model_methods!(raw PolicyArea {
     fn __repr__(&self) -> String {
         format!("PolicyArea(name={:?})", self.name)
     }
-}
+});
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct RelatedCount {
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub count: Option<i32>,
     pub url: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct BillDetail {
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub congress: Option<i32>,
     
     #[pyo3(get)]
@@ -134,7 +158,7 @@ pub struct BillDetail {
     
     #[pyo3(get)]
     #[serde(rename = "originChamber")]
-    pub origin_chamber: Option<String>,
+    pub origin_chamber: Option<Code<Chamber>>,
     
     #[pyo3(get)]
     #[serde(rename = "originChamberCode")]
@@ -145,22 +169,22 @@ pub struct BillDetail {
     
     #[pyo3(get)]
     #[serde(rename = "type")]
-    pub bill_type: Option<String>,
+    pub bill_type: Option<Code<BillType>>,
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "updateDateIncludingText")]
-    pub update_date_including_text: Option<String>,
+    pub update_date_including_text: Option<ApiDate>,
     
     #[pyo3(get)]
     pub url: Option<String>,
     
     #[pyo3(get)]
     #[serde(rename = "introducedDate")]
-    pub introduced_date: Option<String>,
+    pub introduced_date: Option<ApiDate>,
     
     #[pyo3(get)]
     pub sponsors: Option<Vec<Sponsor>>,
@@ -171,25 +195,31 @@ pub struct BillDetail {
     
     #[pyo3(get)]
     pub laws: Option<Vec<Law>>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl BillDetail {
+model_methods!(raw BillDetail {
     fn __repr__(&self) -> String {
         format!(
             "BillDetail(congress={:?}, number={:?}, title={:?}, type={:?})",
             self.congress, self.number, self.title, self.bill_type
         )
     }
-}
+});
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BillDetailResponse {
+    #[serde(deserialize_with = "crate::raw::one")]
     pub bill: BillDetail,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct Action {
     #[pyo3(get)]
     #[serde(rename = "actionCode")]
@@ -197,7 +227,7 @@ pub struct Action {
     
     #[pyo3(get)]
     #[serde(rename = "actionDate")]
-    pub action_date: Option<String>,
+    pub action_date: Option<ApiDate>,
     
     #[pyo3(get)]
     pub text: Option<String>,
@@ -205,27 +235,34 @@ pub struct Action {
     #[pyo3(get)]
     #[serde(rename = "type")]
     pub action_type: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl Action {
+model_methods!(raw Action {
     fn __repr__(&self) -> String {
         format!(
             "Action(date={:?}, type={:?}, text={:?})",
             self.action_date, self.action_type, self.text
         )
     }
-}
+});
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActionsResponse {
+    #[serde(deserialize_with = "crate::raw::list")]
     pub actions: Vec<Action>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct Amendment {
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub congress: Option<i32>,
     
     #[pyo3(get)]
@@ -237,29 +274,37 @@ pub struct Amendment {
     
     #[pyo3(get)]
     #[serde(rename = "type")]
-    pub amendment_type: Option<String>,
+    pub amendment_type: Option<Code<AmendmentType>>,
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl Amendment {
+model_methods!(raw Amendment {
     fn __repr__(&self) -> String {
         format!(
             "Amendment(congress={:?}, number={:?}, type={:?})",
             self.congress, self.number, self.amendment_type
         )
     }
-}
+});
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AmendmentsResponse {
+    #[serde(deserialize_with = "crate::raw::list")]
     pub amendments: Vec<Amendment>,
+    pub pagination: Option<Pagination>,
+    pub request: Option<RequestEcho>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct Committee {
     #[pyo3(get)]
     pub name: Option<String>,
@@ -270,22 +315,28 @@ pub struct Committee {
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl Committee {
+model_methods!(raw Committee {
     fn __repr__(&self) -> String {
         format!("Committee(name={:?}, system_code={:?})", self.name, self.system_code)
     }
-}
+});
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommitteesResponse {
+    #[serde(deserialize_with = "crate::raw::list")]
     pub committees: Vec<Committee>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct Cosponsor {
     #[pyo3(get)]
     #[serde(rename = "bioguideId")]
@@ -311,40 +362,49 @@ pub struct Cosponsor {
     
     #[pyo3(get)]
     #[serde(rename = "sponsorshipDate")]
-    pub sponsorship_date: Option<String>,
+    pub sponsorship_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "isOriginalCosponsor")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub is_original_cosponsor: Option<bool>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl Cosponsor {
+model_methods!(raw Cosponsor {
     fn __repr__(&self) -> String {
         format!(
             "Cosponsor(name={:?}, party={:?}, state={:?})",
             self.full_name, self.party, self.state
         )
     }
-}
+});
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CosponsorsResponse {
+    #[serde(deserialize_with = "crate::raw::list")]
     pub cosponsors: Vec<Cosponsor>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct RelatedBill {
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub congress: Option<i32>,
     
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub number: Option<i32>,
     
     #[pyo3(get)]
     #[serde(rename = "type")]
-    pub bill_type: Option<String>,
+    pub bill_type: Option<Code<BillType>>,
     
     #[pyo3(get)]
     pub title: Option<String>,
@@ -359,20 +419,25 @@ pub struct RelatedBill {
     #[pyo3(get)]
     #[serde(rename = "relationshipDetails")]
     pub relationship_details: Option<Vec<RelationshipDetail>>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl RelatedBill {
+model_methods!(raw RelatedBill {
     fn __repr__(&self) -> String {
         format!(
             "RelatedBill(congress={:?}, number={:?}, type={:?})",
             self.congress, self.number, self.bill_type
         )
     }
-}
+});
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct RelationshipDetail {
     #[pyo3(get)]
     #[serde(rename = "identifiedBy")]
@@ -381,56 +446,66 @@ pub struct RelationshipDetail {
     #[pyo3(get)]
     #[serde(rename = "type")]
     pub relationship_type: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[pymethods]
-impl RelationshipDetail {
+model_methods!(RelationshipDetail {
     fn __repr__(&self) -> String {
         format!(
             "RelationshipDetail(identified_by={:?}, type={:?})",
             self.identified_by, self.relationship_type
         )
     }
-}
+});
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RelatedBillsResponse {
     #[serde(rename = "relatedBills")]
+    #[serde(default, deserialize_with = "crate::raw::optional_list")]
     pub related_bills: Option<Vec<RelatedBill>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct Subject {
     #[pyo3(get)]
     pub name: Option<String>,
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl Subject {
+model_methods!(raw Subject {
     fn __repr__(&self) -> String {
         format!("Subject(name={:?})", self.name)
     }
-}
+});
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubjectsResponse {
     #[serde(rename = "legislativeSubjects")]
+    #[serde(default, deserialize_with = "crate::raw::optional_list")]
     pub legislative_subjects: Option<Vec<Subject>>,
     #[serde(rename = "policyArea")]
+    #[serde(default, deserialize_with = "crate::raw::optional")]
     pub policy_area: Option<PolicyArea>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct Summary {
     #[pyo3(get)]
     #[serde(rename = "actionDate")]
-    pub action_date: Option<String>,
+    pub action_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "actionDesc")]
@@ -441,33 +516,39 @@ pub struct Summary {
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "versionCode")]
     pub version_code: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl Summary {
+model_methods!(raw Summary {
     fn __repr__(&self) -> String {
         format!(
             "Summary(action_date={:?}, action_desc={:?})",
             self.action_date, self.action_desc
         )
     }
-}
+});
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SummariesResponse {
+    #[serde(deserialize_with = "crate::raw::list")]
     pub summaries: Vec<Summary>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct TextVersion {
     #[pyo3(get)]
-    pub date: Option<String>,
+    pub date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "type")]
@@ -475,20 +556,25 @@ pub struct TextVersion {
     
     #[pyo3(get)]
     pub formats: Option<Vec<TextFormat>>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl TextVersion {
+model_methods!(raw TextVersion {
     fn __repr__(&self) -> String {
         format!(
             "TextVersion(date={:?}, type={:?})",
             self.date, self.text_type
         )
     }
-}
+});
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct TextFormat {
     #[pyo3(get)]
     #[serde(rename = "type")]
@@ -496,26 +582,29 @@ pub struct TextFormat {
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[pymethods]
-impl TextFormat {
+model_methods!(TextFormat {
     fn __repr__(&self) -> String {
         format!(
             "TextFormat(type={:?}, url={:?})",
             self.format_type, self.url
         )
     }
-}
+});
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TextVersionsResponse {
     #[serde(rename = "textVersions")]
+    #[serde(deserialize_with = "crate::raw::list")]
     pub text_versions: Vec<TextVersion>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct Title {
     #[pyo3(get)]
     pub title: Option<String>,
@@ -526,20 +615,27 @@ pub struct Title {
     
     #[pyo3(get)]
     #[serde(rename = "titleTypeCode")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub title_type_code: Option<i32>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl Title {
+model_methods!(raw Title {
     fn __repr__(&self) -> String {
         format!(
             "Title(title={:?}, type={:?})",
             self.title, self.title_type
         )
     }
-}
+});
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TitlesResponse {
+    #[serde(deserialize_with = "crate::raw::list")]
     pub titles: Vec<Title>,
 }
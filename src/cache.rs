@@ -0,0 +1,302 @@
+use pyo3::exceptions::{PyOSError, PyValueError};
+use pyo3::prelude::*;
+use reqwest::header::{HeaderMap, ETAG, LAST_MODIFIED};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const ENTRY_EXTENSION: &str = "json";
+
+/// Where cached responses live and how long they stay fresh
+#[derive(Debug, Clone)]
+pub struct CacheConfig {
+    pub dir: PathBuf,
+    /// TTL for endpoints without an override; `None` never expires
+    pub default_ttl: Option<Duration>,
+    /// Overrides keyed by endpoint prefix such as `/law`; the longest match wins
+    pub ttls: Vec<(String, Option<Duration>)>,
+}
+
+impl CacheConfig {
+    pub fn parse_ttl(name: &str, seconds: Option<f64>) -> PyResult<Option<Duration>> {
+        match seconds {
+            None => Ok(None),
+            Some(s) if s.is_finite() && s >= 0.0 => Duration::try_from_secs_f64(s)
+                .map(Some)
+                .map_err(|_| PyValueError::new_err(format!("{} is too large", name))),
+            Some(_) => Err(PyValueError::new_err(format!("{} must be a non-negative number or None", name))),
+        }
+    }
+
+    pub fn parse_ttls(ttls: HashMap<String, Option<f64>>) -> PyResult<Vec<(String, Option<Duration>)>> {
+        ttls.into_iter()
+            .map(|(prefix, seconds)| {
+                if !prefix.starts_with('/') {
+                    return Err(PyValueError::new_err(format!(
+                        "cache_ttls keys must be endpoint paths starting with '/', got {:?}",
+                        prefix
+                    )));
+                }
+                let ttl = Self::parse_ttl(&format!("cache_ttls[{:?}]", prefix), seconds)?;
+                Ok((prefix.trim_end_matches('/').to_string(), ttl))
+            })
+            .collect()
+    }
+
+    fn ttl_for(&self, endpoint: &str) -> Option<Duration> {
+        self.ttls
+            .iter()
+            .filter(|(prefix, _)| matches_prefix(endpoint, prefix))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.default_ttl, |(_, ttl)| *ttl)
+    }
+}
+
+/// Whether `endpoint` is `prefix` or lies below it: `/law` matches
+/// `/law/118` but not `/laws`
+fn matches_prefix(endpoint: &str, prefix: &str) -> bool {
+    endpoint == prefix || endpoint.strip_prefix(prefix).is_some_and(|rest| rest.starts_with('/'))
+}
+
+fn now_secs() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0.0, |d| d.as_secs_f64())
+}
+
+/// A response body stored on disk, with the validators needed to
+/// revalidate it once it goes stale
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedResponse {
+    pub endpoint: String,
+    pub params: BTreeMap<String, String>,
+    /// Body with the API key already scrubbed out
+    pub body: String,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    /// Unix time the body was fetched or last revalidated
+    pub stored_at: f64,
+}
+
+impl CachedResponse {
+    fn age(&self) -> Duration {
+        Duration::from_secs_f64((now_secs() - self.stored_at).max(0.0))
+    }
+
+    pub fn has_validators(&self) -> bool {
+        self.etag.is_some() || self.last_modified.is_some()
+    }
+}
+
+/// Outcome of looking a request up in the cache
+pub enum Lookup {
+    /// Fresh body that can be used without contacting the API
+    Fresh(String),
+    /// Expired entry, to be revalidated or replaced
+    Stale(CachedResponse),
+    Miss,
+}
+
+/// Directory of cached responses, one JSON file per request
+#[derive(Debug)]
+pub struct ResponseCache {
+    config: CacheConfig,
+    base_url: String,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    revalidated: AtomicU64,
+}
+
+impl ResponseCache {
+    pub fn open(config: CacheConfig, base_url: &str) -> PyResult<Self> {
+        fs::create_dir_all(&config.dir).map_err(|e| {
+            PyOSError::new_err(format!("could not create cache_dir {}: {}", config.dir.display(), e))
+        })?;
+
+        Ok(Self {
+            config,
+            base_url: base_url.to_string(),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            revalidated: AtomicU64::new(0),
+        })
+    }
+
+    /// Cache key for a request. Built from the base URL, endpoint and query
+    /// parameters only; the API key travels in a header and is never part of it.
+    pub fn key(&self, endpoint: &str, params: &HashMap<String, String>) -> String {
+        let sorted: BTreeMap<_, _> = params.iter().collect();
+        let mut hasher = Sha256::new();
+        hasher.update(self.base_url.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(endpoint.as_bytes());
+        for (key, value) in sorted {
+            hasher.update(b"\0");
+            hasher.update(key.as_bytes());
+            hasher.update(b"=");
+            hasher.update(value.as_bytes());
+        }
+        hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.config.dir.join(format!("{}.{}", key, ENTRY_EXTENSION))
+    }
+
+    /// Look up a request. Unreadable or corrupt entries count as misses.
+    pub fn lookup(&self, key: &str, endpoint: &str) -> Lookup {
+        let entry = fs::read(self.path(key))
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<CachedResponse>(&bytes).ok());
+        let Some(entry) = entry else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return Lookup::Miss;
+        };
+
+        let fresh = match self.config.ttl_for(endpoint) {
+            Some(ttl) => entry.age() < ttl,
+            None => true,
+        };
+        if fresh {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            Lookup::Fresh(entry.body)
+        } else {
+            Lookup::Stale(entry)
+        }
+    }
+
+    /// Record that a stale entry had to be fetched again in full
+    pub fn record_miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Store a freshly fetched body. Write failures are ignored: the cache
+    /// is an optimisation and must never fail a request.
+    pub fn store(&self, key: &str, endpoint: &str, params: &HashMap<String, String>, body: String, headers: &HeaderMap) {
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|value: &reqwest::header::HeaderValue| value.to_str().ok())
+                .map(str::to_string)
+        };
+        let entry = CachedResponse {
+            endpoint: endpoint.to_string(),
+            params: params.iter().map(|(k, v)| (k.clone(), v.clone())).collect(),
+            body,
+            etag: header(ETAG),
+            last_modified: header(LAST_MODIFIED),
+            stored_at: now_secs(),
+        };
+        self.write(key, &entry);
+    }
+
+    /// Mark a stale entry fresh again after the API answered 304 Not Modified
+    pub fn refresh(&self, key: &str, mut entry: CachedResponse) -> String {
+        self.revalidated.fetch_add(1, Ordering::Relaxed);
+        entry.stored_at = now_secs();
+        self.write(key, &entry);
+        entry.body
+    }
+
+    fn write(&self, key: &str, entry: &CachedResponse) {
+        let Ok(bytes) = serde_json::to_vec(entry) else {
+            return;
+        };
+        // Write to a temporary file first so readers never see a partial entry
+        let path = self.path(key);
+        let tmp = path.with_extension(format!("tmp{}", fastrand::u32(..)));
+        if fs::write(&tmp, bytes).is_err() || fs::rename(&tmp, &path).is_err() {
+            let _ = fs::remove_file(&tmp);
+        }
+    }
+
+    fn entries(&self) -> impl Iterator<Item = PathBuf> {
+        fs::read_dir(&self.config.dir)
+            .into_iter()
+            .flatten()
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == ENTRY_EXTENSION))
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let (entries, size_bytes) = self.entries().fold((0, 0), |(count, size), path| {
+            (count + 1, size + fs::metadata(path).map_or(0, |m| m.len()))
+        });
+
+        CacheStats {
+            directory: Some(self.config.dir.display().to_string()),
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            revalidated: self.revalidated.load(Ordering::Relaxed),
+            entries,
+            size_bytes,
+        }
+    }
+
+    /// Delete every cached response, returning how many were removed
+    pub fn clear(&self) -> PyResult<u64> {
+        let mut removed = 0;
+        for path in self.entries() {
+            remove_entry(&path)?;
+            removed += 1;
+        }
+        Ok(removed)
+    }
+}
+
+fn remove_entry(path: &Path) -> PyResult<()> {
+    match fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        // Removed concurrently by another client sharing the directory
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(PyOSError::new_err(format!("could not remove {}: {}", path.display(), e))),
+    }
+}
+
+/// Snapshot of the response cache
+#[derive(Debug, Clone, Default)]
+#[pyclass]
+pub struct CacheStats {
+    /// Cache directory, or None when caching is disabled
+    #[pyo3(get)]
+    pub directory: Option<String>,
+
+    /// Requests answered from a fresh entry without contacting the API
+    #[pyo3(get)]
+    pub hits: u64,
+
+    /// Requests whose body had to be fetched from the API
+    #[pyo3(get)]
+    pub misses: u64,
+
+    /// Stale entries confirmed unchanged by a 304 Not Modified response
+    #[pyo3(get)]
+    pub revalidated: u64,
+
+    /// Responses currently stored on disk
+    #[pyo3(get)]
+    pub entries: u64,
+
+    /// Total size of the stored responses in bytes
+    #[pyo3(get)]
+    pub size_bytes: u64,
+}
+
+#[pymethods]
+impl CacheStats {
+    fn __repr__(&self) -> String {
+        format!(
+            "CacheStats(hits={}, misses={}, revalidated={}, entries={}, size_bytes={})",
+            self.hits, self.misses, self.revalidated, self.entries, self.size_bytes
+        )
+    }
+}
@@ -0,0 +1,201 @@
+use pyo3::exceptions::{PyOSError, PyValueError};
+use pyo3::prelude::*;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::errors::{ApiError, ApiResult};
+
+/// Whether requests go to the network, and whether they are taped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Talk to the API; nothing is recorded
+    Live,
+    /// Talk to the API and write every response to the cassette directory
+    Record,
+    /// Answer from the cassette directory without touching the network
+    Replay,
+}
+
+impl CassetteMode {
+    pub fn parse(mode: &str) -> PyResult<Self> {
+        match mode.to_ascii_lowercase().as_str() {
+            "live" => Ok(Self::Live),
+            "record" => Ok(Self::Record),
+            "replay" => Ok(Self::Replay),
+            other => Err(PyValueError::new_err(format!(
+                "mode must be 'live', 'record' or 'replay', got {:?}",
+                other
+            ))),
+        }
+    }
+}
+
+/// A response with its body read, as received from the API or stored on tape
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawResponse {
+    pub status: u16,
+    pub headers: BTreeMap<String, String>,
+    /// Body with the API key already scrubbed out
+    pub body: String,
+}
+
+impl RawResponse {
+    pub fn new(status: StatusCode, headers: &HeaderMap, body: String) -> Self {
+        let headers = headers
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        Self {
+            status: status.as_u16(),
+            headers,
+            body,
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    pub fn header_map(&self) -> HeaderMap {
+        self.headers
+            .iter()
+            .filter_map(|(name, value)| {
+                Some((HeaderName::try_from(name.as_str()).ok()?, HeaderValue::from_str(value).ok()?))
+            })
+            .collect()
+    }
+}
+
+/// Everything recorded for one request, in the order it was received
+#[derive(Debug, Serialize, Deserialize)]
+struct Tape {
+    endpoint: String,
+    params: BTreeMap<String, String>,
+    responses: Vec<RawResponse>,
+}
+
+/// A directory of recorded request/response pairs
+#[derive(Debug)]
+pub struct Cassette {
+    mode: CassetteMode,
+    dir: PathBuf,
+    /// Tapes started by this client; re-recording a request replaces the
+    /// file once, then appends (so a retried request keeps its 503s)
+    recorded: Mutex<HashSet<String>>,
+    /// Next response to replay for each tape
+    played: Mutex<HashMap<String, usize>>,
+}
+
+impl Cassette {
+    pub fn open(mode: CassetteMode, dir: PathBuf) -> PyResult<Self> {
+        match mode {
+            CassetteMode::Record => fs::create_dir_all(&dir).map_err(|e| {
+                PyOSError::new_err(format!("could not create cassette_dir {}: {}", dir.display(), e))
+            })?,
+            CassetteMode::Replay if !dir.is_dir() => {
+                return Err(PyValueError::new_err(format!(
+                    "cassette_dir {} does not exist",
+                    dir.display()
+                )))
+            }
+            _ => {}
+        }
+
+        Ok(Self {
+            mode,
+            dir,
+            recorded: Mutex::new(HashSet::new()),
+            played: Mutex::new(HashMap::new()),
+        })
+    }
+
+    pub fn is_replay(&self) -> bool {
+        self.mode == CassetteMode::Replay
+    }
+
+    /// File name for a request: a readable slug of the endpoint plus a hash
+    /// of the endpoint and parameters. The base URL is left out so a tape
+    /// recorded against one server replays against any other.
+    pub fn tape_name(endpoint: &str, params: &HashMap<String, String>) -> String {
+        let sorted: BTreeMap<_, _> = params.iter().collect();
+        let mut hasher = Sha256::new();
+        hasher.update(endpoint.as_bytes());
+        for (key, value) in sorted {
+            hasher.update(b"\0");
+            hasher.update(key.as_bytes());
+            hasher.update(b"=");
+            hasher.update(value.as_bytes());
+        }
+        let hash: String = hasher.finalize()[..8].iter().map(|byte| format!("{:02x}", byte)).collect();
+        let slug: String = endpoint
+            .trim_matches('/')
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+            .collect();
+        format!("{}-{}.json", slug, hash)
+    }
+
+    fn read_tape(&self, name: &str) -> Option<Tape> {
+        let bytes = fs::read(self.dir.join(name)).ok()?;
+        serde_json::from_slice(&bytes).ok()
+    }
+
+    /// Append a live response to the tape for its request
+    pub fn record(
+        &self,
+        name: &str,
+        endpoint: &str,
+        params: &HashMap<String, String>,
+        response: RawResponse,
+    ) -> ApiResult<()> {
+        let first_this_session = self.recorded.lock().unwrap().insert(name.to_string());
+        let mut tape = match self.read_tape(name) {
+            Some(tape) if !first_this_session => tape,
+            _ => Tape {
+                endpoint: endpoint.to_string(),
+                params: params.iter().map(|(k, v)| (k.clone(), v.clone())).collect(),
+                responses: Vec::new(),
+            },
+        };
+        tape.responses.push(response);
+
+        let path = self.dir.join(name);
+        let write = serde_json::to_vec_pretty(&tape)
+            .map_err(|e| e.to_string())
+            .and_then(|bytes| fs::write(&path, bytes).map_err(|e| e.to_string()));
+        write.map_err(|message| ApiError::Cassette {
+            endpoint: endpoint.to_string(),
+            message: format!("could not write {}: {}", path.display(), message),
+        })
+    }
+
+    /// Next recorded response for a request. Once a tape is used up its
+    /// last response keeps being served.
+    pub fn replay(&self, name: &str, endpoint: &str, params: &HashMap<String, String>) -> ApiResult<RawResponse> {
+        let tape = self.read_tape(name).filter(|tape| !tape.responses.is_empty());
+        let Some(tape) = tape else {
+            let sorted: BTreeMap<_, _> = params.iter().collect();
+            return Err(ApiError::Cassette {
+                endpoint: endpoint.to_string(),
+                message: format!(
+                    "no recorded response for params {:?} (expected {} in {})",
+                    sorted,
+                    name,
+                    self.dir.display()
+                ),
+            });
+        };
+
+        let mut played = self.played.lock().unwrap();
+        let next = played.entry(name.to_string()).or_insert(0);
+        let response = tape.responses[(*next).min(tape.responses.len() - 1)].clone();
+        *next += 1;
+        Ok(response)
+    }
+}
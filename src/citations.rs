@@ -0,0 +1,652 @@
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::pyclass::PyClass;
+use pyo3::PyTypeInfo;
+use pyo3::types::PyString;
+
+use crate::enums::{AmendmentType, ApiEnum, BillType, EnumArg, LawType, ReportType};
+
+/// congress.gov path names for bill types
+const BILL_SLUGS: &[(&str, BillType)] = &[
+    ("house-bill", BillType::Hr),
+    ("senate-bill", BillType::S),
+    ("house-joint-resolution", BillType::Hjres),
+    ("senate-joint-resolution", BillType::Sjres),
+    ("house-concurrent-resolution", BillType::Hconres),
+    ("senate-concurrent-resolution", BillType::Sconres),
+    ("house-resolution", BillType::Hres),
+    ("senate-resolution", BillType::Sres),
+];
+
+const AMENDMENT_SLUGS: &[(&str, AmendmentType)] = &[
+    ("house-amendment", AmendmentType::Hamdt),
+    ("senate-amendment", AmendmentType::Samdt),
+];
+
+const REPORT_SLUGS: &[(&str, ReportType)] = &[
+    ("house-report", ReportType::Hrpt),
+    ("senate-report", ReportType::Srpt),
+];
+
+/// Law prefixes after `compact`: "Pub. L." is "publ", "P.L." is "pl"
+const LAW_PREFIXES: &[(&str, LawType)] = &[
+    ("publ", LawType::Public),
+    ("publno", LawType::Public),
+    ("pl", LawType::Public),
+    ("plno", LawType::Public),
+    ("publiclaw", LawType::Public),
+    ("pub", LawType::Public),
+    ("privl", LawType::Private),
+    ("privlno", LawType::Private),
+    ("pvtl", LawType::Private),
+    ("privatelaw", LawType::Private),
+    ("priv", LawType::Private),
+];
+
+/// Lowercase with whitespace and periods removed: "H. J. Res. 5" becomes
+/// "hjres5"
+fn compact(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_whitespace() && *c != '.')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn split_letters(text: &str) -> (&str, &str) {
+    let end = text.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(text.len());
+    text.split_at(end)
+}
+
+fn split_digits(text: &str) -> Option<(i32, &str)> {
+    let end = text.find(|c: char| !c.is_ascii_digit()).unwrap_or(text.len());
+    let (digits, rest) = text.split_at(end);
+    digits.parse().ok().map(|number| (number, rest))
+}
+
+/// Read what follows a number: nothing, or a congress such as "-118",
+/// "(118th Congress)" or ", 118th Congress". `None` if it is anything else.
+fn congress_suffix(text: &str) -> Option<Option<i32>> {
+    let text = text.trim_matches(|c| matches!(c, '-' | '(' | ')' | ','));
+    if text.is_empty() {
+        return Some(None);
+    }
+    let text = text.strip_suffix("congress").unwrap_or(text);
+    let (congress, rest) = split_digits(text)?;
+    matches!(rest, "" | "st" | "nd" | "rd" | "th").then_some(Some(congress))
+}
+
+/// "congress-number" as in "118-5"
+fn congress_and_number(text: &str) -> Option<(i32, i32)> {
+    let (congress, rest) = split_digits(text)?;
+    let (number, rest) = split_digits(rest.strip_prefix('-')?)?;
+    rest.is_empty().then_some((congress, number))
+}
+
+/// The congress, type and number segments following `marker` in a
+/// congress.gov or API URL, or in a bare path such as "/bill/118/hr/1"
+fn url_segments<'a>(text: &'a str, marker: &str) -> Option<[&'a str; 3]> {
+    let path = text.split(['?', '#']).next().unwrap_or(text);
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    let start = segments.iter().position(|s| s.eq_ignore_ascii_case(marker))?;
+    match segments.get(start + 1..start + 4)? {
+        [congress, kind, number] => Some([congress, kind, number]),
+        _ => None,
+    }
+}
+
+/// A congress segment: "118" or "118th-congress"
+fn url_congress(segment: &str) -> Option<i32> {
+    split_digits(segment).map(|(congress, _)| congress)
+}
+
+fn url_kind<E: ApiEnum>(segment: &str, slugs: &[(&str, E)]) -> Option<E> {
+    slugs
+        .iter()
+        .find(|(slug, _)| segment.eq_ignore_ascii_case(slug))
+        .map(|(_, kind)| *kind)
+        .or_else(|| E::parse(segment))
+}
+
+/// Parse "<type> <number>" with an optional congress before or after it,
+/// as used by bills and amendments
+fn parse_numbered<E: ApiEnum>(
+    text: &str,
+    congress: Option<i32>,
+    url_marker: &str,
+    slugs: &[(&str, E)],
+) -> Option<(Option<i32>, E, i32)> {
+    if text.contains('/') {
+        let [congress_segment, kind, number] = url_segments(text, url_marker)?;
+        let congress = url_congress(congress_segment)?;
+        return Some((Some(congress), url_kind(kind, slugs)?, number.parse().ok()?));
+    }
+    let text = compact(text);
+    let (leading, rest) = match split_digits(&text) {
+        Some((congress, rest)) => (Some(congress), rest),
+        None => (None, text.as_str()),
+    };
+    let (letters, rest) = split_letters(rest);
+    let kind = E::parse(letters)?;
+    let (number, rest) = split_digits(rest)?;
+    let trailing = congress_suffix(rest)?;
+    let found = match (leading, trailing) {
+        (Some(_), Some(_)) => return None,
+        (found, None) | (None, found) => found,
+    };
+    match (found, congress) {
+        (Some(found), Some(given)) if found != given => None,
+        (found, given) => Some((found.or(given), kind, number)),
+    }
+}
+
+fn not_a_citation(noun: &str, text: &str) -> PyErr {
+    PyValueError::new_err(format!("not a {} citation: {:?}", noun, text))
+}
+
+fn no_congress(text: &str) -> PyErr {
+    PyValueError::new_err(format!(
+        "no congress in {:?}; pass congress= or use a form like \"hr1234-118\"",
+        text
+    ))
+}
+
+/// "118th", "101st", "112th"
+fn ordinal(number: i32) -> String {
+    let suffix = match (number % 10, number % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", number, suffix)
+}
+
+/// An id type that can stand in for a `congress, type, number` argument
+/// triple
+pub trait Citation: PyClass + Clone {
+    type Kind: ApiEnum;
+
+    fn from_text(text: &str) -> PyResult<Self>;
+    fn parts(&self) -> (i32, Self::Kind, i32);
+}
+
+/// A number argument that can be filled in from an id: `int`, or `str`
+/// for the bill number `get_law` takes
+pub trait IdNumber {
+    fn from_number(number: i32) -> Self;
+}
+
+impl IdNumber for i32 {
+    fn from_number(number: i32) -> Self {
+        number
+    }
+}
+
+impl IdNumber for String {
+    fn from_number(number: i32) -> Self {
+        number.to_string()
+    }
+}
+
+/// The `id=` argument of a method taking `congress, type, number`: a whole
+/// id, or a citation string, in place of the three
+#[derive(Debug, Clone)]
+pub struct IdArg<T>(pub T);
+
+impl<T: Citation> IdArg<T> {
+    /// The congress, type and number to request, given either as `id` or as
+    /// the three separate arguments but not both
+    pub fn resolve<N: IdNumber>(
+        id: Option<Self>,
+        congress: Option<i32>,
+        kind: Option<EnumArg<T::Kind>>,
+        number: Option<N>,
+    ) -> PyResult<(i32, EnumArg<T::Kind>, N)> {
+        match (id, congress, kind, number) {
+            (None, Some(congress), Some(kind), Some(number)) => Ok((congress, kind, number)),
+            (None, _, _, _) => Err(PyTypeError::new_err(format!(
+                "missing congress, type or number; pass all three or pass id= a {} or citation string",
+                <T as PyTypeInfo>::NAME
+            ))),
+            (Some(IdArg(id)), None, None, None) => {
+                let (congress, kind, number) = id.parts();
+                Ok((congress, EnumArg(kind), N::from_number(number)))
+            }
+            (Some(_), _, _, _) => Err(repeated_parts::<T>()),
+        }
+    }
+}
+
+/// A congress, type or number was passed along with an id that already has
+/// them
+pub fn repeated_parts<T: PyTypeInfo>() -> PyErr {
+    PyTypeError::new_err(format!(
+        "a {} already has a congress, type and number; do not pass them as well",
+        <T as PyTypeInfo>::NAME
+    ))
+}
+
+impl<'py, T: Citation> FromPyObject<'py> for IdArg<T> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(id) = ob.downcast::<T>() {
+            return Ok(IdArg(id.borrow().clone()));
+        }
+        if let Ok(text) = ob.downcast::<PyString>() {
+            return T::from_text(text.to_str()?).map(IdArg);
+        }
+        let name = ob
+            .get_type()
+            .name()
+            .map(|name| name.to_string())
+            .unwrap_or_default();
+        Err(PyTypeError::new_err(format!(
+            "expected a {} or citation string, got {}",
+            <T as PyTypeInfo>::NAME,
+            name
+        )))
+    }
+}
+
+/// The `id=` argument of `get_law`: anything `IdArg<BillId>` takes for the
+/// bill that became the law, or a `LawId` / law citation
+#[derive(Debug, Clone)]
+pub enum LawArg {
+    Bill(IdArg<BillId>),
+    Law(LawId),
+}
+
+impl LawArg {
+    /// The congress, bill type and bill number to request, or the law whose
+    /// bill has to be looked up first
+    pub fn resolve(
+        id: Option<Self>,
+        congress: Option<i32>,
+        bill_type: Option<EnumArg<BillType>>,
+        bill_number: Option<String>,
+    ) -> PyResult<Result<(i32, String, String), LawId>> {
+        let bill = match id {
+            Some(LawArg::Law(_)) if congress.is_some() || bill_type.is_some() || bill_number.is_some() => {
+                return Err(repeated_parts::<LawId>());
+            }
+            Some(LawArg::Law(law)) => return Ok(Err(law)),
+            Some(LawArg::Bill(bill)) => Some(bill),
+            None => None,
+        };
+        let (congress, bill_type, bill_number) = IdArg::resolve(bill, congress, bill_type, bill_number)?;
+        Ok(Ok((congress, bill_type.to_string(), bill_number)))
+    }
+}
+
+impl<'py> FromPyObject<'py> for LawArg {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(law) = ob.downcast::<LawId>() {
+            return Ok(LawArg::Law(*law.get()));
+        }
+        if let Ok(text) = ob.downcast::<PyString>() {
+            if let Some(law) = LawId::parse_text(text.to_str()?) {
+                return Ok(LawArg::Law(law));
+            }
+        }
+        ob.extract().map(LawArg::Bill)
+    }
+}
+
+/// Identifies a bill or resolution
+#[pyclass(frozen, eq, hash, module = "cdg_python_client")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BillId {
+    #[pyo3(get)]
+    pub congress: i32,
+
+    #[pyo3(get)]
+    pub bill_type: BillType,
+
+    #[pyo3(get)]
+    pub number: i32,
+}
+
+impl BillId {
+    fn parse_with(text: &str, congress: Option<i32>) -> PyResult<Self> {
+        match parse_numbered(text, congress, "bill", BILL_SLUGS) {
+            Some((Some(congress), bill_type, number)) => Ok(Self { congress, bill_type, number }),
+            Some((None, _, _)) => Err(no_congress(text)),
+            None => Err(not_a_citation("bill", text)),
+        }
+    }
+}
+
+impl Citation for BillId {
+    type Kind = BillType;
+
+    fn from_text(text: &str) -> PyResult<Self> {
+        Self::parse_with(text, None)
+    }
+
+    fn parts(&self) -> (i32, BillType, i32) {
+        (self.congress, self.bill_type, self.number)
+    }
+}
+
+#[pymethods]
+impl BillId {
+    #[new]
+    fn new(congress: i32, bill_type: EnumArg<BillType>, number: i32) -> Self {
+        Self { congress, bill_type: bill_type.0, number }
+    }
+
+    /// Parse "H.R. 1234", "hr1234-118", "S.J.Res. 5 (118th Congress)", a
+    /// congress.gov or API URL and similar forms. `congress` fills in a
+    /// citation that has none.
+    #[staticmethod]
+    #[pyo3(signature = (text, congress=None))]
+    fn parse(text: &str, congress: Option<i32>) -> PyResult<Self> {
+        Self::parse_with(text, congress)
+    }
+
+    /// Citation such as "H.R. 1234"
+    #[getter]
+    fn citation(&self) -> String {
+        let prefix = match self.bill_type {
+            BillType::Hr => "H.R.",
+            BillType::S => "S.",
+            BillType::Hjres => "H.J.Res.",
+            BillType::Sjres => "S.J.Res.",
+            BillType::Hconres => "H.Con.Res.",
+            BillType::Sconres => "S.Con.Res.",
+            BillType::Hres => "H.Res.",
+            BillType::Sres => "S.Res.",
+        };
+        format!("{} {}", prefix, self.number)
+    }
+
+    /// Short form such as "hr1234-118"
+    #[getter]
+    fn slug(&self) -> String {
+        format!("{}{}-{}", self.bill_type.path(), self.number, self.congress)
+    }
+
+    /// Page on congress.gov
+    #[getter]
+    fn url(&self) -> String {
+        let slug = BILL_SLUGS
+            .iter()
+            .find(|(_, bill_type)| *bill_type == self.bill_type)
+            .map(|(slug, _)| *slug)
+            .unwrap_or_default();
+        format!(
+            "https://www.congress.gov/bill/{}-congress/{}/{}",
+            ordinal(self.congress),
+            slug,
+            self.number
+        )
+    }
+
+    fn __str__(&self) -> String {
+        format!("{} ({} Congress)", self.citation(), ordinal(self.congress))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "BillId(congress={}, bill_type={:?}, number={})",
+            self.congress,
+            self.bill_type.value(),
+            self.number
+        )
+    }
+}
+
+/// Identifies an amendment
+#[pyclass(frozen, eq, hash, module = "cdg_python_client")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmendmentId {
+    #[pyo3(get)]
+    pub congress: i32,
+
+    #[pyo3(get)]
+    pub amendment_type: AmendmentType,
+
+    #[pyo3(get)]
+    pub number: i32,
+}
+
+impl AmendmentId {
+    fn parse_with(text: &str, congress: Option<i32>) -> PyResult<Self> {
+        match parse_numbered(text, congress, "amendment", AMENDMENT_SLUGS) {
+            Some((Some(congress), amendment_type, number)) => {
+                Ok(Self { congress, amendment_type, number })
+            }
+            Some((None, _, _)) => Err(no_congress(text)),
+            None => Err(not_a_citation("amendment", text)),
+        }
+    }
+}
+
+impl Citation for AmendmentId {
+    type Kind = AmendmentType;
+
+    fn from_text(text: &str) -> PyResult<Self> {
+        Self::parse_with(text, None)
+    }
+
+    fn parts(&self) -> (i32, AmendmentType, i32) {
+        (self.congress, self.amendment_type, self.number)
+    }
+}
+
+#[pymethods]
+impl AmendmentId {
+    #[new]
+    fn new(congress: i32, amendment_type: EnumArg<AmendmentType>, number: i32) -> Self {
+        Self { congress, amendment_type: amendment_type.0, number }
+    }
+
+    /// Parse "S.Amdt. 45", "H.Amdt. 12 (118th Congress)", "samdt45-118",
+    /// a congress.gov or API URL and similar forms. `congress` fills in a
+    /// citation that has none.
+    #[staticmethod]
+    #[pyo3(signature = (text, congress=None))]
+    fn parse(text: &str, congress: Option<i32>) -> PyResult<Self> {
+        Self::parse_with(text, congress)
+    }
+
+    /// Citation such as "S.Amdt. 45"
+    #[getter]
+    fn citation(&self) -> String {
+        let prefix = match self.amendment_type {
+            AmendmentType::Hamdt => "H.Amdt.",
+            AmendmentType::Samdt => "S.Amdt.",
+            AmendmentType::Suamdt => "S.Up.Amdt.",
+        };
+        format!("{} {}", prefix, self.number)
+    }
+
+    /// Short form such as "samdt45-118"
+    #[getter]
+    fn slug(&self) -> String {
+        format!("{}{}-{}", self.amendment_type.path(), self.number, self.congress)
+    }
+
+    fn __str__(&self) -> String {
+        format!("{} ({} Congress)", self.citation(), ordinal(self.congress))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "AmendmentId(congress={}, amendment_type={:?}, number={})",
+            self.congress,
+            self.amendment_type.value(),
+            self.number
+        )
+    }
+}
+
+/// Identifies a public or private law
+#[pyclass(frozen, eq, hash, module = "cdg_python_client")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LawId {
+    #[pyo3(get)]
+    pub congress: i32,
+
+    #[pyo3(get)]
+    pub law_type: LawType,
+
+    #[pyo3(get)]
+    pub number: i32,
+}
+
+impl LawId {
+    fn parse_text(text: &str) -> Option<Self> {
+        if text.contains('/') {
+            let [congress, law_type, number] = url_segments(text, "law")?;
+            return Some(Self {
+                congress: url_congress(congress)?,
+                law_type: LawType::parse(law_type)?,
+                number: number.parse().ok()?,
+            });
+        }
+        let text = compact(text);
+        let (letters, rest) = split_letters(&text);
+        let law_type = LAW_PREFIXES
+            .iter()
+            .find(|(prefix, _)| *prefix == letters)
+            .map(|(_, law_type)| *law_type)?;
+        let (congress, number) = congress_and_number(rest)?;
+        Some(Self { congress, law_type, number })
+    }
+}
+
+impl Citation for LawId {
+    type Kind = LawType;
+
+    fn from_text(text: &str) -> PyResult<Self> {
+        Self::parse_text(text).ok_or_else(|| not_a_citation("law", text))
+    }
+
+    fn parts(&self) -> (i32, LawType, i32) {
+        (self.congress, self.law_type, self.number)
+    }
+}
+
+#[pymethods]
+impl LawId {
+    #[new]
+    fn new(congress: i32, law_type: EnumArg<LawType>, number: i32) -> Self {
+        Self { congress, law_type: law_type.0, number }
+    }
+
+    /// Parse "Pub. L. 118-5", "Public Law 118-5", "P.L. 118-5",
+    /// "Priv. L. 118-1", an API URL and similar forms
+    #[staticmethod]
+    fn parse(text: &str) -> PyResult<Self> {
+        Self::from_text(text)
+    }
+
+    /// Citation such as "Pub. L. 118-5"
+    #[getter]
+    pub(crate) fn citation(&self) -> String {
+        let prefix = match self.law_type {
+            LawType::Public => "Pub. L.",
+            LawType::Private => "Priv. L.",
+        };
+        format!("{} {}-{}", prefix, self.congress, self.number)
+    }
+
+    fn __str__(&self) -> String {
+        self.citation()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "LawId(congress={}, law_type={:?}, number={})",
+            self.congress,
+            self.law_type.value(),
+            self.number
+        )
+    }
+}
+
+/// Identifies a committee report
+#[pyclass(frozen, eq, hash, module = "cdg_python_client")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ReportId {
+    #[pyo3(get)]
+    pub congress: i32,
+
+    #[pyo3(get)]
+    pub report_type: ReportType,
+
+    #[pyo3(get)]
+    pub number: i32,
+}
+
+impl ReportId {
+    fn parse_text(text: &str) -> Option<Self> {
+        if text.contains('/') {
+            let segments = url_segments(text, "committee-report")
+                .or_else(|| url_segments(text, "congressional-report"))?;
+            let [congress, report_type, number] = segments;
+            return Some(Self {
+                congress: url_congress(congress)?,
+                report_type: url_kind(report_type, REPORT_SLUGS)?,
+                number: number.parse().ok()?,
+            });
+        }
+        let text = compact(text);
+        let (letters, rest) = split_letters(&text);
+        let report_type = ReportType::parse(letters)?;
+        let (congress, number) = congress_and_number(rest)?;
+        Some(Self { congress, report_type, number })
+    }
+}
+
+impl Citation for ReportId {
+    type Kind = ReportType;
+
+    fn from_text(text: &str) -> PyResult<Self> {
+        Self::parse_text(text).ok_or_else(|| not_a_citation("report", text))
+    }
+
+    fn parts(&self) -> (i32, ReportType, i32) {
+        (self.congress, self.report_type, self.number)
+    }
+}
+
+#[pymethods]
+impl ReportId {
+    #[new]
+    fn new(congress: i32, report_type: EnumArg<ReportType>, number: i32) -> Self {
+        Self { congress, report_type: report_type.0, number }
+    }
+
+    /// Parse "H. Rept. 118-12", "S. Rept. 118-3", "Ex. Rept. 118-1", a
+    /// congress.gov or API URL and similar forms
+    #[staticmethod]
+    fn parse(text: &str) -> PyResult<Self> {
+        Self::from_text(text)
+    }
+
+    /// Citation such as "H. Rept. 118-12"
+    #[getter]
+    fn citation(&self) -> String {
+        let prefix = match self.report_type {
+            ReportType::Hrpt => "H. Rept.",
+            ReportType::Srpt => "S. Rept.",
+            ReportType::Erpt => "Ex. Rept.",
+        };
+        format!("{} {}-{}", prefix, self.congress, self.number)
+    }
+
+    fn __str__(&self) -> String {
+        self.citation()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ReportId(congress={}, report_type={:?}, number={})",
+            self.congress,
+            self.report_type.value(),
+            self.number
+        )
+    }
+}
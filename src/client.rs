@@ -1,2238 +1,562 @@
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use reqwest::blocking::Client;
+use reqwest::Client;
+use reqwest::header::{HeaderMap, HeaderValue, IF_MODIFIED_SINCE, IF_NONE_MATCH};
+use reqwest::StatusCode;
 use serde::de::DeserializeOwned;
 use std::collections::HashMap;
-use thiserror::Error;
-
-use crate::bills::{
-    Action, ActionsResponse, Amendment, AmendmentsResponse, Bill, BillDetail, 
-    BillDetailResponse, BillsResponse, Committee, CommitteesResponse, Cosponsor, 
-    CosponsorsResponse, RelatedBill, RelatedBillsResponse, Subject, 
-    SubjectsResponse, SummariesResponse, Summary, TextVersion, TextVersionsResponse, 
-    Title, TitlesResponse,
-};
-use crate::members::{
-    CosponsoredLegislationResponse, MemberResponse, MembersResponse, Sponsor, 
-    SponsoredLegislationResponse,
-};
-use crate::sessions::{Congress, CongressesResponse, CongressResponse};
-use crate::house_votes::{
-    HouseVote, HouseVoteDetail, HouseVoteDetailResponse, HouseVoteMembers,
-    HouseVoteMembersResponse, HouseVotesResponse,
-};
-use crate::committees::{
-    CommitteeBill, CommitteeBillsResponse, CommitteeDetailInfo, CommitteeDetailResponse,
-    CommitteeItem, CommitteePrintDetail, CommitteePrintDetailResponse, CommitteePrintItem,
-    CommitteePrintsResponse, CommitteePrintText, CommitteePrintTextResponse,
-    CommitteeReportDetail, CommitteeReportDetailResponse, CommitteeReportItem,
-    CommitteeReportsResponse, CommitteeReportText, CommitteeReportTextResponse,
-    CommitteesResponse as CommitteesListResponse,
-};
-use crate::nominations::{Nomination, NominationDetailResponse, NominationsResponse, Nominee, NomineesResponse};
-use crate::treaties::{Treaty, TreatiesResponse, TreatyDetailResponse};
-use crate::hearings::{Hearing, HearingsResponse, HearingDetailResponse};
-use crate::congressional_record::{DailyCongressionalRecord, DailyCongressionalRecordsResponse};
-use crate::laws::{LawDetail, LawDetailResponse, LawItem, LawsResponse};
-use crate::summaries::{SummaryItem, SummariesListResponse};
-use crate::crsreport::{CrsReport, CrsReportDetail, CrsReportDetailResponse, CrsReportsResponse};
-
-#[derive(Error, Debug)]
-pub enum ApiError {
-    #[error("HTTP request failed: {0}")]
-    RequestFailed(#[from] reqwest::Error),
-    
-    #[error("API error: {0}")]
-    ApiError(String),
-    
-    #[allow(dead_code)]
-    #[error("Missing API key")]
-    MissingApiKey,
-}
-
-pub type ApiResult<T> = Result<T, ApiError>;
-
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+use crate::laws::LawsResponse;
+use crate::cache::{CacheConfig, CacheStats, CachedResponse, Lookup, ResponseCache};
+use crate::cassette::{Cassette, CassetteMode, RawResponse};
+use crate::config::HttpConfig;
+use crate::citations::{Citation, LawId};
+use crate::enums::{ApiEnum, Code};
+use crate::errors::{ApiError, ApiResult, NotFoundError};
+use crate::endpoints::{endpoint_methods, Dispatch};
+use crate::pagination::{fetch_page, PageIterator, Paginated};
+use crate::redact;
+use crate::rate_limit::{LimiterMode, QuotaTracker, RateLimitStatus, TokenBucket};
+use crate::retry::{self, RetryConfig, RetryCounters, RetryStats};
+use crate::xml;
+
+/// Shared by every thread and task using a client, hence `Send + Sync`
 pub struct CongressApiClient {
     client: Client,
     api_key: String,
+    api_key_header: HeaderValue,
     base_url: String,
+    retry: RetryConfig,
+    retry_counters: RetryCounters,
+    limiter: Option<TokenBucket>,
+    quota: QuotaTracker,
+    /// Caps the number of requests in flight at once
+    concurrency: Option<Semaphore>,
+    cache: Option<ResponseCache>,
+    cassette: Option<Cassette>,
 }
 
-impl CongressApiClient {
-    pub fn new(api_key: String) -> Self {
-        Self {
-            client: Client::new(),
-            api_key,
-            base_url: "https://api.congress.gov/v3".to_string(),
-        }
-    }
-
-    pub fn get<T: DeserializeOwned>(
-        &self,
-        endpoint: &str,
-        params: Option<HashMap<String, String>>,
-    ) -> ApiResult<T> {
-        let url = format!("{}{}", self.base_url, endpoint);
-        
-        let mut request = self.client.get(&url).query(&[("api_key", &self.api_key)]);
-        
-        if let Some(params) = params {
-            for (key, value) in params {
-                request = request.query(&[(key.as_str(), value.as_str())]);
-            }
-        }
-        
-        let response = request.send()?;
-        
-        if !response.status().is_success() {
-            return Err(ApiError::ApiError(format!(
-                "API returned status: {}",
-                response.status()
-            )));
-        }
-        
-        Ok(response.json()?)
-    }
+/// Result of a request that may have been conditional
+enum Fetched {
+    /// Body (API key scrubbed) and the response headers
+    Body { body: String, headers: HeaderMap },
+    /// The cached copy is still current (HTTP 304)
+    NotModified,
 }
 
-// PyO3 wrapper class for Congress.gov API
-#[pyclass]
-pub struct CDGPythonClient {
-    client: CongressApiClient,
+/// Decode a JSON or XML response body, keeping the body on failure for
+/// debugging
+fn decode<T: DeserializeOwned>(endpoint: &str, body: &str) -> ApiResult<T> {
+    let decoded = if xml::is_xml(body) {
+        xml::to_json(body).and_then(|value| serde_json::from_value(value).map_err(|e| e.to_string()))
+    } else {
+        serde_json::from_str(body).map_err(|e| e.to_string())
+    };
+    decoded.map_err(|message| ApiError::Decode {
+        endpoint: endpoint.to_string(),
+        message,
+        body: Some(body.to_string()),
+    })
 }
 
-#[pymethods]
-impl CDGPythonClient {
-    #[new]
-    pub fn new(api_key: String) -> Self {
-        Self {
-            client: CongressApiClient::new(api_key),
-        }
-    }
-
-    // ========== Bill Endpoints ==========
-
-    /// Get a list of bills sorted by date of latest action
-    #[pyo3(signature = (format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
-    pub fn list_bills(
-        &self,
-        format: Option<String>,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        from_date_time: Option<String>,
-        to_date_time: Option<String>,
-    ) -> PyResult<Vec<Bill>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(o) = offset {
-            params.insert("offset".to_string(), o.to_string());
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
-        }
-        if let Some(from) = from_date_time {
-            params.insert("fromDateTime".to_string(), from);
-        }
-        if let Some(to) = to_date_time {
-            params.insert("toDateTime".to_string(), to);
-        }
-        
-        let response: BillsResponse = self
-            .client
-            .get("/bill", Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.bills)
-    }
-
-    /// Get bills filtered by congress
-    #[pyo3(signature = (congress, format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
-    pub fn list_bills_by_congress(
-        &self,
-        congress: i32,
-        format: Option<String>,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        from_date_time: Option<String>,
-        to_date_time: Option<String>,
-    ) -> PyResult<Vec<Bill>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(o) = offset {
-            params.insert("offset".to_string(), o.to_string());
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
-        }
-        if let Some(from) = from_date_time {
-            params.insert("fromDateTime".to_string(), from);
-        }
-        if let Some(to) = to_date_time {
-            params.insert("toDateTime".to_string(), to);
-        }
-        
-        let endpoint = format!("/bill/{}", congress);
-        let response: BillsResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.bills)
-    }
-
-    /// Get bills filtered by congress and bill type
-    #[pyo3(signature = (congress, bill_type, format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
-    pub fn list_bills_by_type(
-        &self,
-        congress: i32,
-        bill_type: String,
-        format: Option<String>,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        from_date_time: Option<String>,
-        to_date_time: Option<String>,
-    ) -> PyResult<Vec<Bill>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(o) = offset {
-            params.insert("offset".to_string(), o.to_string());
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
-        }
-        if let Some(from) = from_date_time {
-            params.insert("fromDateTime".to_string(), from);
-        }
-        if let Some(to) = to_date_time {
-            params.insert("toDateTime".to_string(), to);
-        }
-        
-        let endpoint = format!("/bill/{}/{}", congress, bill_type);
-        let response: BillsResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.bills)
-    }
-
-    /// Get detailed information for a specified bill
-    pub fn get_bill(
-        &self,
-        congress: i32,
-        bill_type: String,
-        bill_number: i32,
-    ) -> PyResult<BillDetail> {
-        let endpoint = format!("/bill/{}/{}/{}", congress, bill_type, bill_number);
-        let response: BillDetailResponse = self
-            .client
-            .get(&endpoint, None)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.bill)
-    }
-
-    /// Get the list of actions on a specified bill
-    #[pyo3(signature = (congress, bill_type, bill_number, format=None, offset=None, limit=None))]
-    pub fn get_bill_actions(
-        &self,
-        congress: i32,
-        bill_type: String,
-        bill_number: i32,
-        format: Option<String>,
-        offset: Option<i32>,
-        limit: Option<i32>,
-    ) -> PyResult<Vec<Action>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(o) = offset {
-            params.insert("offset".to_string(), o.to_string());
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
-        }
-        
-        let endpoint = format!("/bill/{}/{}/{}/actions", congress, bill_type, bill_number);
-        let response: ActionsResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.actions)
-    }
-
-    /// Get the list of amendments to a specified bill
-    #[pyo3(signature = (congress, bill_type, bill_number, format=None, offset=None, limit=None))]
-    pub fn get_bill_amendments(
-        &self,
-        congress: i32,
-        bill_type: String,
-        bill_number: i32,
-        format: Option<String>,
-        offset: Option<i32>,
-        limit: Option<i32>,
-    ) -> PyResult<Vec<Amendment>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(o) = offset {
-            params.insert("offset".to_string(), o.to_string());
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
-        }
-        
-        let endpoint = format!("/bill/{}/{}/{}/amendments", congress, bill_type, bill_number);
-        let response: AmendmentsResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.amendments)
-    }
-
-    /// Get the list of committees associated with a specified bill
-    #[pyo3(signature = (congress, bill_type, bill_number, format=None, offset=None, limit=None))]
-    pub fn get_bill_committees(
-        &self,
-        congress: i32,
-        bill_type: String,
-        bill_number: i32,
-        format: Option<String>,
-        offset: Option<i32>,
-        limit: Option<i32>,
-    ) -> PyResult<Vec<Committee>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(o) = offset {
-            params.insert("offset".to_string(), o.to_string());
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
-        }
-        
-        let endpoint = format!("/bill/{}/{}/{}/committees", congress, bill_type, bill_number);
-        let response: CommitteesResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.committees)
-    }
-
-    /// Get the list of cosponsors on a specified bill
-    #[pyo3(signature = (congress, bill_type, bill_number, format=None, offset=None, limit=None))]
-    pub fn get_bill_cosponsors(
-        &self,
-        congress: i32,
-        bill_type: String,
-        bill_number: i32,
-        format: Option<String>,
-        offset: Option<i32>,
-        limit: Option<i32>,
-    ) -> PyResult<Vec<Cosponsor>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(o) = offset {
-            params.insert("offset".to_string(), o.to_string());
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
-        }
-        
-        let endpoint = format!("/bill/{}/{}/{}/cosponsors", congress, bill_type, bill_number);
-        let response: CosponsorsResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.cosponsors)
-    }
-
-    /// Get the list of related bills to a specified bill
-    #[pyo3(signature = (congress, bill_type, bill_number, format=None, offset=None, limit=None))]
-    pub fn get_related_bills(
-        &self,
-        congress: i32,
-        bill_type: String,
-        bill_number: i32,
-        format: Option<String>,
-        offset: Option<i32>,
-        limit: Option<i32>,
-    ) -> PyResult<Vec<RelatedBill>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(o) = offset {
-            params.insert("offset".to_string(), o.to_string());
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
-        }
-        
-        let endpoint = format!("/bill/{}/{}/{}/relatedbills", congress, bill_type, bill_number);
-        let response: RelatedBillsResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.related_bills.unwrap_or_default())
-    }
-
-    /// Get the list of legislative subjects on a specified bill
-    #[pyo3(signature = (congress, bill_type, bill_number, format=None, offset=None, limit=None))]
-    pub fn get_bill_subjects(
-        &self,
-        congress: i32,
-        bill_type: String,
-        bill_number: i32,
-        format: Option<String>,
-        offset: Option<i32>,
-        limit: Option<i32>,
-    ) -> PyResult<Vec<Subject>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(o) = offset {
-            params.insert("offset".to_string(), o.to_string());
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
-        }
-        
-        let endpoint = format!("/bill/{}/{}/{}/subjects", congress, bill_type, bill_number);
-        let response: SubjectsResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.legislative_subjects.unwrap_or_default())
-    }
-
-    /// Get the list of summaries for a specified bill
-    #[pyo3(signature = (congress, bill_type, bill_number, format=None, offset=None, limit=None))]
-    pub fn get_bill_summaries(
-        &self,
-        congress: i32,
-        bill_type: String,
-        bill_number: i32,
-        format: Option<String>,
-        offset: Option<i32>,
-        limit: Option<i32>,
-    ) -> PyResult<Vec<Summary>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(o) = offset {
-            params.insert("offset".to_string(), o.to_string());
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
-        }
-        
-        let endpoint = format!("/bill/{}/{}/{}/summaries", congress, bill_type, bill_number);
-        let response: SummariesResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.summaries)
-    }
-
-    /// Get the list of text versions for a specified bill
-    #[pyo3(signature = (congress, bill_type, bill_number, format=None, offset=None, limit=None))]
-    pub fn get_bill_text(
-        &self,
-        congress: i32,
-        bill_type: String,
-        bill_number: i32,
-        format: Option<String>,
-        offset: Option<i32>,
-        limit: Option<i32>,
-    ) -> PyResult<Vec<TextVersion>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(o) = offset {
-            params.insert("offset".to_string(), o.to_string());
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
-        }
-        
-        let endpoint = format!("/bill/{}/{}/{}/text", congress, bill_type, bill_number);
-        let response: TextVersionsResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.text_versions)
-    }
-
-    /// Get the list of titles for a specified bill
-    #[pyo3(signature = (congress, bill_type, bill_number, format=None, offset=None, limit=None))]
-    pub fn get_bill_titles(
-        &self,
-        congress: i32,
-        bill_type: String,
-        bill_number: i32,
-        format: Option<String>,
-        offset: Option<i32>,
-        limit: Option<i32>,
-    ) -> PyResult<Vec<Title>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(o) = offset {
-            params.insert("offset".to_string(), o.to_string());
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
-        }
-        
-        let endpoint = format!("/bill/{}/{}/{}/titles", congress, bill_type, bill_number);
-        let response: TitlesResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.titles)
-    }
-
-    // ========== Amendment Endpoints ==========
-
-    /// Get a list of amendments sorted by date of latest action
-    #[pyo3(signature = (format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
-    pub fn list_amendments(
-        &self,
-        format: Option<String>,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        from_date_time: Option<String>,
-        to_date_time: Option<String>,
-    ) -> PyResult<Vec<Amendment>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(o) = offset {
-            params.insert("offset".to_string(), o.to_string());
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
-        }
-        if let Some(from) = from_date_time {
-            params.insert("fromDateTime".to_string(), from);
-        }
-        if let Some(to) = to_date_time {
-            params.insert("toDateTime".to_string(), to);
-        }
-        
-        let response: AmendmentsResponse = self
-            .client
-            .get("/amendment", Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
+impl CongressApiClient {
+    pub fn new(
+        api_key: String,
+        http: &HttpConfig,
+        retry: RetryConfig,
+        limiter: Option<TokenBucket>,
+        max_concurrency: Option<usize>,
+        cache: Option<CacheConfig>,
+        cassette: Option<Cassette>,
+    ) -> PyResult<Self> {
+        // Sent as a header so the key never appears in a URL (and therefore
+        // in logs, proxies or error messages); sensitive keeps it out of Debug
+        let mut api_key_header = HeaderValue::from_str(&api_key)
+            .map_err(|_| PyValueError::new_err("api_key contains characters not allowed in an HTTP header"))?;
+        api_key_header.set_sensitive(true);
+
+        Ok(Self {
+            client: http.build_client()?,
+            api_key,
+            api_key_header,
+            base_url: http.base_url.clone(),
+            retry,
+            retry_counters: RetryCounters::default(),
+            limiter,
+            quota: QuotaTracker::default(),
+            concurrency: max_concurrency.map(Semaphore::new),
+            cache: cache.map(|config| ResponseCache::open(config, &http.base_url)).transpose()?,
+            cassette,
+        })
+    }
+
+    /// Validate the keyword arguments shared by the sync and async Python
+    /// clients and build the client from them
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_options(
+        api_key: String,
+        max_retries: u32,
+        backoff_factor: f64,
+        max_backoff: f64,
+        rate_limit: Option<u32>,
+        rate_limit_period: f64,
+        rate_limit_mode: &str,
+        base_url: Option<&str>,
+        timeout: Option<f64>,
+        connect_timeout: Option<f64>,
+        read_timeout: Option<f64>,
+        proxy: Option<String>,
+        ca_bundle: Option<PathBuf>,
+        user_agent: Option<String>,
+        max_concurrency: Option<usize>,
+        cache_dir: Option<PathBuf>,
+        cache_ttl: Option<f64>,
+        cache_ttls: Option<HashMap<String, Option<f64>>>,
+        mode: &str,
+        cassette_dir: Option<PathBuf>,
+    ) -> PyResult<Self> {
+        if !(backoff_factor.is_finite() && backoff_factor >= 0.0) {
+            return Err(PyValueError::new_err("backoff_factor must be a non-negative number"));
+        }
+        if !(max_backoff.is_finite() && max_backoff >= 0.0) {
+            return Err(PyValueError::new_err("max_backoff must be a non-negative number"));
+        }
+        let max_backoff = Duration::try_from_secs_f64(max_backoff)
+            .map_err(|_| PyValueError::new_err("max_backoff is too large"))?;
+        
+        if !(rate_limit_period.is_finite() && rate_limit_period > 0.0) {
+            return Err(PyValueError::new_err("rate_limit_period must be a positive number"));
+        }
+        let rate_limit_period = Duration::try_from_secs_f64(rate_limit_period)
+            .map_err(|_| PyValueError::new_err("rate_limit_period is too large"))?;
+        let limiter_mode = LimiterMode::parse(rate_limit_mode)?;
+        
+        let defaults = HttpConfig::default();
+        let http = HttpConfig {
+            base_url: match base_url {
+                Some(url) => HttpConfig::parse_base_url(url)?,
+                None => defaults.base_url,
+            },
+            timeout: HttpConfig::parse_timeout("timeout", timeout)?,
+            connect_timeout: HttpConfig::parse_timeout("connect_timeout", connect_timeout)?,
+            read_timeout: HttpConfig::parse_timeout("read_timeout", read_timeout)?,
+            proxy,
+            ca_bundle,
+            user_agent: user_agent.unwrap_or(defaults.user_agent),
+        };
+        
+        let retry = RetryConfig {
+            max_retries,
+            backoff_factor,
+            max_backoff,
+        };
+        
+        let limiter = match rate_limit {
+            Some(0) => return Err(PyValueError::new_err("rate_limit must be at least 1")),
+            Some(limit) => Some(TokenBucket::new(limit, rate_limit_period, limiter_mode)),
+            None => None,
+        };
+        
+        if max_concurrency == Some(0) {
+            return Err(PyValueError::new_err("max_concurrency must be at least 1"));
+        }
+        
+        let cache = match cache_dir {
+            Some(dir) => Some(CacheConfig {
+                dir,
+                default_ttl: CacheConfig::parse_ttl("cache_ttl", cache_ttl)?,
+                ttls: CacheConfig::parse_ttls(cache_ttls.unwrap_or_default())?,
+            }),
+            None => None,
+        };
+        
+        let cassette = match (CassetteMode::parse(mode)?, cassette_dir) {
+            (CassetteMode::Live, None) => None,
+            (CassetteMode::Live, Some(_)) => {
+                return Err(PyValueError::new_err("cassette_dir requires mode='record' or 'replay'"))
+            }
+            (_, None) => return Err(PyValueError::new_err(format!("mode={:?} requires cassette_dir", mode))),
+            (mode, Some(dir)) => Some(Cassette::open(mode, dir)?),
+        };
         
-        Ok(response.amendments)
+        Self::new(api_key, &http, retry, limiter, max_concurrency, cache, cassette)
     }
 
-    /// Get amendments filtered by congress
-    #[pyo3(signature = (congress, format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
-    pub fn list_amendments_by_congress(
-        &self,
-        congress: i32,
-        format: Option<String>,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        from_date_time: Option<String>,
-        to_date_time: Option<String>,
-    ) -> PyResult<Vec<Amendment>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(o) = offset {
-            params.insert("offset".to_string(), o.to_string());
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
-        }
-        if let Some(from) = from_date_time {
-            params.insert("fromDateTime".to_string(), from);
-        }
-        if let Some(to) = to_date_time {
-            params.insert("toDateTime".to_string(), to);
-        }
-        
-        let endpoint = format!("/amendment/{}", congress);
-        let response: AmendmentsResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.amendments)
+    pub fn retry_stats(&self) -> RetryStats {
+        self.retry_counters.snapshot()
     }
 
-    // ========== Member Endpoints ==========
-
-    /// Get a list of congressional members
-    #[pyo3(signature = (format=None, offset=None, limit=None, from_date_time=None, to_date_time=None, current_member=None))]
-    pub fn list_members(
-        &self,
-        format: Option<String>,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        from_date_time: Option<String>,
-        to_date_time: Option<String>,
-        current_member: Option<bool>,
-    ) -> PyResult<Vec<Sponsor>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(o) = offset {
-            params.insert("offset".to_string(), o.to_string());
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
-        }
-        if let Some(from) = from_date_time {
-            params.insert("fromDateTime".to_string(), from);
-        }
-        if let Some(to) = to_date_time {
-            params.insert("toDateTime".to_string(), to);
-        }
-        if let Some(cm) = current_member {
-            params.insert("currentMember".to_string(), cm.to_string());
-        }
-        
-        let response: MembersResponse = self
-            .client
-            .get("/member", Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.members)
+    pub fn rate_limit_status(&self) -> RateLimitStatus {
+        self.quota.status(self.limiter.as_ref())
     }
 
-    /// Get detailed information for a specified congressional member
-    pub fn get_member(&self, bioguide_id: String) -> PyResult<Sponsor> {
-        let endpoint = format!("/member/{}", bioguide_id);
-        let response: MemberResponse = self
-            .client
-            .get(&endpoint, None)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.member)
+    pub fn cache_stats(&self) -> CacheStats {
+        self.cache.as_ref().map(ResponseCache::stats).unwrap_or_default()
     }
 
-    /// Get the list of members by congress
-    #[pyo3(signature = (congress, format=None, offset=None, limit=None, current_member=None))]
-    pub fn list_members_by_congress(
-        &self,
-        congress: i32,
-        format: Option<String>,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        current_member: Option<bool>,
-    ) -> PyResult<Vec<Sponsor>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(o) = offset {
-            params.insert("offset".to_string(), o.to_string());
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
-        }
-        if let Some(cm) = current_member {
-            params.insert("currentMember".to_string(), cm.to_string());
-        }
-        
-        let endpoint = format!("/member/congress/{}", congress);
-        let response: MembersResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.members)
+    pub fn clear_cache(&self) -> PyResult<u64> {
+        self.cache.as_ref().map_or(Ok(0), ResponseCache::clear)
     }
 
-    /// Get legislation sponsored by a specified member
-    #[pyo3(signature = (bioguide_id, format=None, offset=None, limit=None))]
-    pub fn get_member_sponsored_legislation(
+    /// Blocking version of `get_async`, driven on the shared Tokio runtime.
+    /// The GIL is released for the request and the JSON decoding so other
+    /// Python threads keep running.
+    pub fn get<T: DeserializeOwned + Send>(
         &self,
-        bioguide_id: String,
-        format: Option<String>,
-        offset: Option<i32>,
-        limit: Option<i32>,
-    ) -> PyResult<Vec<Bill>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(o) = offset {
-            params.insert("offset".to_string(), o.to_string());
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
-        }
-        
-        let endpoint = format!("/member/{}/sponsored-legislation", bioguide_id);
-        let response: SponsoredLegislationResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.sponsored_legislation)
+        py: Python<'_>,
+        endpoint: &str,
+        params: Option<HashMap<String, String>>,
+    ) -> ApiResult<T> {
+        py.allow_threads(|| {
+            pyo3_async_runtimes::tokio::get_runtime().block_on(self.get_async(endpoint, params))
+        })
     }
 
-    /// Get legislation cosponsored by a specified member
-    #[pyo3(signature = (bioguide_id, format=None, offset=None, limit=None))]
-    pub fn get_member_cosponsored_legislation(
+    pub async fn get_async<T: DeserializeOwned>(
         &self,
-        bioguide_id: String,
-        format: Option<String>,
-        offset: Option<i32>,
-        limit: Option<i32>,
-    ) -> PyResult<Vec<Bill>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(o) = offset {
-            params.insert("offset".to_string(), o.to_string());
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
+        endpoint: &str,
+        params: Option<HashMap<String, String>>,
+    ) -> ApiResult<T> {
+        let params = params.unwrap_or_default();
+        let Some(cache) = &self.cache else {
+            let Fetched::Body { body, .. } = self.fetch(endpoint, &params, None).await? else {
+                unreachable!("304 is only accepted for conditional requests");
+            };
+            return decode(endpoint, &body);
+        };
+        
+        let key = cache.key(endpoint, &params);
+        let stale = match cache.lookup(&key, endpoint) {
+            Lookup::Fresh(body) => return decode(endpoint, &body),
+            Lookup::Stale(entry) => Some(entry),
+            Lookup::Miss => None,
+        };
+        
+        match (self.fetch(endpoint, &params, stale.as_ref()).await?, stale) {
+            (Fetched::NotModified, Some(entry)) => decode(endpoint, &cache.refresh(&key, entry)),
+            (Fetched::Body { body, headers }, stale) => {
+                if stale.is_some() {
+                    cache.record_miss();
+                }
+                // Only bodies that decode are worth keeping
+                let value = decode(endpoint, &body)?;
+                cache.store(&key, endpoint, &params, body, &headers);
+                Ok(value)
+            }
+            (Fetched::NotModified, None) => unreachable!("304 is only accepted for conditional requests"),
+        }
+    }
+
+    /// The bill type and number the law detail endpoint is keyed by, for the
+    /// bill that became `law`. The API has no lookup by law number, so this
+    /// pages through the laws of that congress and type.
+    pub async fn find_law_bill(&self, law: &LawId) -> PyResult<(String, String)> {
+        let (congress, law_type, number) = law.parts();
+        let endpoint = format!("/law/{}/{}", congress, law_type.path());
+        let wanted = format!("{}-{}", congress, number);
+        let mut offset = 0;
+        loop {
+            let params = HashMap::from([
+                ("offset".to_string(), offset.to_string()),
+                ("limit".to_string(), "250".to_string()),
+            ]);
+            let response: LawsResponse = self.get_async(&endpoint, Some(params)).await?;
+            for item in &response.bills {
+                let is_law = item.laws.iter().flatten().any(|enacted| {
+                    enacted.number.as_deref() == Some(wanted.as_str())
+                        && !matches!(&enacted.law_type, Some(Code::Known(kind)) if *kind != law_type)
+                });
+                if let (true, Some(bill_type), Some(bill_number)) = (is_law, &item.law_type, &item.number) {
+                    let bill_type = match bill_type {
+                        Code::Known(bill_type) => bill_type.path().to_string(),
+                        Code::Other(bill_type) => bill_type.to_lowercase(),
+                    };
+                    return Ok((bill_type, bill_number.clone()));
+                }
+            }
+            let has_next = response.pagination.is_some_and(|page| page.next.is_some());
+            if response.bills.is_empty() || !has_next {
+                return Err(NotFoundError::new_err(format!("{} is not listed at {}", law.citation(), endpoint)));
+            }
+            offset += response.bills.len();
         }
-        
-        let endpoint = format!("/member/{}/cosponsored-legislation", bioguide_id);
-        let response: CosponsoredLegislationResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.cosponsored_legislation)
     }
 
-    /// Get the list of members by state
-    #[pyo3(signature = (state_code, format=None, limit=None, current_member=None))]
-    pub fn list_members_by_state(
+    /// Send a request, retrying transient failures. When `cached` carries
+    /// validators the request is conditional and may come back `NotModified`.
+    async fn fetch(
         &self,
-        state_code: String,
-        format: Option<String>,
-        limit: Option<i32>,
-        current_member: Option<bool>,
-    ) -> PyResult<Vec<Sponsor>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
-        }
-        if let Some(cm) = current_member {
-            params.insert("currentMember".to_string(), cm.to_string());
+        endpoint: &str,
+        params: &HashMap<String, String>,
+        cached: Option<&CachedResponse>,
+    ) -> ApiResult<Fetched> {
+        let url = format!("{}{}", self.base_url, endpoint);
+        let cached = cached.filter(|entry| entry.has_validators());
+        let tape = self.cassette.as_ref().map(|_| Cassette::tape_name(endpoint, params));
+        let replaying = self.cassette.as_ref().is_some_and(Cassette::is_replay);
+        let mut attempt = 0;
+        
+        self.retry_counters.record_request();
+        
+        loop {
+            let response = match (&self.cassette, &tape) {
+                (Some(cassette), Some(tape)) if replaying => Ok(cassette.replay(tape, endpoint, params)?),
+                _ => {
+                    // Every attempt, retries included, counts against the quota
+                    if let Some(limiter) = &self.limiter {
+                        limiter.acquire().await.map_err(ApiError::RateLimited)?;
+                    }
+                    // Held until the body is read; not while waiting to retry
+                    let _permit = match &self.concurrency {
+                        Some(semaphore) => semaphore.acquire().await.ok(),
+                        None => None,
+                    };
+                    self.quota.record_request();
+                    
+                    let response = self.send(&url, params, cached).await;
+                    if let (Some(cassette), Some(tape), Ok(response)) = (&self.cassette, &tape, &response) {
+                        cassette.record(tape, endpoint, params, response.clone())?;
+                    }
+                    response
+                }
+            };
+            
+            let (retryable, error) = match response {
+                Ok(response) => {
+                    let status = response.status();
+                    let headers = response.header_map();
+                    self.quota.update(&headers);
+                    
+                    if status == StatusCode::NOT_MODIFIED && cached.is_some() {
+                        return Ok(Fetched::NotModified);
+                    }
+                    if status.is_success() {
+                        return Ok(Fetched::Body { body: response.body, headers });
+                    }
+                    (
+                        self.retry.is_retryable_status(status),
+                        ApiError::http(status.as_u16(), endpoint, response.body, retry::retry_after(&headers)),
+                    )
+                }
+                Err(source) => (
+                    self.retry.is_retryable_error(&source),
+                    ApiError::Network {
+                        endpoint: endpoint.to_string(),
+                        message: redact::describe_error(&source, &self.api_key),
+                    },
+                ),
+            };
+            
+            if !retryable {
+                return Err(error);
+            }
+            
+            let delay = if attempt < self.retry.max_retries {
+                self.retry.delay_for(attempt, error.retry_after())
+            } else {
+                None
+            };
+            
+            match delay {
+                Some(delay) => {
+                    self.retry_counters.record_retry();
+                    attempt += 1;
+                    // Replayed tapes already hold the eventual answer
+                    if !replaying {
+                        tokio::time::sleep(delay).await;
+                    }
+                }
+                None => {
+                    self.retry_counters.record_exhausted();
+                    return Err(error);
+                }
+            }
         }
-        
-        let endpoint = format!("/member/{}", state_code);
-        let response: MembersResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.members)
     }
 
-    /// Get the list of members by state and district
-    #[pyo3(signature = (state_code, district, format=None, current_member=None))]
-    pub fn list_members_by_state_district(
+    /// Send one live attempt and read its body
+    async fn send(
         &self,
-        state_code: String,
-        district: i32,
-        format: Option<String>,
-        current_member: Option<bool>,
-    ) -> PyResult<Vec<Sponsor>> {
-        let mut params = HashMap::new();
+        url: &str,
+        params: &HashMap<String, String>,
+        cached: Option<&CachedResponse>,
+    ) -> Result<RawResponse, reqwest::Error> {
+        let mut request = self.client.get(url).header("X-Api-Key", self.api_key_header.clone());
         
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
+        for (key, value) in params {
+            request = request.query(&[(key.as_str(), value.as_str())]);
         }
-        if let Some(cm) = current_member {
-            params.insert("currentMember".to_string(), cm.to_string());
+        if let Some(entry) = cached {
+            if let Some(etag) = &entry.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &entry.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
         }
         
-        let endpoint = format!("/member/{}/{}", state_code, district);
-        let response: MembersResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.members)
+        let response = request.send().await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.text().await?;
+        // Scrub before decoding, caching or recording so echoed `url`
+        // fields never carry the key
+        Ok(RawResponse::new(status, &headers, redact::scrub(&body, &self.api_key)))
     }
+}
 
-    // ========== Committee Endpoints ==========
+// Fails to compile if the shared state stops being thread-safe
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<CongressApiClient>();
+};
 
-    /// Get a list of committees
-    #[pyo3(signature = (format=None, offset=None, limit=None))]
-    pub fn list_committees(
-        &self,
-        format: Option<String>,
-        offset: Option<i32>,
-        limit: Option<i32>,
-    ) -> PyResult<Vec<CommitteeItem>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(o) = offset {
-            params.insert("offset".to_string(), o.to_string());
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
-        }
-        
-        let response: CommitteesListResponse = self
-            .client
-            .get("/committee", Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.committees)
-    }
+// PyO3 wrapper class for Congress.gov API. Frozen: all state lives behind
+// the `Arc`, so one instance can be shared by any number of Python threads.
+#[pyclass(frozen)]
+pub struct CDGPythonClient {
+    client: Arc<CongressApiClient>,
+}
 
-    // ========== Congress/Session Endpoints ==========
+impl Dispatch for CDGPythonClient {
+    type Output<'py, T: 'py> = T;
+    type Iter = PageIterator;
 
-    /// Get a list of congresses and congressional sessions
-    #[pyo3(signature = (format=None, offset=None, limit=None))]
-    pub fn list_congresses(
-        &self,
-        format: Option<String>,
-        offset: Option<i32>,
-        limit: Option<i32>,
-    ) -> PyResult<Vec<Congress>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        if let Some(o) = offset {
-            params.insert("offset".to_string(), o.to_string());
-        }
-        if let Some(l) = limit {
-            params.insert("limit".to_string(), l.to_string());
-        }
-        
-        let response: CongressesResponse = self
-            .client
-            .get("/congress", Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.congresses)
+    fn api(&self) -> Arc<CongressApiClient> {
+        Arc::clone(&self.client)
     }
 
-    /// Get information about a specific congress
-    #[pyo3(signature = (congress, format=None))]
-    pub fn get_congress(
+    /// Blocks on the shared Tokio runtime with the GIL released
+    fn run<'py, T>(
         &self,
-        congress: i32,
-        format: Option<String>,
-    ) -> PyResult<Congress> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/congress/{}", congress);
-        let response: CongressResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.congress)
+        py: Python<'py>,
+        request: impl Future<Output = PyResult<T>> + Send + 'static,
+    ) -> PyResult<T>
+    where
+        T: for<'a> IntoPyObject<'a> + Send + 'static,
+    {
+        py.allow_threads(|| pyo3_async_runtimes::tokio::get_runtime().block_on(request))
     }
 
-    /// Get information about the current congress
-    #[pyo3(signature = (format=None))]
-    pub fn get_current_congress(
+    fn iter<R: Paginated>(
         &self,
-        format: Option<String>,
-    ) -> PyResult<Congress> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let response: CongressResponse = self
-            .client
-            .get("/congress/current", Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.congress)
+        endpoint: &str,
+        params: HashMap<String, String>,
+        offset: Option<u64>,
+        page_size: Option<u32>,
+        max_items: Option<usize>,
+    ) -> PyResult<PageIterator> {
+        PageIterator::new(
+            self.api(),
+            endpoint.to_string(),
+            params,
+            offset,
+            page_size,
+            max_items,
+            fetch_page::<R>,
+        )
     }
+}
 
-    // ========================================
-    // House Vote Operations
-    // ========================================
-
-    /// Get a list of house votes (BETA)
-    #[pyo3(signature = (offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
-    pub fn list_house_votes(
-        &self,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        from_date: Option<String>,
-        to_date: Option<String>,
-        sort: Option<String>,
-        format: Option<String>,
-    ) -> PyResult<Vec<HouseVote>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(from) = from_date {
-            params.insert("fromDateTime".to_string(), from);
-        }
-        if let Some(to) = to_date {
-            params.insert("toDateTime".to_string(), to);
-        }
-        if let Some(s) = sort {
-            params.insert("sort".to_string(), s);
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let response: HouseVotesResponse = self
-            .client
-            .get("/house-vote", Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.votes)
-    }
-
-    /// Get house votes for a specific congress (BETA)
-    #[pyo3(signature = (congress, offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
-    pub fn list_house_votes_by_congress(
-        &self,
-        congress: i32,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        from_date: Option<String>,
-        to_date: Option<String>,
-        sort: Option<String>,
-        format: Option<String>,
-    ) -> PyResult<Vec<HouseVote>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(from) = from_date {
-            params.insert("fromDateTime".to_string(), from);
-        }
-        if let Some(to) = to_date {
-            params.insert("toDateTime".to_string(), to);
-        }
-        if let Some(s) = sort {
-            params.insert("sort".to_string(), s);
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/house-vote/{}", congress);
-        let response: HouseVotesResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.votes)
-    }
-
-    /// Get house votes for a specific congress and session (BETA)
-    #[pyo3(signature = (congress, session, offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
-    pub fn list_house_votes_by_session(
-        &self,
-        congress: i32,
-        session: i32,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        from_date: Option<String>,
-        to_date: Option<String>,
-        sort: Option<String>,
-        format: Option<String>,
-    ) -> PyResult<Vec<HouseVote>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(from) = from_date {
-            params.insert("fromDateTime".to_string(), from);
-        }
-        if let Some(to) = to_date {
-            params.insert("toDateTime".to_string(), to);
-        }
-        if let Some(s) = sort {
-            params.insert("sort".to_string(), s);
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/house-vote/{}/{}", congress, session);
-        let response: HouseVotesResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.votes)
-    }
-
-    /// Get detailed information about a specific house vote (BETA)
-    #[pyo3(signature = (congress, session, vote_number, format=None))]
-    pub fn get_house_vote(
-        &self,
-        congress: i32,
-        session: i32,
-        vote_number: i32,
-        format: Option<String>,
-    ) -> PyResult<HouseVoteDetail> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/house-vote/{}/{}/{}", congress, session, vote_number);
-        let response: HouseVoteDetailResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.vote)
-    }
-
-    /// Get how members voted on a specific house vote (BETA)
-    #[pyo3(signature = (congress, session, vote_number, offset=None, limit=None, format=None))]
-    pub fn get_house_vote_members(
-        &self,
-        congress: i32,
-        session: i32,
-        vote_number: i32,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        format: Option<String>,
-    ) -> PyResult<HouseVoteMembers> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/house-vote/{}/{}/{}/members", congress, session, vote_number);
-        let response: HouseVoteMembersResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.vote)
-    }
-
-    // ========================================
-    // Committee Operations
-    // ========================================
-
-    /// Get committees filtered by chamber
-    #[pyo3(signature = (chamber, offset=None, limit=None, format=None))]
-    pub fn list_committees_by_chamber(
-        &self,
-        chamber: String,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        format: Option<String>,
-    ) -> PyResult<Vec<CommitteeItem>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/committee/{}", chamber);
-        let response: CommitteesListResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.committees)
-    }
-
-    /// Get committees filtered by congress
-    #[pyo3(signature = (congress, offset=None, limit=None, format=None))]
-    pub fn list_committees_by_congress(
-        &self,
-        congress: i32,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        format: Option<String>,
-    ) -> PyResult<Vec<CommitteeItem>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/committee/{}", congress);
-        let response: CommitteesListResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.committees)
-    }
-
-    /// Get committees filtered by congress and chamber
-    #[pyo3(signature = (congress, chamber, offset=None, limit=None, format=None))]
-    pub fn list_committees_by_congress_and_chamber(
-        &self,
-        congress: i32,
-        chamber: String,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        format: Option<String>,
-    ) -> PyResult<Vec<CommitteeItem>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/committee/{}/{}", congress, chamber);
-        let response: CommitteesListResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.committees)
-    }
-
-    /// Get detailed information about a specific committee
-    #[pyo3(signature = (chamber, committee_code, format=None))]
-    pub fn get_committee(
-        &self,
-        chamber: String,
-        committee_code: String,
-        format: Option<String>,
-    ) -> PyResult<CommitteeDetailInfo> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/committee/{}/{}", chamber, committee_code);
-        let response: CommitteeDetailResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.committee)
-    }
-
-    /// Get bills associated with a committee
-    #[pyo3(signature = (chamber, committee_code, offset=None, limit=None, format=None))]
-    pub fn get_committee_bills(
-        &self,
-        chamber: String,
-        committee_code: String,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        format: Option<String>,
-    ) -> PyResult<Vec<CommitteeBill>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/committee/{}/{}/bills", chamber, committee_code);
-        let response: CommitteeBillsResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.bills)
-    }
-
-    // ========================================
-    // Committee Report Operations
-    // ========================================
-
-    /// Get a list of all committee reports
-    #[pyo3(signature = (offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
-    pub fn list_committee_reports(
-        &self,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        from_date: Option<String>,
-        to_date: Option<String>,
-        sort: Option<String>,
-        format: Option<String>,
-    ) -> PyResult<Vec<CommitteeReportItem>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(from) = from_date {
-            params.insert("fromDateTime".to_string(), from);
-        }
-        if let Some(to) = to_date {
-            params.insert("toDateTime".to_string(), to);
-        }
-        if let Some(s) = sort {
-            params.insert("sort".to_string(), s);
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let response: CommitteeReportsResponse = self
-            .client
-            .get("/committee-report", Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.reports)
-    }
-
-    /// Get committee reports filtered by congress
-    #[pyo3(signature = (congress, offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
-    pub fn list_committee_reports_by_congress(
-        &self,
-        congress: i32,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        from_date: Option<String>,
-        to_date: Option<String>,
-        sort: Option<String>,
-        format: Option<String>,
-    ) -> PyResult<Vec<CommitteeReportItem>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(from) = from_date {
-            params.insert("fromDateTime".to_string(), from);
-        }
-        if let Some(to) = to_date {
-            params.insert("toDateTime".to_string(), to);
-        }
-        if let Some(s) = sort {
-            params.insert("sort".to_string(), s);
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/committee-report/{}", congress);
-        let response: CommitteeReportsResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.reports)
-    }
-
-    /// Get committee reports filtered by congress and report type
-    #[pyo3(signature = (congress, report_type, offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
-    pub fn list_committee_reports_by_type(
-        &self,
-        congress: i32,
-        report_type: String,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        from_date: Option<String>,
-        to_date: Option<String>,
-        sort: Option<String>,
-        format: Option<String>,
-    ) -> PyResult<Vec<CommitteeReportItem>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(from) = from_date {
-            params.insert("fromDateTime".to_string(), from);
-        }
-        if let Some(to) = to_date {
-            params.insert("toDateTime".to_string(), to);
-        }
-        if let Some(s) = sort {
-            params.insert("sort".to_string(), s);
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/committee-report/{}/{}", congress, report_type);
-        let response: CommitteeReportsResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.reports)
-    }
-
-    /// Get detailed information about a specific committee report
-    #[pyo3(signature = (congress, report_type, report_number, format=None))]
-    pub fn get_committee_report(
-        &self,
-        congress: i32,
-        report_type: String,
-        report_number: i32,
-        format: Option<String>,
-    ) -> PyResult<CommitteeReportDetail> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/committee-report/{}/{}/{}", congress, report_type, report_number);
-        let response: CommitteeReportDetailResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.report)
-    }
-
-    /// Get text formats available for a committee report
-    #[pyo3(signature = (congress, report_type, report_number, format=None))]
-    pub fn get_committee_report_text(
-        &self,
-        congress: i32,
-        report_type: String,
-        report_number: i32,
-        format: Option<String>,
-    ) -> PyResult<Vec<CommitteeReportText>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/committee-report/{}/{}/{}/text", congress, report_type, report_number);
-        let response: CommitteeReportTextResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.text)
-    }
-
-    // ========================================
-    // Committee Print Operations
-    // ========================================
-
-    /// Get a list of all committee prints
-    #[pyo3(signature = (offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
-    pub fn list_committee_prints(
-        &self,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        from_date: Option<String>,
-        to_date: Option<String>,
-        sort: Option<String>,
-        format: Option<String>,
-    ) -> PyResult<Vec<CommitteePrintItem>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(from) = from_date {
-            params.insert("fromDateTime".to_string(), from);
-        }
-        if let Some(to) = to_date {
-            params.insert("toDateTime".to_string(), to);
-        }
-        if let Some(s) = sort {
-            params.insert("sort".to_string(), s);
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let response: CommitteePrintsResponse = self
-            .client
-            .get("/committee-print", Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.committee_prints)
-    }
-
-    /// Get committee prints filtered by congress
-    #[pyo3(signature = (congress, offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
-    pub fn list_committee_prints_by_congress(
-        &self,
-        congress: i32,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        from_date: Option<String>,
-        to_date: Option<String>,
-        sort: Option<String>,
-        format: Option<String>,
-    ) -> PyResult<Vec<CommitteePrintItem>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(from) = from_date {
-            params.insert("fromDateTime".to_string(), from);
-        }
-        if let Some(to) = to_date {
-            params.insert("toDateTime".to_string(), to);
-        }
-        if let Some(s) = sort {
-            params.insert("sort".to_string(), s);
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/committee-print/{}", congress);
-        let response: CommitteePrintsResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.committee_prints)
-    }
-
-    /// Get committee prints filtered by congress and chamber
-    #[pyo3(signature = (congress, chamber, offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
-    pub fn list_committee_prints_by_chamber(
-        &self,
-        congress: i32,
-        chamber: String,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        from_date: Option<String>,
-        to_date: Option<String>,
-        sort: Option<String>,
-        format: Option<String>,
-    ) -> PyResult<Vec<CommitteePrintItem>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(from) = from_date {
-            params.insert("fromDateTime".to_string(), from);
-        }
-        if let Some(to) = to_date {
-            params.insert("toDateTime".to_string(), to);
-        }
-        if let Some(s) = sort {
-            params.insert("sort".to_string(), s);
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/committee-print/{}/{}", congress, chamber);
-        let response: CommitteePrintsResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.committee_prints)
-    }
-
-    /// Get detailed information about a specific committee print
-    #[pyo3(signature = (congress, chamber, jacket_number, format=None))]
-    pub fn get_committee_print(
-        &self,
-        congress: i32,
-        chamber: String,
-        jacket_number: i32,
-        format: Option<String>,
-    ) -> PyResult<CommitteePrintDetail> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/committee-print/{}/{}/{}", congress, chamber, jacket_number);
-        let response: CommitteePrintDetailResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.committee_print)
-    }
-
-    /// Get text formats available for a committee print
-    #[pyo3(signature = (congress, chamber, jacket_number, format=None))]
-    pub fn get_committee_print_text(
-        &self,
-        congress: i32,
-        chamber: String,
-        jacket_number: i32,
-        format: Option<String>,
-    ) -> PyResult<Vec<CommitteePrintText>> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/committee-print/{}/{}/{}/text", congress, chamber, jacket_number);
-        let response: CommitteePrintTextResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.text)
-    }
-
-    // ========================================
-    // Nomination Operations
-    // ========================================
-
-    /// Get a list of all nominations
-    #[pyo3(signature = (offset=None, limit=None, sort=None, format=None))]
-    pub fn list_nominations(
-        &self,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        sort: Option<String>,
-        format: Option<String>,
-    ) -> PyResult<Vec<Nomination>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(s) = sort {
-            params.insert("sort".to_string(), s);
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let response: NominationsResponse = self
-            .client
-            .get("/nomination", Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.nominations)
-    }
-
-    /// Get nominations by congress
-    #[pyo3(signature = (congress, offset=None, limit=None, sort=None, format=None))]
-    pub fn list_nominations_by_congress(
-        &self,
-        congress: i32,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        sort: Option<String>,
-        format: Option<String>,
-    ) -> PyResult<Vec<Nomination>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(s) = sort {
-            params.insert("sort".to_string(), s);
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/nomination/{}", congress);
-        let response: NominationsResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.nominations)
-    }
-
-    /// Get a specific nomination
-    #[pyo3(signature = (congress, nomination_number, format=None))]
-    pub fn get_nomination(
-        &self,
-        congress: i32,
-        nomination_number: String,
-        format: Option<String>,
-    ) -> PyResult<Nomination> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/nomination/{}/{}", congress, nomination_number);
-        let response: NominationDetailResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.nomination)
-    }
-
-    /// Get nominees for a nomination
-    #[pyo3(signature = (congress, nomination_number, offset=None, limit=None, format=None))]
-    pub fn get_nomination_nominees(
-        &self,
-        congress: i32,
-        nomination_number: String,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        format: Option<String>,
-    ) -> PyResult<Vec<Nominee>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/nomination/{}/{}/nominees", congress, nomination_number);
-        let response: NomineesResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.nominees)
-    }
-
-    // ========================================
-    // Treaty Operations
-    // ========================================
-
-    /// Get a list of all treaties
-    #[pyo3(signature = (offset=None, limit=None, sort=None, format=None))]
-    pub fn list_treaties(
-        &self,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        sort: Option<String>,
-        format: Option<String>,
-    ) -> PyResult<Vec<Treaty>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(s) = sort {
-            params.insert("sort".to_string(), s);
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let response: TreatiesResponse = self
-            .client
-            .get("/treaty", Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.treaties)
-    }
-
-    /// Get treaties by congress
-    #[pyo3(signature = (congress, offset=None, limit=None, sort=None, format=None))]
-    pub fn list_treaties_by_congress(
-        &self,
-        congress: i32,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        sort: Option<String>,
-        format: Option<String>,
-    ) -> PyResult<Vec<Treaty>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(s) = sort {
-            params.insert("sort".to_string(), s);
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/treaty/{}", congress);
-        let response: TreatiesResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.treaties)
-    }
-
-    /// Get a specific treaty
-    #[pyo3(signature = (congress, treaty_number, format=None))]
-    pub fn get_treaty(
-        &self,
-        congress: i32,
-        treaty_number: String,
-        format: Option<String>,
-    ) -> PyResult<Treaty> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/treaty/{}/{}", congress, treaty_number);
-        let response: TreatyDetailResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.treaty)
-    }
-
-    // ========================================
-    // Hearing Operations
-    // ========================================
-
-    /// Get a list of all hearings
-    #[pyo3(signature = (offset=None, limit=None, sort=None, format=None))]
-    pub fn list_hearings(
-        &self,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        sort: Option<String>,
-        format: Option<String>,
-    ) -> PyResult<Vec<Hearing>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(s) = sort {
-            params.insert("sort".to_string(), s);
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let response: HearingsResponse = self
-            .client
-            .get("/hearing", Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.hearings)
-    }
-
-    /// Get hearings by congress
-    #[pyo3(signature = (congress, offset=None, limit=None, sort=None, format=None))]
-    pub fn list_hearings_by_congress(
-        &self,
-        congress: i32,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        sort: Option<String>,
-        format: Option<String>,
-    ) -> PyResult<Vec<Hearing>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(s) = sort {
-            params.insert("sort".to_string(), s);
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/hearing/{}", congress);
-        let response: HearingsResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.hearings)
-    }
-
-    /// Get hearings by congress and chamber
-    #[pyo3(signature = (congress, chamber, offset=None, limit=None, sort=None, format=None))]
-    pub fn list_hearings_by_chamber(
-        &self,
-        congress: i32,
-        chamber: String,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        sort: Option<String>,
-        format: Option<String>,
-    ) -> PyResult<Vec<Hearing>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(s) = sort {
-            params.insert("sort".to_string(), s);
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/hearing/{}/{}", congress, chamber.to_lowercase());
-        let response: HearingsResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.hearings)
-    }
-
-    /// Get a specific hearing
-    #[pyo3(signature = (congress, chamber, jacket_number, format=None))]
-    pub fn get_hearing(
-        &self,
-        congress: i32,
-        chamber: String,
-        jacket_number: i32,
-        format: Option<String>,
-    ) -> PyResult<Hearing> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/hearing/{}/{}/{}", congress, chamber.to_lowercase(), jacket_number);
-        let response: HearingDetailResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.hearing)
-    }
-
-    // ========================================
-    // Congressional Record Operations
-    // ========================================
-
-    /// Get daily congressional records
-    #[pyo3(signature = (offset=None, limit=None, format=None))]
-    pub fn list_congressional_records(
-        &self,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        format: Option<String>,
-    ) -> PyResult<Vec<DailyCongressionalRecord>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let response: DailyCongressionalRecordsResponse = self
-            .client
-            .get("/daily-congressional-record", Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.daily_congressional_record)
-    }
-
-    // ========================================
-    // Law Operations
-    // ========================================
-
-    /// Get a list of all laws
-    #[pyo3(signature = (offset=None, limit=None, format=None))]
-    pub fn list_laws(
-        &self,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        format: Option<String>,
-    ) -> PyResult<Vec<LawItem>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let response: LawsResponse = self
-            .client
-            .get("/law", Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.bills)
-    }
-
-    /// Get laws by congress
-    #[pyo3(signature = (congress, offset=None, limit=None, format=None))]
-    pub fn list_laws_by_congress(
-        &self,
-        congress: i32,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        format: Option<String>,
-    ) -> PyResult<Vec<LawItem>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/law/{}", congress);
-        let response: LawsResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.bills)
-    }
-
-    /// Get laws by congress and type
-    /// Parameters:
-    ///   - congress: The congress number (e.g., 118)
-    ///   - law_type: The law type. Values are either "pub" (public laws) or "priv" (private laws)
-    ///   - offset: Pagination offset (optional)
-    ///   - limit: Number of results to return (optional)
-    ///   - format: Response format (optional)
-    #[pyo3(signature = (congress, law_type, offset=None, limit=None, format=None))]
-    pub fn list_laws_by_type(
-        &self,
-        congress: i32,
-        law_type: String,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        format: Option<String>,
-    ) -> PyResult<Vec<LawItem>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/law/{}/{}", congress, law_type);
-        let response: LawsResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.bills)
-    }
-
-    /// Get a specific law by bill type and bill number
-    /// 
-    /// Note: Despite the swagger documentation referring to "lawType" and "lawNumber",
-    /// the actual API endpoint uses the BILL's type and number, not the resulting law's type/number.
-    /// For example, to get the law that HR 4984 became, use law_type="hr" and law_number="4984"
-    /// 
-    /// Parameters:
-    ///   - congress: The congress number (e.g., 118)
-    ///   - law_type: Bill type like "hr", "s", "hjres", "sjres" (case-insensitive, will be lowercased)
-    ///               This is NOT "pub"/"priv" - those are for list_laws_by_type()
-    ///   - law_number: The bill number as string (e.g., "346" or "4984")
-    #[pyo3(signature = (congress, law_type, law_number, format=None))]
-    pub fn get_law(
-        &self,
-        congress: i32,
-        law_type: String,
-        law_number: String,
-        format: Option<String>,
-    ) -> PyResult<LawDetail> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        // API expects lowercase bill type
-        let law_type_lower = law_type.to_lowercase();
-        let endpoint = format!("/law/{}/{}/{}", congress, law_type_lower, law_number);
-        let response: LawDetailResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.bill)
-    }
-
-    // ========================================
-    // Summaries Operations
-    // ========================================
-
-    /// Get a list of summaries
-    #[pyo3(signature = (offset=None, limit=None, format=None))]
-    pub fn list_summaries(
-        &self,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        format: Option<String>,
-    ) -> PyResult<Vec<SummaryItem>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let response: SummariesListResponse = self
-            .client
-            .get("/summaries", Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.summaries)
-    }
-
-    /// Get summaries by congress
-    #[pyo3(signature = (congress, offset=None, limit=None, format=None))]
-    pub fn list_summaries_by_congress(
-        &self,
-        congress: i32,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        format: Option<String>,
-    ) -> PyResult<Vec<SummaryItem>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/summaries/{}", congress);
-        let response: SummariesListResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.summaries)
-    }
-
-    // ========================================
-    // CRS Report Operations
-    // ========================================
-
-    /// Get a list of CRS reports
-    #[pyo3(signature = (offset=None, limit=None, from_date_time=None, to_date_time=None, format=None))]
-    pub fn list_crs_reports(
-        &self,
-        offset: Option<i32>,
-        limit: Option<i32>,
-        from_date_time: Option<String>,
-        to_date_time: Option<String>,
-        format: Option<String>,
-    ) -> PyResult<Vec<CrsReport>> {
-        let mut params = HashMap::new();
-        
-        if let Some(off) = offset {
-            params.insert("offset".to_string(), off.to_string());
-        }
-        if let Some(lim) = limit {
-            params.insert("limit".to_string(), lim.to_string());
-        }
-        if let Some(from) = from_date_time {
-            params.insert("fromDateTime".to_string(), from);
-        }
-        if let Some(to) = to_date_time {
-            params.insert("toDateTime".to_string(), to);
-        }
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let response: CrsReportsResponse = self
-            .client
-            .get("/crsreport", Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.crs_reports)
-    }
-
-    /// Get detailed information for a specific CRS report
-    #[pyo3(signature = (report_number, format=None))]
-    pub fn get_crs_report(
-        &self,
-        report_number: String,
-        format: Option<String>,
-    ) -> PyResult<CrsReportDetail> {
-        let mut params = HashMap::new();
-        
-        if let Some(f) = format {
-            params.insert("format".to_string(), f);
-        }
-        
-        let endpoint = format!("/crsreport/{}", report_number);
-        let response: CrsReportDetailResponse = self
-            .client
-            .get(&endpoint, Some(params))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
-        
-        Ok(response.report)
-    }
-}
-
+endpoint_methods!(CDGPythonClient {
+    /// Create a client.
+    ///
+    /// Requests that fail with 429 or a transient 5xx status (or a connection
+    /// error/timeout) are retried up to `max_retries` times with exponential
+    /// backoff and jitter, honouring any `Retry-After` header the server sends.
+    ///
+    /// Passing `rate_limit` enables a client-side token bucket allowing that
+    /// many requests per `rate_limit_period` seconds. When the budget is spent
+    /// the request either waits (`rate_limit_mode="block"`) or fails
+    /// immediately (`rate_limit_mode="raise"`).
+    ///
+    /// `base_url`, `timeout` (whole attempt), `connect_timeout`, `read_timeout`
+    /// (between socket reads; all in seconds, `None` for no limit), `proxy`, `ca_bundle` (a PEM file of extra trusted roots) and
+    /// `user_agent` configure the HTTP transport, e.g. to target a mock server
+    /// or go through an egress proxy.
+    ///
+    /// Passing `cache_dir` stores successful responses on disk. Entries stay
+    /// fresh for `cache_ttl` seconds (`None` for ever), overridable per
+    /// endpoint prefix with `cache_ttls`, e.g. `{"/law": None}`; stale entries
+    /// are revalidated with `If-None-Match`/`If-Modified-Since` when the API
+    /// sent an `ETag` or `Last-Modified` header.
+    ///
+    /// `mode="record"` writes every response (API key scrubbed) under
+    /// `cassette_dir`; `mode="replay"` serves them back without touching the
+    /// network and raises `CassetteError` for any request not on tape.
+    #[new]
+    #[pyo3(signature = (
+        api_key,
+        max_retries=3,
+        backoff_factor=0.5,
+        max_backoff=30.0,
+        rate_limit=None,
+        rate_limit_period=3600.0,
+        rate_limit_mode="block",
+        base_url=None,
+        timeout=Some(30.0),
+        connect_timeout=Some(10.0),
+        read_timeout=None,
+        proxy=None,
+        ca_bundle=None,
+        user_agent=None,
+        cache_dir=None,
+        cache_ttl=Some(86400.0),
+        cache_ttls=None,
+        mode="live",
+        cassette_dir=None,
+    ))]
+    pub fn new(
+        api_key: String,
+        max_retries: u32,
+        backoff_factor: f64,
+        max_backoff: f64,
+        rate_limit: Option<u32>,
+        rate_limit_period: f64,
+        rate_limit_mode: &str,
+        base_url: Option<&str>,
+        timeout: Option<f64>,
+        connect_timeout: Option<f64>,
+        read_timeout: Option<f64>,
+        proxy: Option<String>,
+        ca_bundle: Option<PathBuf>,
+        user_agent: Option<String>,
+        cache_dir: Option<PathBuf>,
+        cache_ttl: Option<f64>,
+        cache_ttls: Option<HashMap<String, Option<f64>>>,
+        mode: &str,
+        cassette_dir: Option<PathBuf>,
+    ) -> PyResult<Self> {
+        let client = CongressApiClient::from_options(
+            api_key,
+            max_retries,
+            backoff_factor,
+            max_backoff,
+            rate_limit,
+            rate_limit_period,
+            rate_limit_mode,
+            base_url,
+            timeout,
+            connect_timeout,
+            read_timeout,
+            proxy,
+            ca_bundle,
+            user_agent,
+            None,
+            cache_dir,
+            cache_ttl,
+            cache_ttls,
+            mode,
+            cassette_dir,
+        )?;
+        
+        Ok(Self {
+            client: Arc::new(client),
+        })
+    }
+});
@@ -0,0 +1,416 @@
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyInt, PyString};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::dates::ApiDate;
+use crate::enums::{BillType, Chamber, Code};
+use crate::hearings::HearingCommittee;
+use crate::pagination::{Pagination, RequestEcho};
+use crate::serialization::model_methods;
+
+/// A meeting's event id argument: an `int`, or the digit string the API
+/// returns (such as `Hearing.associated_meeting.event_id`)
+pub struct EventId(String);
+
+impl<'py> FromPyObject<'py> for EventId {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let text = if ob.is_instance_of::<PyInt>() {
+            ob.extract::<u64>()?.to_string()
+        } else if let Ok(text) = ob.downcast::<PyString>() {
+            text.to_str()?.trim().to_string()
+        } else {
+            return Err(PyTypeError::new_err(format!(
+                "expected an event id (int or str), got {}",
+                ob.get_type().name()?
+            )));
+        };
+        if text.is_empty() || !text.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(PyValueError::new_err(format!("invalid event id {:?}", text)));
+        }
+        Ok(EventId(text))
+    }
+}
+
+impl fmt::Display for EventId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Represents a committee meeting in list responses
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
+pub struct CommitteeMeeting {
+    #[pyo3(get)]
+    pub chamber: Option<Code<Chamber>>,
+    
+    #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub congress: Option<i32>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "eventId", alias = "eventid")]
+    pub event_id: Option<String>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "updateDate")]
+    pub update_date: Option<ApiDate>,
+    
+    #[pyo3(get)]
+    pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
+}
+
+model_methods!(raw CommitteeMeeting {
+    fn __repr__(&self) -> String {
+        format!(
+            "CommitteeMeeting(congress={:?}, chamber={:?}, event_id={:?})",
+            self.congress, self.chamber, self.event_id
+        )
+    }
+});
+
+/// Where a committee meeting is held
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
+pub struct MeetingLocation {
+    #[pyo3(get)]
+    pub building: Option<String>,
+    
+    #[pyo3(get)]
+    pub room: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+model_methods!(MeetingLocation {
+    fn __repr__(&self) -> String {
+        format!(
+            "MeetingLocation(building={:?}, room={:?})",
+            self.building, self.room
+        )
+    }
+});
+
+/// A witness at a committee meeting
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
+pub struct Witness {
+    #[pyo3(get)]
+    pub name: Option<String>,
+    
+    #[pyo3(get)]
+    pub organization: Option<String>,
+    
+    #[pyo3(get)]
+    pub position: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+model_methods!(Witness {
+    fn __repr__(&self) -> String {
+        format!(
+            "Witness(name={:?}, organization={:?})",
+            self.name, self.organization
+        )
+    }
+});
+
+/// A statement or other document submitted by a witness
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
+pub struct WitnessDocument {
+    #[pyo3(get)]
+    #[serde(rename = "documentType")]
+    pub document_type: Option<String>,
+    
+    #[pyo3(get)]
+    pub format: Option<String>,
+    
+    #[pyo3(get)]
+    pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+model_methods!(WitnessDocument {
+    fn __repr__(&self) -> String {
+        format!(
+            "WitnessDocument(type={:?}, format={:?})",
+            self.document_type, self.format
+        )
+    }
+});
+
+/// A notice, agenda or other document posted for a committee meeting
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
+pub struct MeetingDocument {
+    #[pyo3(get)]
+    pub name: Option<String>,
+    
+    #[pyo3(get)]
+    pub description: Option<String>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "documentType")]
+    pub document_type: Option<String>,
+    
+    #[pyo3(get)]
+    pub format: Option<String>,
+    
+    #[pyo3(get)]
+    pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+model_methods!(MeetingDocument {
+    fn __repr__(&self) -> String {
+        format!(
+            "MeetingDocument(name={:?}, type={:?}, format={:?})",
+            self.name, self.document_type, self.format
+        )
+    }
+});
+
+/// A video recording of a committee meeting
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
+pub struct MeetingVideo {
+    #[pyo3(get)]
+    pub name: Option<String>,
+    
+    #[pyo3(get)]
+    pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+model_methods!(MeetingVideo {
+    fn __repr__(&self) -> String {
+        format!(
+            "MeetingVideo(name={:?}, url={:?})",
+            self.name, self.url
+        )
+    }
+});
+
+/// A bill considered at a committee meeting
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
+pub struct MeetingBill {
+    #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub congress: Option<i32>,
+    
+    #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub number: Option<i32>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "type")]
+    pub bill_type: Option<Code<BillType>>,
+    
+    #[pyo3(get)]
+    pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+model_methods!(MeetingBill {
+    fn __repr__(&self) -> String {
+        format!(
+            "MeetingBill(congress={:?}, type={:?}, number={:?})",
+            self.congress, self.bill_type, self.number
+        )
+    }
+});
+
+/// A nomination considered at a committee meeting
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
+pub struct MeetingNomination {
+    #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub congress: Option<i32>,
+    
+    #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub number: Option<i32>,
+    
+    #[pyo3(get)]
+    pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+model_methods!(MeetingNomination {
+    fn __repr__(&self) -> String {
+        format!(
+            "MeetingNomination(congress={:?}, number={:?})",
+            self.congress, self.number
+        )
+    }
+});
+
+/// A treaty considered at a committee meeting
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
+pub struct MeetingTreaty {
+    #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub congress: Option<i32>,
+    
+    #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub number: Option<i32>,
+    
+    #[pyo3(get)]
+    pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+model_methods!(MeetingTreaty {
+    fn __repr__(&self) -> String {
+        format!(
+            "MeetingTreaty(congress={:?}, number={:?})",
+            self.congress, self.number
+        )
+    }
+});
+
+/// Bills, nominations and treaties considered at a committee meeting
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
+pub struct MeetingRelatedItems {
+    #[pyo3(get)]
+    pub bills: Option<Vec<MeetingBill>>,
+    
+    #[pyo3(get)]
+    pub nominations: Option<Vec<MeetingNomination>>,
+    
+    #[pyo3(get)]
+    pub treaties: Option<Vec<MeetingTreaty>>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+model_methods!(MeetingRelatedItems {
+    fn __repr__(&self) -> String {
+        fn count<T>(items: &Option<Vec<T>>) -> usize {
+            items.as_ref().map_or(0, Vec::len)
+        }
+        format!(
+            "MeetingRelatedItems(bills={}, nominations={}, treaties={})",
+            count(&self.bills), count(&self.nominations), count(&self.treaties)
+        )
+    }
+});
+
+/// Detailed information for a single committee meeting
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
+pub struct CommitteeMeetingDetail {
+    #[pyo3(get)]
+    pub chamber: Option<Code<Chamber>>,
+    
+    #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub congress: Option<i32>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "eventId", alias = "eventid")]
+    pub event_id: Option<String>,
+    
+    #[pyo3(get)]
+    pub title: Option<String>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "type")]
+    pub meeting_type: Option<String>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "meetingStatus")]
+    pub meeting_status: Option<String>,
+    
+    #[pyo3(get)]
+    pub date: Option<ApiDate>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "updateDate")]
+    pub update_date: Option<ApiDate>,
+    
+    #[pyo3(get)]
+    pub location: Option<MeetingLocation>,
+    
+    #[pyo3(get)]
+    pub committees: Option<Vec<HearingCommittee>>,
+    
+    #[pyo3(get)]
+    pub witnesses: Option<Vec<Witness>>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "witnessDocuments")]
+    pub witness_documents: Option<Vec<WitnessDocument>>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "meetingDocuments")]
+    pub meeting_documents: Option<Vec<MeetingDocument>>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "relatedItems")]
+    pub related_items: Option<MeetingRelatedItems>,
+    
+    #[pyo3(get)]
+    pub videos: Option<Vec<MeetingVideo>>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
+}
+
+model_methods!(raw CommitteeMeetingDetail {
+    fn __repr__(&self) -> String {
+        format!(
+            "CommitteeMeetingDetail(congress={:?}, chamber={:?}, event_id={:?}, status={:?}, title={:?})",
+            self.congress, self.chamber, self.event_id, self.meeting_status, self.title
+        )
+    }
+});
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitteeMeetingsResponse {
+    #[serde(rename = "committeeMeetings")]
+    #[serde(deserialize_with = "crate::raw::list")]
+    pub committee_meetings: Vec<CommitteeMeeting>,
+    pub pagination: Option<Pagination>,
+    pub request: Option<RequestEcho>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitteeMeetingDetailResponse {
+    #[serde(rename = "committeeMeeting")]
+    #[serde(deserialize_with = "crate::raw::one")]
+    pub committee_meeting: CommitteeMeetingDetail,
+}
@@ -1,9 +1,14 @@
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::dates::ApiDate;
+use crate::enums::{BillType, Chamber, Code, ReportType};
+use crate::pagination::{Pagination, RequestEcho};
+use crate::serialization::model_methods;
+
 /// Represents a subcommittee
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct Subcommittee {
     #[pyo3(get)]
     pub name: Option<String>,
@@ -14,18 +19,20 @@ pub struct Subcommittee {
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[pymethods]
-impl Subcommittee {
+model_methods!(Subcommittee {
     fn __repr__(&self) -> String {
         format!("Subcommittee(name={:?}, code={:?})", self.name, self.system_code)
     }
-}
+});
 
 /// Represents a parent committee
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct ParentCommittee {
     #[pyo3(get)]
     pub name: Option<String>,
@@ -36,21 +43,23 @@ pub struct ParentCommittee {
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[pymethods]
-impl ParentCommittee {
+model_methods!(ParentCommittee {
     fn __repr__(&self) -> String {
         format!("ParentCommittee(name={:?}, code={:?})", self.name, self.system_code)
     }
-}
+});
 
 /// Represents a committee in list responses
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct CommitteeItem {
     #[pyo3(get)]
-    pub chamber: Option<String>,
+    pub chamber: Option<Code<Chamber>>,
     
     #[pyo3(get)]
     #[serde(rename = "committeeTypeCode")]
@@ -58,7 +67,7 @@ pub struct CommitteeItem {
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
     
     #[pyo3(get)]
     pub name: Option<String>,
@@ -75,21 +84,26 @@ pub struct CommitteeItem {
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl CommitteeItem {
+model_methods!(raw CommitteeItem {
     fn __repr__(&self) -> String {
         format!(
             "CommitteeItem(chamber={:?}, name={:?}, type={:?})",
             self.chamber, self.name, self.committee_type_code
         )
     }
-}
+});
 
 /// Represents committee history entry
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct CommitteeHistory {
     #[pyo3(get)]
     #[serde(rename = "libraryOfCongressName")]
@@ -101,48 +115,53 @@ pub struct CommitteeHistory {
     
     #[pyo3(get)]
     #[serde(rename = "startDate")]
-    pub start_date: Option<String>,
+    pub start_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "endDate")]
-    pub end_date: Option<String>,
+    pub end_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[pymethods]
-impl CommitteeHistory {
+model_methods!(CommitteeHistory {
     fn __repr__(&self) -> String {
         format!(
             "CommitteeHistory(name={:?}, start={:?})",
             self.official_name, self.start_date
         )
     }
-}
+});
 
 /// Represents a resource count with URL
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct ResourceCount {
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub count: Option<i32>,
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[pymethods]
-impl ResourceCount {
+model_methods!(ResourceCount {
     fn __repr__(&self) -> String {
         format!("ResourceCount(count={:?})", self.count)
     }
-}
+});
 
 /// Represents detailed committee information
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct CommitteeDetailInfo {
     #[pyo3(get)]
     pub bills: Option<ResourceCount>,
@@ -155,6 +174,7 @@ pub struct CommitteeDetailInfo {
     
     #[pyo3(get)]
     #[serde(rename = "isCurrent")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub is_current: Option<bool>,
     
     #[pyo3(get)]
@@ -173,28 +193,34 @@ pub struct CommitteeDetailInfo {
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl CommitteeDetailInfo {
+model_methods!(raw CommitteeDetailInfo {
     fn __repr__(&self) -> String {
         format!(
             "CommitteeDetailInfo(code={:?}, type={:?}, current={:?})",
             self.system_code, self.committee_type, self.is_current
         )
     }
-}
+});
 
 /// Represents a committee bill relationship
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct CommitteeBill {
     #[pyo3(get)]
     #[serde(rename = "actionDate")]
-    pub action_date: Option<String>,
+    pub action_date: Option<ApiDate>,
     
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub congress: Option<i32>,
     
     #[pyo3(get)]
@@ -206,82 +232,97 @@ pub struct CommitteeBill {
     
     #[pyo3(get)]
     #[serde(rename = "type")]
-    pub bill_type: Option<String>,
+    pub bill_type: Option<Code<BillType>>,
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl CommitteeBill {
+model_methods!(raw CommitteeBill {
     fn __repr__(&self) -> String {
         format!(
             "CommitteeBill(congress={:?}, type={:?}, number={:?})",
             self.congress, self.bill_type, self.number
         )
     }
-}
+});
 
 /// Represents a committee report
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct CommitteeReportItem {
     #[pyo3(get)]
     pub citation: Option<String>,
     
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub congress: Option<i32>,
     
     #[pyo3(get)]
     pub number: Option<String>,
     
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub part: Option<i32>,
     
     #[pyo3(get)]
     #[serde(rename = "type")]
-    pub report_type: Option<String>,
+    pub report_type: Option<Code<ReportType>>,
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl CommitteeReportItem {
+model_methods!(raw CommitteeReportItem {
     fn __repr__(&self) -> String {
         format!(
             "CommitteeReportItem(citation={:?}, type={:?})",
             self.citation, self.report_type
         )
     }
-}
+});
 
 /// Represents detailed committee report information
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct CommitteeReportDetail {
     #[pyo3(get)]
     pub citation: Option<String>,
     
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub congress: Option<i32>,
     
     #[pyo3(get)]
     #[serde(rename = "isConferenceReport")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub is_conference_report: Option<bool>,
     
     #[pyo3(get)]
     pub number: Option<String>,
     
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub part: Option<i32>,
     
     #[pyo3(get)]
@@ -292,26 +333,31 @@ pub struct CommitteeReportDetail {
     
     #[pyo3(get)]
     #[serde(rename = "type")]
-    pub report_type: Option<String>,
+    pub report_type: Option<Code<ReportType>>,
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl CommitteeReportDetail {
+model_methods!(raw CommitteeReportDetail {
     fn __repr__(&self) -> String {
         format!(
             "CommitteeReportDetail(citation={:?}, title={:?})",
             self.citation, self.title
         )
     }
-}
+});
 
 /// Represents committee report text format
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct CommitteeReportText {
     #[pyo3(get)]
     #[serde(rename = "type")]
@@ -319,30 +365,37 @@ pub struct CommitteeReportText {
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl CommitteeReportText {
+model_methods!(raw CommitteeReportText {
     fn __repr__(&self) -> String {
         format!("CommitteeReportText(type={:?})", self.text_type)
     }
-}
+});
 
 /// Represents a committee print
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct CommitteePrintItem {
     #[pyo3(get)]
-    pub chamber: Option<String>,
+    pub chamber: Option<Code<Chamber>>,
     
     #[pyo3(get)]
     pub citation: Option<String>,
     
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub congress: Option<i32>,
     
     #[pyo3(get)]
     #[serde(rename = "jacketNumber")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub jacket_number: Option<i32>,
     
     #[pyo3(get)]
@@ -353,34 +406,41 @@ pub struct CommitteePrintItem {
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl CommitteePrintItem {
+model_methods!(raw CommitteePrintItem {
     fn __repr__(&self) -> String {
         format!(
             "CommitteePrintItem(citation={:?}, chamber={:?})",
             self.citation, self.chamber
         )
     }
-}
+});
 
 /// Represents detailed committee print information
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct CommitteePrintDetail {
     #[pyo3(get)]
-    pub chamber: Option<String>,
+    pub chamber: Option<Code<Chamber>>,
     
     #[pyo3(get)]
     pub citation: Option<String>,
     
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub congress: Option<i32>,
     
     #[pyo3(get)]
     #[serde(rename = "jacketNumber")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub jacket_number: Option<i32>,
     
     #[pyo3(get)]
@@ -394,22 +454,27 @@ pub struct CommitteePrintDetail {
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl CommitteePrintDetail {
+model_methods!(raw CommitteePrintDetail {
     fn __repr__(&self) -> String {
         format!(
             "CommitteePrintDetail(citation={:?}, title={:?})",
             self.citation, self.title
         )
     }
-}
+});
 
 /// Represents committee print text format
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct CommitteePrintText {
     #[pyo3(get)]
     #[serde(rename = "type")]
@@ -417,60 +482,80 @@ pub struct CommitteePrintText {
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl CommitteePrintText {
+model_methods!(raw CommitteePrintText {
     fn __repr__(&self) -> String {
         format!("CommitteePrintText(type={:?})", self.text_type)
     }
-}
+});
 
 // Response structures (not exposed to Python)
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommitteesResponse {
+    #[serde(deserialize_with = "crate::raw::list")]
     pub committees: Vec<CommitteeItem>,
+    pub pagination: Option<Pagination>,
+    pub request: Option<RequestEcho>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommitteeDetailResponse {
+    #[serde(deserialize_with = "crate::raw::one")]
     pub committee: CommitteeDetailInfo,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommitteeBillsResponse {
+    #[serde(deserialize_with = "crate::raw::list")]
     pub bills: Vec<CommitteeBill>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommitteeReportsResponse {
+    #[serde(deserialize_with = "crate::raw::list")]
     pub reports: Vec<CommitteeReportItem>,
+    pub pagination: Option<Pagination>,
+    pub request: Option<RequestEcho>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommitteeReportDetailResponse {
+    #[serde(deserialize_with = "crate::raw::one")]
     pub report: CommitteeReportDetail,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommitteeReportTextResponse {
+    #[serde(deserialize_with = "crate::raw::list")]
     pub text: Vec<CommitteeReportText>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommitteePrintsResponse {
     #[serde(rename = "committeePrints")]
+    #[serde(deserialize_with = "crate::raw::list")]
     pub committee_prints: Vec<CommitteePrintItem>,
+    pub pagination: Option<Pagination>,
+    pub request: Option<RequestEcho>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommitteePrintDetailResponse {
     #[serde(rename = "committeePrint")]
+    #[serde(deserialize_with = "crate::raw::one")]
     pub committee_print: CommitteePrintDetail,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommitteePrintTextResponse {
+    #[serde(deserialize_with = "crate::raw::list")]
     pub text: Vec<CommitteePrintText>,
 }
@@ -0,0 +1,301 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::dates::ApiDate;
+use crate::enums::{ApiEnum, Chamber, Code, CommunicationType, EnumArg};
+use crate::pagination::{Pagination, RequestEcho};
+use crate::serialization::model_methods;
+
+/// A House communication type argument: `ec`, `ml`, `pm` or `pt`
+pub struct HouseCommunicationType(CommunicationType);
+
+/// A Senate communication type argument: `ec`, `pm` or `pom`
+pub struct SenateCommunicationType(CommunicationType);
+
+const HOUSE_TYPES: &[CommunicationType] = &[
+    CommunicationType::Ec,
+    CommunicationType::Ml,
+    CommunicationType::Pm,
+    CommunicationType::Pt,
+];
+
+const SENATE_TYPES: &[CommunicationType] = &[
+    CommunicationType::Ec,
+    CommunicationType::Pm,
+    CommunicationType::Pom,
+];
+
+// Each chamber files under its own subset of the codes, so a type from the
+// other chamber is refused here rather than by a 400 from the API
+fn chamber_type(
+    ob: &Bound<'_, PyAny>,
+    chamber: &str,
+    allowed: &[CommunicationType],
+) -> PyResult<CommunicationType> {
+    let EnumArg(kind) = ob.extract::<EnumArg<CommunicationType>>()?;
+    if allowed.contains(&kind) {
+        return Ok(kind);
+    }
+    let valid: Vec<&str> = allowed.iter().map(|kind| kind.path()).collect();
+    Err(PyValueError::new_err(format!(
+        "{} communications have no type {:?}; expected one of {}",
+        chamber,
+        kind.path(),
+        valid.join(", ")
+    )))
+}
+
+impl<'py> FromPyObject<'py> for HouseCommunicationType {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        chamber_type(ob, "House", HOUSE_TYPES).map(HouseCommunicationType)
+    }
+}
+
+impl<'py> FromPyObject<'py> for SenateCommunicationType {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        chamber_type(ob, "Senate", SENATE_TYPES).map(SenateCommunicationType)
+    }
+}
+
+impl fmt::Display for HouseCommunicationType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0.path())
+    }
+}
+
+impl fmt::Display for SenateCommunicationType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0.path())
+    }
+}
+
+/// The code and name of a communication's type
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
+pub struct CommunicationTypeInfo {
+    #[pyo3(get)]
+    pub code: Option<Code<CommunicationType>>,
+    
+    #[pyo3(get)]
+    pub name: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+model_methods!(CommunicationTypeInfo {
+    fn __repr__(&self) -> String {
+        format!(
+            "CommunicationTypeInfo(code={:?}, name={:?})",
+            self.code, self.name
+        )
+    }
+});
+
+/// Represents a House or Senate communication in list responses
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
+pub struct Communication {
+    #[pyo3(get)]
+    pub chamber: Option<Code<Chamber>>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "communicationType")]
+    pub communication_type: Option<CommunicationTypeInfo>,
+    
+    #[pyo3(get)]
+    #[serde(alias = "congressNumber")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub congress: Option<i32>,
+    
+    #[pyo3(get)]
+    #[serde(alias = "communicationNumber")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub number: Option<i32>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "referralDate")]
+    pub referral_date: Option<ApiDate>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "updateDate")]
+    pub update_date: Option<ApiDate>,
+    
+    #[pyo3(get)]
+    pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
+}
+
+model_methods!(raw Communication {
+    fn __repr__(&self) -> String {
+        format!(
+            "Communication(congress={:?}, chamber={:?}, type={:?}, number={:?})",
+            self.congress,
+            self.chamber,
+            self.communication_type.as_ref().and_then(|kind| kind.code.as_ref()),
+            self.number
+        )
+    }
+});
+
+/// A committee a communication was referred to
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
+pub struct CommunicationCommittee {
+    #[pyo3(get)]
+    pub name: Option<String>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "systemCode")]
+    pub system_code: Option<String>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "referralDate")]
+    pub referral_date: Option<ApiDate>,
+    
+    #[pyo3(get)]
+    pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+model_methods!(CommunicationCommittee {
+    fn __repr__(&self) -> String {
+        format!(
+            "CommunicationCommittee(name={:?}, system_code={:?})",
+            self.name, self.system_code
+        )
+    }
+});
+
+/// A House requirement a communication was filed under
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
+pub struct MatchingRequirement {
+    #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub number: Option<i32>,
+    
+    #[pyo3(get)]
+    pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+model_methods!(MatchingRequirement {
+    fn __repr__(&self) -> String {
+        format!(
+            "MatchingRequirement(number={:?})",
+            self.number
+        )
+    }
+});
+
+/// Detailed information for a House or Senate communication
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
+pub struct CommunicationDetail {
+    #[pyo3(get)]
+    #[serde(rename = "abstract")]
+    pub abstract_text: Option<String>,
+    
+    #[pyo3(get)]
+    pub chamber: Option<Code<Chamber>>,
+    
+    #[pyo3(get)]
+    pub committees: Option<Vec<CommunicationCommittee>>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "communicationType")]
+    pub communication_type: Option<CommunicationTypeInfo>,
+    
+    #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub congress: Option<i32>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "congressionalRecordDate")]
+    pub congressional_record_date: Option<ApiDate>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "isRulemaking")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub is_rulemaking: Option<bool>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "legalAuthority")]
+    pub legal_authority: Option<String>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "matchingRequirements")]
+    pub matching_requirements: Option<Vec<MatchingRequirement>>,
+    
+    #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub number: Option<i32>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "reportNature")]
+    pub report_nature: Option<String>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "sessionNumber")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub session_number: Option<i32>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "submittingAgency")]
+    pub submitting_agency: Option<String>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "submittingOfficial")]
+    pub submitting_official: Option<String>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "updateDate")]
+    pub update_date: Option<ApiDate>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
+}
+
+model_methods!(raw CommunicationDetail {
+    fn __repr__(&self) -> String {
+        format!(
+            "CommunicationDetail(congress={:?}, chamber={:?}, type={:?}, number={:?})",
+            self.congress,
+            self.chamber,
+            self.communication_type.as_ref().and_then(|kind| kind.code.as_ref()),
+            self.number
+        )
+    }
+});
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommunicationsResponse {
+    #[serde(rename = "houseCommunications", alias = "senateCommunications")]
+    #[serde(alias = "matchingCommunications", alias = "matchCommunications")]
+    #[serde(deserialize_with = "crate::raw::list")]
+    pub communications: Vec<Communication>,
+    pub pagination: Option<Pagination>,
+    pub request: Option<RequestEcho>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommunicationDetailResponse {
+    #[serde(rename = "house-communication", alias = "houseCommunication", alias = "senateCommunication")]
+    #[serde(deserialize_with = "crate::raw::one")]
+    pub communication: CommunicationDetail,
+}
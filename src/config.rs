@@ -0,0 +1,109 @@
+use pyo3::exceptions::{PyOSError, PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use reqwest::header::HeaderValue;
+use reqwest::{Certificate, Client, Proxy, Url};
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const DEFAULT_BASE_URL: &str = "https://api.congress.gov/v3";
+pub const DEFAULT_USER_AGENT: &str = concat!("cdg_python_client/", env!("CARGO_PKG_VERSION"));
+
+/// Transport settings for the underlying HTTP client
+#[derive(Debug, Clone)]
+pub struct HttpConfig {
+    /// API root without a trailing slash; endpoints are appended to it
+    pub base_url: String,
+    /// Limit on establishing the TCP/TLS connection
+    pub connect_timeout: Option<Duration>,
+    /// Limit on a whole attempt: connecting, sending and reading the body
+    pub timeout: Option<Duration>,
+    /// Limit on each read from the socket, reset whenever data arrives
+    pub read_timeout: Option<Duration>,
+    /// Proxy for all requests; when unset the `HTTP(S)_PROXY` variables apply
+    pub proxy: Option<String>,
+    /// PEM file with extra root certificates to trust
+    pub ca_bundle: Option<PathBuf>,
+    pub user_agent: String,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            connect_timeout: Some(Duration::from_secs(10)),
+            timeout: Some(Duration::from_secs(30)),
+            read_timeout: None,
+            proxy: None,
+            ca_bundle: None,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+        }
+    }
+}
+
+impl HttpConfig {
+    /// Check and normalise a user-supplied base URL
+    pub fn parse_base_url(base_url: &str) -> PyResult<String> {
+        let url = Url::parse(base_url)
+            .map_err(|e| PyValueError::new_err(format!("invalid base_url {:?}: {}", base_url, e)))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(PyValueError::new_err("base_url must use http or https"));
+        }
+        if url.query().is_some() || url.fragment().is_some() {
+            return Err(PyValueError::new_err("base_url must not contain a query or fragment"));
+        }
+        Ok(base_url.trim_end_matches('/').to_string())
+    }
+
+    /// Convert a timeout in seconds, where `None` means no limit
+    pub fn parse_timeout(name: &str, seconds: Option<f64>) -> PyResult<Option<Duration>> {
+        match seconds {
+            None => Ok(None),
+            Some(s) if s.is_finite() && s > 0.0 => Duration::try_from_secs_f64(s)
+                .map(Some)
+                .map_err(|_| PyValueError::new_err(format!("{} is too large", name))),
+            Some(_) => Err(PyValueError::new_err(format!("{} must be a positive number or None", name))),
+        }
+    }
+
+    pub fn build_client(&self) -> PyResult<Client> {
+        let user_agent = HeaderValue::from_str(&self.user_agent)
+            .map_err(|_| PyValueError::new_err("user_agent contains characters not allowed in an HTTP header"))?;
+        let mut builder = Client::builder().user_agent(user_agent);
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(read_timeout) = self.read_timeout {
+            builder = builder.read_timeout(read_timeout);
+        }
+
+        if let Some(proxy) = &self.proxy {
+            let proxy = Proxy::all(proxy.as_str())
+                .map_err(|e| PyValueError::new_err(format!("invalid proxy URL: {}", e)))?;
+            builder = builder.proxy(proxy);
+        }
+
+        if let Some(path) = &self.ca_bundle {
+            let pem = std::fs::read(path).map_err(|e| {
+                PyOSError::new_err(format!("could not read ca_bundle {}: {}", path.display(), e))
+            })?;
+            let certs = Certificate::from_pem_bundle(&pem)
+                .map_err(|e| PyValueError::new_err(format!("invalid ca_bundle {}: {}", path.display(), e)))?;
+            if certs.is_empty() {
+                return Err(PyValueError::new_err(format!(
+                    "ca_bundle {} contains no PEM certificates",
+                    path.display()
+                )));
+            }
+            for cert in certs {
+                builder = builder.add_root_certificate(cert);
+            }
+        }
+
+        builder
+            .build()
+            .map_err(|e| PyRuntimeError::new_err(format!("could not create HTTP client: {}", e)))
+    }
+}
@@ -1,9 +1,13 @@
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::dates::ApiDate;
+use crate::pagination::{Pagination, RequestEcho};
+use crate::serialization::model_methods;
+
 /// Represents a daily congressional record
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct DailyCongressionalRecord {
     #[pyo3(get)]
     #[serde(rename = "issueNumber")]
@@ -11,38 +15,49 @@ pub struct DailyCongressionalRecord {
     
     #[pyo3(get)]
     #[serde(rename = "volumeNumber")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub volume_number: Option<i32>,
     
     #[pyo3(get)]
     #[serde(rename = "issueDate")]
-    pub issue_date: Option<String>,
+    pub issue_date: Option<ApiDate>,
     
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub congress: Option<i32>,
     
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub session: Option<i32>,
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl DailyCongressionalRecord {
+model_methods!(raw DailyCongressionalRecord {
     fn __repr__(&self) -> String {
         format!(
             "DailyCongressionalRecord(volume={:?}, issue={:?}, date={:?})",
             self.volume_number, self.issue_number, self.issue_date
         )
     }
-}
+});
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DailyCongressionalRecordsResponse {
     #[serde(rename = "dailyCongressionalRecord")]
+    #[serde(deserialize_with = "crate::raw::list")]
     pub daily_congressional_record: Vec<DailyCongressionalRecord>,
+    pub pagination: Option<Pagination>,
+    pub request: Option<RequestEcho>,
 }
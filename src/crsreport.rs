@@ -1,63 +1,74 @@
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::dates::ApiDate;
+use crate::pagination::{Pagination, RequestEcho};
+use crate::serialization::model_methods;
+
 /// Represents a CRS report format
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct CrsReportFormat {
     #[pyo3(get)]
     pub format: Option<String>,
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[pymethods]
-impl CrsReportFormat {
+model_methods!(CrsReportFormat {
     fn __repr__(&self) -> String {
         format!("CrsReportFormat(format={:?})", self.format)
     }
-}
+});
 
 /// Represents an author of a CRS report
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct CrsReportAuthor {
     #[pyo3(get)]
     pub author: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[pymethods]
-impl CrsReportAuthor {
+model_methods!(CrsReportAuthor {
     fn __repr__(&self) -> String {
         format!("CrsReportAuthor(author={:?})", self.author)
     }
-}
+});
 
 /// Represents a topic of a CRS report
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct CrsReportTopic {
     #[pyo3(get)]
     pub topic: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[pymethods]
-impl CrsReportTopic {
+model_methods!(CrsReportTopic {
     fn __repr__(&self) -> String {
         format!("CrsReportTopic(topic={:?})", self.topic)
     }
-}
+});
 
 /// Represents related material for a CRS report
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct CrsReportRelatedMaterial {
     #[pyo3(get)]
     #[serde(rename = "URL")]
     pub url: Option<String>,
     
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub congress: Option<i32>,
     
     #[pyo3(get)]
@@ -69,21 +80,23 @@ pub struct CrsReportRelatedMaterial {
     #[pyo3(get)]
     #[serde(rename = "type")]
     pub material_type: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[pymethods]
-impl CrsReportRelatedMaterial {
+model_methods!(CrsReportRelatedMaterial {
     fn __repr__(&self) -> String {
         format!(
             "CrsReportRelatedMaterial(number={:?}, type={:?})",
             self.number, self.material_type
         )
     }
-}
+});
 
 /// Represents a CRS report in list responses
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct CrsReport {
     #[pyo3(get)]
     #[serde(rename = "contentType")]
@@ -94,7 +107,7 @@ pub struct CrsReport {
     
     #[pyo3(get)]
     #[serde(rename = "publishDate")]
-    pub publish_date: Option<String>,
+    pub publish_date: Option<ApiDate>,
     
     #[pyo3(get)]
     pub status: Option<String>,
@@ -104,28 +117,34 @@ pub struct CrsReport {
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
     
     #[pyo3(get)]
     pub url: Option<String>,
     
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub version: Option<i32>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl CrsReport {
+model_methods!(raw CrsReport {
     fn __repr__(&self) -> String {
         format!(
             "CrsReport(id={:?}, title={:?}, version={:?})",
             self.id, self.title, self.version
         )
     }
-}
+});
 
 /// Represents detailed information about a CRS report
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct CrsReportDetail {
     #[pyo3(get)]
     pub authors: Option<Vec<CrsReportAuthor>>,
@@ -142,7 +161,7 @@ pub struct CrsReportDetail {
     
     #[pyo3(get)]
     #[serde(rename = "publishDate")]
-    pub publish_date: Option<String>,
+    pub publish_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "relatedMaterials")]
@@ -162,33 +181,43 @@ pub struct CrsReportDetail {
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
     
     #[pyo3(get)]
     pub url: Option<String>,
     
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub version: Option<i32>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl CrsReportDetail {
+model_methods!(raw CrsReportDetail {
     fn __repr__(&self) -> String {
         format!(
             "CrsReportDetail(id={:?}, title={:?}, version={:?})",
             self.id, self.title, self.version
         )
     }
-}
+});
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CrsReportsResponse {
     #[serde(rename = "CRSReports")]
+    #[serde(deserialize_with = "crate::raw::list")]
     pub crs_reports: Vec<CrsReport>,
+    pub pagination: Option<Pagination>,
+    pub request: Option<RequestEcho>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CrsReportDetailResponse {
     #[serde(rename = "CRSReport")]
+    #[serde(deserialize_with = "crate::raw::one")]
     pub report: CrsReportDetail,
 }
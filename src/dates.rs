@@ -0,0 +1,183 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{timezone_utc, PyDate, PyDateTime, PyString, PyTzInfoAccess};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// A date or timestamp returned by the API.
+///
+/// The API mixes plain dates (`2023-03-14`), UTC timestamps
+/// (`2024-07-23T08:05:37Z`) and timestamps with an offset
+/// (`2023-01-09T12:53:00-05:00`). Python sees a `datetime.date` or an aware
+/// `datetime.datetime`; anything that does not parse is kept as the
+/// original string.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum ApiDate {
+    Date(NaiveDate),
+    DateTime(DateTime<FixedOffset>),
+    Text(String),
+}
+
+impl ApiDate {
+    pub fn parse(text: &str) -> Self {
+        let trimmed = text.trim();
+        if let Ok(datetime) = DateTime::parse_from_rfc3339(trimmed) {
+            return Self::DateTime(datetime);
+        }
+        if let Ok(datetime) = DateTime::parse_from_str(trimmed, "%Y-%m-%d %H:%M:%S%:z") {
+            return Self::DateTime(datetime);
+        }
+        // Timestamps without an offset are in UTC
+        for format in ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S"] {
+            if let Ok(naive) = NaiveDateTime::parse_from_str(trimmed, format) {
+                return Self::DateTime(naive.and_utc().fixed_offset());
+            }
+        }
+        match NaiveDate::parse_from_str(trimmed, "%Y-%m-%d") {
+            Ok(date) => Self::Date(date),
+            Err(_) => Self::Text(text.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for ApiDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Date(date) => write!(f, "{}", date.format("%Y-%m-%d")),
+            Self::DateTime(datetime) => {
+                let utc = datetime.offset().local_minus_utc() == 0;
+                f.write_str(&datetime.to_rfc3339_opts(SecondsFormat::AutoSi, utc))
+            }
+            Self::Text(text) => f.write_str(text),
+        }
+    }
+}
+
+// Debug shows the API's string form so model reprs read as before
+impl fmt::Debug for ApiDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_string(), f)
+    }
+}
+
+impl Serialize for ApiDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ApiDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(|text| Self::parse(&text))
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &ApiDate {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        Ok(match self {
+            ApiDate::Date(date) => date.into_pyobject(py)?.into_any(),
+            ApiDate::DateTime(datetime) => datetime.into_pyobject(py)?.into_any(),
+            ApiDate::Text(text) => text.into_pyobject(py)?.into_any(),
+        })
+    }
+}
+
+impl<'py> IntoPyObject<'py> for ApiDate {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        (&self).into_pyobject(py)
+    }
+}
+
+/// A `from_*`/`to_*` filter argument: a `datetime`, a `date` or an ISO 8601
+/// string. Naive datetimes and strings without an offset are taken as UTC;
+/// aware ones are converted to UTC.
+#[derive(Debug, Clone, Copy)]
+pub enum DateFilter {
+    Date(NaiveDate),
+    DateTime(DateTime<Utc>),
+}
+
+impl DateFilter {
+    fn parse(text: &str) -> PyResult<Self> {
+        match ApiDate::parse(text) {
+            ApiDate::Date(date) => Ok(Self::Date(date)),
+            ApiDate::DateTime(datetime) => Ok(Self::DateTime(datetime.to_utc())),
+            ApiDate::Text(_) => Err(PyValueError::new_err(format!(
+                "invalid date {:?}; expected ISO 8601 such as '2024-01-31' or '2024-01-31T12:00:00Z'",
+                text
+            ))),
+        }
+    }
+
+    /// Earliest instant covered: a date starts at midnight
+    fn start(self) -> DateTime<Utc> {
+        match self {
+            Self::Date(date) => date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc(),
+            Self::DateTime(datetime) => datetime,
+        }
+    }
+
+    /// Latest instant covered: a date runs until 23:59:59
+    fn end(self) -> DateTime<Utc> {
+        match self {
+            Self::Date(date) => date.and_hms_opt(23, 59, 59).unwrap_or_default().and_utc(),
+            Self::DateTime(datetime) => datetime,
+        }
+    }
+}
+
+impl<'py> FromPyObject<'py> for DateFilter {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        // datetime is a subclass of date, so it has to be checked first
+        if let Ok(datetime) = ob.downcast::<PyDateTime>() {
+            if datetime.get_tzinfo().is_none() {
+                return Ok(Self::DateTime(datetime.extract::<NaiveDateTime>()?.and_utc()));
+            }
+            let utc = datetime.call_method1("astimezone", (timezone_utc(ob.py()),))?;
+            return Ok(Self::DateTime(utc.extract()?));
+        }
+        if let Ok(date) = ob.downcast::<PyDate>() {
+            return Ok(Self::Date(date.extract()?));
+        }
+        if let Ok(text) = ob.downcast::<PyString>() {
+            return Self::parse(text.to_str()?);
+        }
+        Err(PyTypeError::new_err(format!(
+            "expected a datetime, date or ISO 8601 string, got {}",
+            ob.get_type().name()?
+        )))
+    }
+}
+
+/// Check a `from`/`to` filter pair and render it in the API's
+/// `YYYY-MM-DDTHH:MM:SSZ` form. A date covers the whole day.
+pub fn date_range(
+    from: Option<DateFilter>,
+    to: Option<DateFilter>,
+) -> PyResult<(Option<String>, Option<String>)> {
+    let from = from.map(DateFilter::start);
+    let to = to.map(DateFilter::end);
+    if let (Some(from), Some(to)) = (from, to) {
+        if from > to {
+            return Err(PyValueError::new_err(format!(
+                "empty date range: from ({}) is after to ({})",
+                api_format(from),
+                api_format(to)
+            )));
+        }
+    }
+    Ok((from.map(api_format), to.map(api_format)))
+}
+
+fn api_format(datetime: DateTime<Utc>) -> String {
+    datetime.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+}
@@ -0,0 +1,4094 @@
+use pyo3::prelude::*;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+
+use crate::client::CongressApiClient;
+use crate::dates::{self, DateFilter};
+use crate::pagination::{count_items_async, fetch_page_async, Page, Paginated};
+
+/// How a client turns a request into its Python result: the blocking client
+/// waits for it with the GIL released, the asyncio client hands back an
+/// awaitable. Every endpoint method is written once against this trait by
+/// `endpoint_methods!`.
+pub trait Dispatch {
+    /// What an endpoint producing `T` returns to Python
+    type Output<'py, T>
+    where
+        T: 'py;
+    /// What the `iter_*` methods return
+    type Iter;
+
+    fn api(&self) -> Arc<CongressApiClient>;
+
+    fn run<'py, T>(
+        &self,
+        py: Python<'py>,
+        request: impl Future<Output = PyResult<T>> + Send + 'static,
+    ) -> PyResult<Self::Output<'py, T>>
+    where
+        T: for<'a> IntoPyObject<'a> + Send + 'static;
+
+    fn iter<R: Paginated>(
+        &self,
+        endpoint: &str,
+        params: HashMap<String, String>,
+        offset: Option<u64>,
+        page_size: Option<u32>,
+        max_items: Option<usize>,
+    ) -> PyResult<Self::Iter>;
+
+    /// Request `endpoint` and keep the part of the response `pick` selects
+    fn fetch<'py, R, T>(
+        &self,
+        py: Python<'py>,
+        endpoint: &str,
+        params: Option<HashMap<String, String>>,
+        pick: impl FnOnce(R) -> T + Send + 'static,
+    ) -> PyResult<Self::Output<'py, T>>
+    where
+        R: DeserializeOwned + Send + 'static,
+        T: for<'a> IntoPyObject<'a> + Send + 'static,
+    {
+        let client = self.api();
+        let endpoint = endpoint.to_string();
+        self.run(py, async move {
+            let response: R = client.get_async(&endpoint, params).await?;
+            Ok(pick(response))
+        })
+    }
+
+    fn page<'py, R: Paginated + 'static>(
+        &self,
+        py: Python<'py>,
+        endpoint: &str,
+        params: HashMap<String, String>,
+    ) -> PyResult<Self::Output<'py, Page>> {
+        self.run(py, fetch_page_async::<R>(self.api(), endpoint.to_string(), params))
+    }
+
+    fn count<'py, R: Paginated + 'static>(
+        &self,
+        py: Python<'py>,
+        endpoint: &str,
+        params: HashMap<String, String>,
+    ) -> PyResult<Self::Output<'py, u64>> {
+        let client = self.api();
+        let endpoint = endpoint.to_string();
+        self.run(py, async move { count_items_async::<R>(client, &endpoint, params).await })
+    }
+}
+
+pub type Output<'py, C, T> = <C as Dispatch>::Output<'py, T>;
+pub type Iter<C> = <C as Dispatch>::Iter;
+
+/// Query parameters every list endpoint takes; `None`s are left out
+pub fn list_params(
+    format: Option<String>,
+    offset: Option<i32>,
+    limit: Option<i32>,
+) -> HashMap<String, String> {
+    let mut params = HashMap::new();
+    
+    if let Some(f) = format {
+        params.insert("format".to_string(), f);
+    }
+    if let Some(o) = offset {
+        params.insert("offset".to_string(), o.to_string());
+    }
+    if let Some(l) = limit {
+        params.insert("limit".to_string(), l.to_string());
+    }
+    params
+}
+
+/// `list_params` plus the `fromDateTime`/`toDateTime` window
+pub fn dated_params(
+    format: Option<String>,
+    offset: Option<i32>,
+    limit: Option<i32>,
+    from_date_time: Option<DateFilter>,
+    to_date_time: Option<DateFilter>,
+) -> PyResult<HashMap<String, String>> {
+    let (from_date_time, to_date_time) = dates::date_range(from_date_time, to_date_time)?;
+    let mut params = list_params(format, offset, limit);
+    
+    if let Some(from) = from_date_time {
+        params.insert("fromDateTime".to_string(), from);
+    }
+    if let Some(to) = to_date_time {
+        params.insert("toDateTime".to_string(), to);
+    }
+    Ok(params)
+}
+
+/// `dated_params` plus `sort`; endpoints without a date window pass `None`
+pub fn sorted_params(
+    format: Option<String>,
+    offset: Option<i32>,
+    limit: Option<i32>,
+    from_date_time: Option<DateFilter>,
+    to_date_time: Option<DateFilter>,
+    sort: Option<String>,
+) -> PyResult<HashMap<String, String>> {
+    let mut params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+    
+    if let Some(s) = sort {
+        params.insert("sort".to_string(), s);
+    }
+    Ok(params)
+}
+
+/// `dated_params` plus `currentMember`, for the member lists
+pub fn member_params(
+    format: Option<String>,
+    offset: Option<i32>,
+    limit: Option<i32>,
+    from_date_time: Option<DateFilter>,
+    to_date_time: Option<DateFilter>,
+    current_member: Option<bool>,
+) -> PyResult<HashMap<String, String>> {
+    let mut params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+    
+    if let Some(cm) = current_member {
+        params.insert("currentMember".to_string(), cm.to_string());
+    }
+    Ok(params)
+}
+
+/// The `#[pymethods]` block of a client: its own methods (`new`) followed by
+/// the shared stats and endpoint methods, dispatched through its `Dispatch`
+/// impl.
+macro_rules! endpoint_methods {
+    ($client:ident { $($own:tt)* }) => {
+        const _: () = {
+            use ::pyo3::prelude::*;
+            use ::std::collections::HashMap;
+
+            use $crate::bills::{
+                Action, ActionsResponse, Amendment, AmendmentsResponse, Bill, BillDetail,
+                BillDetailResponse, BillsResponse, Committee, CommitteesResponse, Cosponsor,
+                CosponsorsResponse, RelatedBill, RelatedBillsResponse, Subject,
+                SubjectsResponse, SummariesResponse, Summary, TextVersion, TextVersionsResponse,
+                Title, TitlesResponse,
+            };
+            use $crate::amendments::{AmendmentDetail, AmendmentDetailResponse};
+            use $crate::members::{
+                CosponsoredLegislationResponse, MemberResponse, MembersResponse, Sponsor,
+                SponsoredLegislationResponse,
+            };
+            use $crate::sessions::{Congress, CongressesResponse, CongressResponse};
+            use $crate::house_votes::{
+                HouseVote, HouseVoteDetail, HouseVoteDetailResponse, HouseVoteMembers,
+                HouseVoteMembersResponse, HouseVotesResponse,
+            };
+            use $crate::committees::{
+                CommitteeBill, CommitteeBillsResponse, CommitteeDetailInfo, CommitteeDetailResponse,
+                CommitteeItem, CommitteePrintDetail, CommitteePrintDetailResponse, CommitteePrintItem,
+                CommitteePrintsResponse, CommitteePrintText, CommitteePrintTextResponse,
+                CommitteeReportDetail, CommitteeReportDetailResponse, CommitteeReportItem,
+                CommitteeReportsResponse, CommitteeReportText, CommitteeReportTextResponse,
+                CommitteesResponse as CommitteesListResponse,
+            };
+            use $crate::nominations::{Nomination, NominationDetailResponse, NominationsResponse, Nominee, NomineesResponse};
+            use $crate::treaties::{Treaty, TreatiesResponse, TreatyDetailResponse};
+            use $crate::hearings::{Hearing, HearingsResponse, HearingDetailResponse};
+            use $crate::committee_meetings::{
+                CommitteeMeeting, CommitteeMeetingDetail, CommitteeMeetingDetailResponse, CommitteeMeetingsResponse,
+                EventId,
+            };
+            use $crate::communications::{
+                Communication, CommunicationDetail, CommunicationDetailResponse, CommunicationsResponse,
+                HouseCommunicationType, SenateCommunicationType,
+            };
+            use $crate::house_requirements::{
+                HouseRequirement, HouseRequirementDetailResponse, HouseRequirementsResponse,
+            };
+            use $crate::congressional_record::{DailyCongressionalRecord, DailyCongressionalRecordsResponse};
+            use $crate::laws::{LawDetail, LawDetailResponse, LawItem, LawsResponse};
+            use $crate::summaries::{SummaryItem, SummariesListResponse};
+            use $crate::crsreport::{CrsReport, CrsReportDetail, CrsReportDetailResponse, CrsReportsResponse};
+            use $crate::cache::CacheStats;
+            use $crate::citations::{AmendmentId, BillId, IdArg, LawArg, ReportId};
+            use $crate::dates::DateFilter;
+            use $crate::endpoints::{
+                dated_params, list_params, member_params, sorted_params, Dispatch, Iter, Output,
+            };
+            use $crate::enums::{AmendmentType, BillType, Chamber, EnumArg, LawType, ReportType};
+            use $crate::pagination::Page;
+            use $crate::raw;
+            use $crate::rate_limit::RateLimitStatus;
+            use $crate::retry::RetryStats;
+
+            #[pymethods]
+            #[allow(clippy::too_many_arguments)]
+            impl $client {
+                $($own)*
+
+                /// Get the quota reported by the API and the state of the local limiter
+                pub fn rate_limit_status(&self) -> RateLimitStatus {
+                    self.api().rate_limit_status()
+                }
+
+                /// Get counters describing how many requests were retried so far
+                pub fn retry_stats(&self) -> RetryStats {
+                    self.api().retry_stats()
+                }
+
+                /// Get hit/miss counters and the size of the response cache
+                pub fn cache_stats(&self) -> CacheStats {
+                    self.api().cache_stats()
+                }
+
+                /// Delete every cached response, returning how many were removed
+                pub fn clear_cache(&self) -> PyResult<u64> {
+                    self.api().clear_cache()
+                }
+
+                /// Fetch any endpoint and return its JSON as plain Python dicts and lists.
+                ///
+                /// `path` is relative to `base_url` (e.g. `"/bill/118/hr/1/actions"`).
+                /// Authentication, rate limiting, retries, caching and error mapping work
+                /// exactly as for the typed methods.
+                #[pyo3(signature = (path, params=None))]
+                pub fn get_raw<'py>(
+                    &self,
+                    py: Python<'py>,
+                    path: &str,
+                    params: Option<HashMap<String, Bound<'py, PyAny>>>,
+                ) -> PyResult<Output<'py, Self, PyObject>> {
+                    let endpoint = raw::endpoint(path)?;
+                    let params = raw::query_params(params)?;
+                    let client = self.api();
+                    self.run(py, async move {
+                        let response: serde_json::Value = client.get_async(&endpoint, Some(params)).await?;
+                        Python::with_gil(|py| raw::to_py(py, &response))
+                    })
+                }
+
+                // ========== Bill Endpoints ==========
+
+                /// Get a list of bills sorted by date of latest action
+                #[pyo3(signature = (format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
+                pub fn list_bills<'py>(
+                    &self,
+                    py: Python<'py>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, Vec<Bill>>> {
+                    let params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+                    
+                    self.fetch(py, "/bill", Some(params), |response: BillsResponse| response.bills)
+                }
+
+                /// Iterate over all bills sorted by date of latest action, fetching pages lazily
+                #[pyo3(signature = (offset=None, from_date_time=None, to_date_time=None, page_size=None, max_items=None))]
+                pub fn iter_bills(
+                    &self,
+                    offset: Option<u64>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = dated_params(None, None, None, from_date_time, to_date_time)?;
+                    
+                    self.iter::<BillsResponse>("/bill", params, offset, page_size, max_items)
+                }
+
+                /// Get a list of bills sorted by date of latest action, returning a `Page` with pagination metadata
+                #[pyo3(signature = (format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
+                pub fn list_bills_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+                    
+                    self.page::<BillsResponse>(py, "/bill", params)
+                }
+
+                /// Count bills, using a single `limit=1` request
+                #[pyo3(signature = (from_date_time=None, to_date_time=None))]
+                pub fn count_bills<'py>(
+                    &self,
+                    py: Python<'py>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = dated_params(None, None, None, from_date_time, to_date_time)?;
+                    
+                    self.count::<BillsResponse>(py, "/bill", params)
+                }
+
+                /// Get bills filtered by congress
+                #[pyo3(signature = (congress, format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
+                pub fn list_bills_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, Vec<Bill>>> {
+                    let params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/bill/{}", congress);
+                    self.fetch(py, &endpoint, Some(params), |response: BillsResponse| response.bills)
+                }
+
+                /// Iterate over all bills filtered by congress, fetching pages lazily
+                #[pyo3(signature = (congress, offset=None, from_date_time=None, to_date_time=None, page_size=None, max_items=None))]
+                pub fn iter_bills_by_congress(
+                    &self,
+                    congress: i32,
+                    offset: Option<u64>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = dated_params(None, None, None, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/bill/{}", congress);
+                    self.iter::<BillsResponse>(&endpoint, params, offset, page_size, max_items)
+                }
+
+                /// Get bills filtered by congress, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
+                pub fn list_bills_by_congress_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/bill/{}", congress);
+                    self.page::<BillsResponse>(py, &endpoint, params)
+                }
+
+                /// Count bills filtered by congress, using a single `limit=1` request
+                #[pyo3(signature = (congress, from_date_time=None, to_date_time=None))]
+                pub fn count_bills_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = dated_params(None, None, None, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/bill/{}", congress);
+                    self.count::<BillsResponse>(py, &endpoint, params)
+                }
+
+                /// Get bills filtered by congress and bill type
+                #[pyo3(signature = (congress, bill_type, format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
+                pub fn list_bills_by_type<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    bill_type: EnumArg<BillType>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, Vec<Bill>>> {
+                    let params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/bill/{}/{}", congress, bill_type);
+                    self.fetch(py, &endpoint, Some(params), |response: BillsResponse| response.bills)
+                }
+
+                /// Iterate over all bills filtered by congress and bill type, fetching pages lazily
+                #[pyo3(signature = (congress, bill_type, offset=None, from_date_time=None, to_date_time=None, page_size=None, max_items=None))]
+                pub fn iter_bills_by_type(
+                    &self,
+                    congress: i32,
+                    bill_type: EnumArg<BillType>,
+                    offset: Option<u64>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = dated_params(None, None, None, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/bill/{}/{}", congress, bill_type);
+                    self.iter::<BillsResponse>(&endpoint, params, offset, page_size, max_items)
+                }
+
+                /// Get bills filtered by congress and bill type, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, bill_type, format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
+                pub fn list_bills_by_type_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    bill_type: EnumArg<BillType>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/bill/{}/{}", congress, bill_type);
+                    self.page::<BillsResponse>(py, &endpoint, params)
+                }
+
+                /// Count bills filtered by congress and bill type, using a single `limit=1` request
+                #[pyo3(signature = (congress, bill_type, from_date_time=None, to_date_time=None))]
+                pub fn count_bills_by_type<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    bill_type: EnumArg<BillType>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = dated_params(None, None, None, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/bill/{}/{}", congress, bill_type);
+                    self.count::<BillsResponse>(py, &endpoint, params)
+                }
+
+                /// Get detailed information for a specified bill
+                #[pyo3(signature = (congress=None, bill_type=None, bill_number=None, *, id=None))]
+                pub fn get_bill<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: Option<i32>,
+                    bill_type: Option<EnumArg<BillType>>,
+                    bill_number: Option<i32>,
+                    id: Option<IdArg<BillId>>,
+                ) -> PyResult<Output<'py, Self, BillDetail>> {
+                    let (congress, bill_type, bill_number) = IdArg::resolve(id, congress, bill_type, bill_number)?;
+                    let endpoint = format!("/bill/{}/{}/{}", congress, bill_type, bill_number);
+                    self.fetch(py, &endpoint, None, |response: BillDetailResponse| response.bill)
+                }
+
+                /// Get the list of actions on a specified bill
+                #[pyo3(signature = (congress=None, bill_type=None, bill_number=None, format=None, offset=None, limit=None, *, id=None))]
+                pub fn get_bill_actions<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: Option<i32>,
+                    bill_type: Option<EnumArg<BillType>>,
+                    bill_number: Option<i32>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    id: Option<IdArg<BillId>>,
+                ) -> PyResult<Output<'py, Self, Vec<Action>>> {
+                    let (congress, bill_type, bill_number) = IdArg::resolve(id, congress, bill_type, bill_number)?;
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/bill/{}/{}/{}/actions", congress, bill_type, bill_number);
+                    self.fetch(py, &endpoint, Some(params), |response: ActionsResponse| response.actions)
+                }
+
+                /// Get the list of amendments to a specified bill
+                #[pyo3(signature = (congress=None, bill_type=None, bill_number=None, format=None, offset=None, limit=None, *, id=None))]
+                pub fn get_bill_amendments<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: Option<i32>,
+                    bill_type: Option<EnumArg<BillType>>,
+                    bill_number: Option<i32>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    id: Option<IdArg<BillId>>,
+                ) -> PyResult<Output<'py, Self, Vec<Amendment>>> {
+                    let (congress, bill_type, bill_number) = IdArg::resolve(id, congress, bill_type, bill_number)?;
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/bill/{}/{}/{}/amendments", congress, bill_type, bill_number);
+                    self.fetch(py, &endpoint, Some(params), |response: AmendmentsResponse| response.amendments)
+                }
+
+                /// Get the list of committees associated with a specified bill
+                #[pyo3(signature = (congress=None, bill_type=None, bill_number=None, format=None, offset=None, limit=None, *, id=None))]
+                pub fn get_bill_committees<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: Option<i32>,
+                    bill_type: Option<EnumArg<BillType>>,
+                    bill_number: Option<i32>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    id: Option<IdArg<BillId>>,
+                ) -> PyResult<Output<'py, Self, Vec<Committee>>> {
+                    let (congress, bill_type, bill_number) = IdArg::resolve(id, congress, bill_type, bill_number)?;
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/bill/{}/{}/{}/committees", congress, bill_type, bill_number);
+                    self.fetch(py, &endpoint, Some(params), |response: CommitteesResponse| response.committees)
+                }
+
+                /// Get the list of cosponsors on a specified bill
+                #[pyo3(signature = (congress=None, bill_type=None, bill_number=None, format=None, offset=None, limit=None, *, id=None))]
+                pub fn get_bill_cosponsors<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: Option<i32>,
+                    bill_type: Option<EnumArg<BillType>>,
+                    bill_number: Option<i32>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    id: Option<IdArg<BillId>>,
+                ) -> PyResult<Output<'py, Self, Vec<Cosponsor>>> {
+                    let (congress, bill_type, bill_number) = IdArg::resolve(id, congress, bill_type, bill_number)?;
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/bill/{}/{}/{}/cosponsors", congress, bill_type, bill_number);
+                    self.fetch(py, &endpoint, Some(params), |response: CosponsorsResponse| response.cosponsors)
+                }
+
+                /// Get the list of related bills to a specified bill
+                #[pyo3(signature = (congress=None, bill_type=None, bill_number=None, format=None, offset=None, limit=None, *, id=None))]
+                pub fn get_related_bills<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: Option<i32>,
+                    bill_type: Option<EnumArg<BillType>>,
+                    bill_number: Option<i32>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    id: Option<IdArg<BillId>>,
+                ) -> PyResult<Output<'py, Self, Vec<RelatedBill>>> {
+                    let (congress, bill_type, bill_number) = IdArg::resolve(id, congress, bill_type, bill_number)?;
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/bill/{}/{}/{}/relatedbills", congress, bill_type, bill_number);
+                    self.fetch(py, &endpoint, Some(params), |response: RelatedBillsResponse| response.related_bills.unwrap_or_default())
+                }
+
+                /// Get the list of legislative subjects on a specified bill
+                #[pyo3(signature = (congress=None, bill_type=None, bill_number=None, format=None, offset=None, limit=None, *, id=None))]
+                pub fn get_bill_subjects<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: Option<i32>,
+                    bill_type: Option<EnumArg<BillType>>,
+                    bill_number: Option<i32>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    id: Option<IdArg<BillId>>,
+                ) -> PyResult<Output<'py, Self, Vec<Subject>>> {
+                    let (congress, bill_type, bill_number) = IdArg::resolve(id, congress, bill_type, bill_number)?;
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/bill/{}/{}/{}/subjects", congress, bill_type, bill_number);
+                    self.fetch(py, &endpoint, Some(params), |response: SubjectsResponse| response.legislative_subjects.unwrap_or_default())
+                }
+
+                /// Get the list of summaries for a specified bill
+                #[pyo3(signature = (congress=None, bill_type=None, bill_number=None, format=None, offset=None, limit=None, *, id=None))]
+                pub fn get_bill_summaries<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: Option<i32>,
+                    bill_type: Option<EnumArg<BillType>>,
+                    bill_number: Option<i32>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    id: Option<IdArg<BillId>>,
+                ) -> PyResult<Output<'py, Self, Vec<Summary>>> {
+                    let (congress, bill_type, bill_number) = IdArg::resolve(id, congress, bill_type, bill_number)?;
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/bill/{}/{}/{}/summaries", congress, bill_type, bill_number);
+                    self.fetch(py, &endpoint, Some(params), |response: SummariesResponse| response.summaries)
+                }
+
+                /// Get the list of text versions for a specified bill
+                #[pyo3(signature = (congress=None, bill_type=None, bill_number=None, format=None, offset=None, limit=None, *, id=None))]
+                pub fn get_bill_text<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: Option<i32>,
+                    bill_type: Option<EnumArg<BillType>>,
+                    bill_number: Option<i32>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    id: Option<IdArg<BillId>>,
+                ) -> PyResult<Output<'py, Self, Vec<TextVersion>>> {
+                    let (congress, bill_type, bill_number) = IdArg::resolve(id, congress, bill_type, bill_number)?;
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/bill/{}/{}/{}/text", congress, bill_type, bill_number);
+                    self.fetch(py, &endpoint, Some(params), |response: TextVersionsResponse| response.text_versions)
+                }
+
+                /// Get the list of titles for a specified bill
+                #[pyo3(signature = (congress=None, bill_type=None, bill_number=None, format=None, offset=None, limit=None, *, id=None))]
+                pub fn get_bill_titles<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: Option<i32>,
+                    bill_type: Option<EnumArg<BillType>>,
+                    bill_number: Option<i32>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    id: Option<IdArg<BillId>>,
+                ) -> PyResult<Output<'py, Self, Vec<Title>>> {
+                    let (congress, bill_type, bill_number) = IdArg::resolve(id, congress, bill_type, bill_number)?;
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/bill/{}/{}/{}/titles", congress, bill_type, bill_number);
+                    self.fetch(py, &endpoint, Some(params), |response: TitlesResponse| response.titles)
+                }
+
+                // ========== Amendment Endpoints ==========
+
+                /// Get a list of amendments sorted by date of latest action
+                #[pyo3(signature = (format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
+                pub fn list_amendments<'py>(
+                    &self,
+                    py: Python<'py>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, Vec<Amendment>>> {
+                    let params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+                    
+                    self.fetch(py, "/amendment", Some(params), |response: AmendmentsResponse| response.amendments)
+                }
+
+                /// Iterate over all amendments sorted by date of latest action, fetching pages lazily
+                #[pyo3(signature = (offset=None, from_date_time=None, to_date_time=None, page_size=None, max_items=None))]
+                pub fn iter_amendments(
+                    &self,
+                    offset: Option<u64>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = dated_params(None, None, None, from_date_time, to_date_time)?;
+                    
+                    self.iter::<AmendmentsResponse>("/amendment", params, offset, page_size, max_items)
+                }
+
+                /// Get a list of amendments sorted by date of latest action, returning a `Page` with pagination metadata
+                #[pyo3(signature = (format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
+                pub fn list_amendments_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+                    
+                    self.page::<AmendmentsResponse>(py, "/amendment", params)
+                }
+
+                /// Count amendments, using a single `limit=1` request
+                #[pyo3(signature = (from_date_time=None, to_date_time=None))]
+                pub fn count_amendments<'py>(
+                    &self,
+                    py: Python<'py>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = dated_params(None, None, None, from_date_time, to_date_time)?;
+                    
+                    self.count::<AmendmentsResponse>(py, "/amendment", params)
+                }
+
+                /// Get amendments filtered by congress
+                #[pyo3(signature = (congress, format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
+                pub fn list_amendments_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, Vec<Amendment>>> {
+                    let params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/amendment/{}", congress);
+                    self.fetch(py, &endpoint, Some(params), |response: AmendmentsResponse| response.amendments)
+                }
+
+                /// Iterate over all amendments filtered by congress, fetching pages lazily
+                #[pyo3(signature = (congress, offset=None, from_date_time=None, to_date_time=None, page_size=None, max_items=None))]
+                pub fn iter_amendments_by_congress(
+                    &self,
+                    congress: i32,
+                    offset: Option<u64>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = dated_params(None, None, None, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/amendment/{}", congress);
+                    self.iter::<AmendmentsResponse>(&endpoint, params, offset, page_size, max_items)
+                }
+
+                /// Get amendments filtered by congress, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
+                pub fn list_amendments_by_congress_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/amendment/{}", congress);
+                    self.page::<AmendmentsResponse>(py, &endpoint, params)
+                }
+
+                /// Count amendments filtered by congress, using a single `limit=1` request
+                #[pyo3(signature = (congress, from_date_time=None, to_date_time=None))]
+                pub fn count_amendments_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = dated_params(None, None, None, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/amendment/{}", congress);
+                    self.count::<AmendmentsResponse>(py, &endpoint, params)
+                }
+
+                /// Get amendments filtered by congress and amendment type
+                #[pyo3(signature = (congress, amendment_type, format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
+                pub fn list_amendments_by_type<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    amendment_type: EnumArg<AmendmentType>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, Vec<Amendment>>> {
+                    let params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/amendment/{}/{}", congress, amendment_type);
+                    self.fetch(py, &endpoint, Some(params), |response: AmendmentsResponse| response.amendments)
+                }
+
+                /// Iterate over all amendments filtered by congress and amendment type, fetching pages lazily
+                #[pyo3(signature = (congress, amendment_type, offset=None, from_date_time=None, to_date_time=None, page_size=None, max_items=None))]
+                pub fn iter_amendments_by_type(
+                    &self,
+                    congress: i32,
+                    amendment_type: EnumArg<AmendmentType>,
+                    offset: Option<u64>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = dated_params(None, None, None, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/amendment/{}/{}", congress, amendment_type);
+                    self.iter::<AmendmentsResponse>(&endpoint, params, offset, page_size, max_items)
+                }
+
+                /// Get amendments filtered by congress and amendment type, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, amendment_type, format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
+                pub fn list_amendments_by_type_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    amendment_type: EnumArg<AmendmentType>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/amendment/{}/{}", congress, amendment_type);
+                    self.page::<AmendmentsResponse>(py, &endpoint, params)
+                }
+
+                /// Count amendments filtered by congress and amendment type, using a single `limit=1` request
+                #[pyo3(signature = (congress, amendment_type, from_date_time=None, to_date_time=None))]
+                pub fn count_amendments_by_type<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    amendment_type: EnumArg<AmendmentType>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = dated_params(None, None, None, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/amendment/{}/{}", congress, amendment_type);
+                    self.count::<AmendmentsResponse>(py, &endpoint, params)
+                }
+
+                /// Get detailed information for a specified amendment
+                #[pyo3(signature = (congress=None, amendment_type=None, amendment_number=None, *, id=None))]
+                pub fn get_amendment<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: Option<i32>,
+                    amendment_type: Option<EnumArg<AmendmentType>>,
+                    amendment_number: Option<i32>,
+                    id: Option<IdArg<AmendmentId>>,
+                ) -> PyResult<Output<'py, Self, AmendmentDetail>> {
+                    let (congress, amendment_type, amendment_number) = IdArg::resolve(id, congress, amendment_type, amendment_number)?;
+                    let endpoint = format!("/amendment/{}/{}/{}", congress, amendment_type, amendment_number);
+                    self.fetch(py, &endpoint, None, |response: AmendmentDetailResponse| response.amendment)
+                }
+
+                /// Get the list of actions on a specified amendment
+                #[pyo3(signature = (congress=None, amendment_type=None, amendment_number=None, format=None, offset=None, limit=None, *, id=None))]
+                pub fn get_amendment_actions<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: Option<i32>,
+                    amendment_type: Option<EnumArg<AmendmentType>>,
+                    amendment_number: Option<i32>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    id: Option<IdArg<AmendmentId>>,
+                ) -> PyResult<Output<'py, Self, Vec<Action>>> {
+                    let (congress, amendment_type, amendment_number) = IdArg::resolve(id, congress, amendment_type, amendment_number)?;
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/amendment/{}/{}/{}/actions", congress, amendment_type, amendment_number);
+                    self.fetch(py, &endpoint, Some(params), |response: ActionsResponse| response.actions)
+                }
+
+                /// Get the list of cosponsors on a specified amendment
+                #[pyo3(signature = (congress=None, amendment_type=None, amendment_number=None, format=None, offset=None, limit=None, *, id=None))]
+                pub fn get_amendment_cosponsors<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: Option<i32>,
+                    amendment_type: Option<EnumArg<AmendmentType>>,
+                    amendment_number: Option<i32>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    id: Option<IdArg<AmendmentId>>,
+                ) -> PyResult<Output<'py, Self, Vec<Cosponsor>>> {
+                    let (congress, amendment_type, amendment_number) = IdArg::resolve(id, congress, amendment_type, amendment_number)?;
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/amendment/{}/{}/{}/cosponsors", congress, amendment_type, amendment_number);
+                    self.fetch(py, &endpoint, Some(params), |response: CosponsorsResponse| response.cosponsors)
+                }
+
+                /// Get the list of amendments to a specified amendment
+                #[pyo3(signature = (congress=None, amendment_type=None, amendment_number=None, format=None, offset=None, limit=None, *, id=None))]
+                pub fn get_amendment_amendments<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: Option<i32>,
+                    amendment_type: Option<EnumArg<AmendmentType>>,
+                    amendment_number: Option<i32>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    id: Option<IdArg<AmendmentId>>,
+                ) -> PyResult<Output<'py, Self, Vec<Amendment>>> {
+                    let (congress, amendment_type, amendment_number) = IdArg::resolve(id, congress, amendment_type, amendment_number)?;
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/amendment/{}/{}/{}/amendments", congress, amendment_type, amendment_number);
+                    self.fetch(py, &endpoint, Some(params), |response: AmendmentsResponse| response.amendments)
+                }
+
+                /// Get the list of text versions for a specified amendment (117th Congress onwards)
+                #[pyo3(signature = (congress=None, amendment_type=None, amendment_number=None, format=None, offset=None, limit=None, *, id=None))]
+                pub fn get_amendment_text<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: Option<i32>,
+                    amendment_type: Option<EnumArg<AmendmentType>>,
+                    amendment_number: Option<i32>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    id: Option<IdArg<AmendmentId>>,
+                ) -> PyResult<Output<'py, Self, Vec<TextVersion>>> {
+                    let (congress, amendment_type, amendment_number) = IdArg::resolve(id, congress, amendment_type, amendment_number)?;
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/amendment/{}/{}/{}/text", congress, amendment_type, amendment_number);
+                    self.fetch(py, &endpoint, Some(params), |response: TextVersionsResponse| response.text_versions)
+                }
+
+                // ========== Member Endpoints ==========
+
+                /// Get a list of congressional members
+                #[pyo3(signature = (format=None, offset=None, limit=None, from_date_time=None, to_date_time=None, current_member=None))]
+                pub fn list_members<'py>(
+                    &self,
+                    py: Python<'py>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                    current_member: Option<bool>,
+                ) -> PyResult<Output<'py, Self, Vec<Sponsor>>> {
+                    let params = member_params(format, offset, limit, from_date_time, to_date_time, current_member)?;
+                    
+                    self.fetch(py, "/member", Some(params), |response: MembersResponse| response.members)
+                }
+
+                /// Iterate over all congressional members, fetching pages lazily
+                #[pyo3(signature = (offset=None, from_date_time=None, to_date_time=None, current_member=None, page_size=None, max_items=None))]
+                pub fn iter_members(
+                    &self,
+                    offset: Option<u64>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                    current_member: Option<bool>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = member_params(None, None, None, from_date_time, to_date_time, current_member)?;
+                    
+                    self.iter::<MembersResponse>("/member", params, offset, page_size, max_items)
+                }
+
+                /// Get a list of congressional members, returning a `Page` with pagination metadata
+                #[pyo3(signature = (format=None, offset=None, limit=None, from_date_time=None, to_date_time=None, current_member=None))]
+                pub fn list_members_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                    current_member: Option<bool>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = member_params(format, offset, limit, from_date_time, to_date_time, current_member)?;
+                    
+                    self.page::<MembersResponse>(py, "/member", params)
+                }
+
+                /// Count congressional members, using a single `limit=1` request
+                #[pyo3(signature = (from_date_time=None, to_date_time=None, current_member=None))]
+                pub fn count_members<'py>(
+                    &self,
+                    py: Python<'py>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                    current_member: Option<bool>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = member_params(None, None, None, from_date_time, to_date_time, current_member)?;
+                    
+                    self.count::<MembersResponse>(py, "/member", params)
+                }
+
+                /// Get detailed information for a specified congressional member
+                pub fn get_member<'py>(&self, py: Python<'py>, bioguide_id: String) -> PyResult<Output<'py, Self, Sponsor>> {
+                    let endpoint = format!("/member/{}", bioguide_id);
+                    self.fetch(py, &endpoint, None, |response: MemberResponse| response.member)
+                }
+
+                /// Get the list of members by congress
+                #[pyo3(signature = (congress, format=None, offset=None, limit=None, current_member=None))]
+                pub fn list_members_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    current_member: Option<bool>,
+                ) -> PyResult<Output<'py, Self, Vec<Sponsor>>> {
+                    let params = member_params(format, offset, limit, None, None, current_member)?;
+                    
+                    let endpoint = format!("/member/congress/{}", congress);
+                    self.fetch(py, &endpoint, Some(params), |response: MembersResponse| response.members)
+                }
+
+                /// Iterate over all members by congress, fetching pages lazily
+                #[pyo3(signature = (congress, offset=None, current_member=None, page_size=None, max_items=None))]
+                pub fn iter_members_by_congress(
+                    &self,
+                    congress: i32,
+                    offset: Option<u64>,
+                    current_member: Option<bool>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = member_params(None, None, None, None, None, current_member)?;
+                    
+                    let endpoint = format!("/member/congress/{}", congress);
+                    self.iter::<MembersResponse>(&endpoint, params, offset, page_size, max_items)
+                }
+
+                /// Get the list of members by congress, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, format=None, offset=None, limit=None, current_member=None))]
+                pub fn list_members_by_congress_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    current_member: Option<bool>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = member_params(format, offset, limit, None, None, current_member)?;
+                    
+                    let endpoint = format!("/member/congress/{}", congress);
+                    self.page::<MembersResponse>(py, &endpoint, params)
+                }
+
+                /// Count members by congress, using a single `limit=1` request
+                #[pyo3(signature = (congress, current_member=None))]
+                pub fn count_members_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    current_member: Option<bool>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = member_params(None, None, None, None, None, current_member)?;
+                    
+                    let endpoint = format!("/member/congress/{}", congress);
+                    self.count::<MembersResponse>(py, &endpoint, params)
+                }
+
+                /// Get legislation sponsored by a specified member
+                #[pyo3(signature = (bioguide_id, format=None, offset=None, limit=None))]
+                pub fn get_member_sponsored_legislation<'py>(
+                    &self,
+                    py: Python<'py>,
+                    bioguide_id: String,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                ) -> PyResult<Output<'py, Self, Vec<Bill>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/member/{}/sponsored-legislation", bioguide_id);
+                    self.fetch(py, &endpoint, Some(params), |response: SponsoredLegislationResponse| response.sponsored_legislation)
+                }
+
+                /// Get legislation cosponsored by a specified member
+                #[pyo3(signature = (bioguide_id, format=None, offset=None, limit=None))]
+                pub fn get_member_cosponsored_legislation<'py>(
+                    &self,
+                    py: Python<'py>,
+                    bioguide_id: String,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                ) -> PyResult<Output<'py, Self, Vec<Bill>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/member/{}/cosponsored-legislation", bioguide_id);
+                    self.fetch(py, &endpoint, Some(params), |response: CosponsoredLegislationResponse| response.cosponsored_legislation)
+                }
+
+                /// Get the list of members by state
+                #[pyo3(signature = (state_code, format=None, limit=None, current_member=None))]
+                pub fn list_members_by_state<'py>(
+                    &self,
+                    py: Python<'py>,
+                    state_code: String,
+                    format: Option<String>,
+                    limit: Option<i32>,
+                    current_member: Option<bool>,
+                ) -> PyResult<Output<'py, Self, Vec<Sponsor>>> {
+                    let params = member_params(format, None, limit, None, None, current_member)?;
+                    
+                    let endpoint = format!("/member/{}", state_code);
+                    self.fetch(py, &endpoint, Some(params), |response: MembersResponse| response.members)
+                }
+
+                /// Iterate over all members by state, fetching pages lazily
+                #[pyo3(signature = (state_code, current_member=None, page_size=None, max_items=None))]
+                pub fn iter_members_by_state(
+                    &self,
+                    state_code: String,
+                    current_member: Option<bool>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = member_params(None, None, None, None, None, current_member)?;
+                    
+                    let endpoint = format!("/member/{}", state_code);
+                    self.iter::<MembersResponse>(&endpoint, params, None, page_size, max_items)
+                }
+
+                /// Get the list of members by state, returning a `Page` with pagination metadata
+                #[pyo3(signature = (state_code, format=None, limit=None, current_member=None))]
+                pub fn list_members_by_state_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    state_code: String,
+                    format: Option<String>,
+                    limit: Option<i32>,
+                    current_member: Option<bool>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = member_params(format, None, limit, None, None, current_member)?;
+                    
+                    let endpoint = format!("/member/{}", state_code);
+                    self.page::<MembersResponse>(py, &endpoint, params)
+                }
+
+                /// Count members by state, using a single `limit=1` request
+                #[pyo3(signature = (state_code, current_member=None))]
+                pub fn count_members_by_state<'py>(
+                    &self,
+                    py: Python<'py>,
+                    state_code: String,
+                    current_member: Option<bool>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = member_params(None, None, None, None, None, current_member)?;
+                    
+                    let endpoint = format!("/member/{}", state_code);
+                    self.count::<MembersResponse>(py, &endpoint, params)
+                }
+
+                /// Get the list of members by state and district
+                #[pyo3(signature = (state_code, district, format=None, current_member=None))]
+                pub fn list_members_by_state_district<'py>(
+                    &self,
+                    py: Python<'py>,
+                    state_code: String,
+                    district: i32,
+                    format: Option<String>,
+                    current_member: Option<bool>,
+                ) -> PyResult<Output<'py, Self, Vec<Sponsor>>> {
+                    let params = member_params(format, None, None, None, None, current_member)?;
+                    
+                    let endpoint = format!("/member/{}/{}", state_code, district);
+                    self.fetch(py, &endpoint, Some(params), |response: MembersResponse| response.members)
+                }
+
+                /// Iterate over all members by state and district, fetching pages lazily
+                #[pyo3(signature = (state_code, district, current_member=None, page_size=None, max_items=None))]
+                pub fn iter_members_by_state_district(
+                    &self,
+                    state_code: String,
+                    district: i32,
+                    current_member: Option<bool>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = member_params(None, None, None, None, None, current_member)?;
+                    
+                    let endpoint = format!("/member/{}/{}", state_code, district);
+                    self.iter::<MembersResponse>(&endpoint, params, None, page_size, max_items)
+                }
+
+                /// Get the list of members by state and district, returning a `Page` with pagination metadata
+                #[pyo3(signature = (state_code, district, format=None, current_member=None))]
+                pub fn list_members_by_state_district_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    state_code: String,
+                    district: i32,
+                    format: Option<String>,
+                    current_member: Option<bool>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = member_params(format, None, None, None, None, current_member)?;
+                    
+                    let endpoint = format!("/member/{}/{}", state_code, district);
+                    self.page::<MembersResponse>(py, &endpoint, params)
+                }
+
+                /// Count members by state and district, using a single `limit=1` request
+                #[pyo3(signature = (state_code, district, current_member=None))]
+                pub fn count_members_by_state_district<'py>(
+                    &self,
+                    py: Python<'py>,
+                    state_code: String,
+                    district: i32,
+                    current_member: Option<bool>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = member_params(None, None, None, None, None, current_member)?;
+                    
+                    let endpoint = format!("/member/{}/{}", state_code, district);
+                    self.count::<MembersResponse>(py, &endpoint, params)
+                }
+
+                // ========== Committee Endpoints ==========
+
+                /// Get a list of committees
+                #[pyo3(signature = (format=None, offset=None, limit=None))]
+                pub fn list_committees<'py>(
+                    &self,
+                    py: Python<'py>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                ) -> PyResult<Output<'py, Self, Vec<CommitteeItem>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    self.fetch(py, "/committee", Some(params), |response: CommitteesListResponse| response.committees)
+                }
+
+                /// Iterate over all committees, fetching pages lazily
+                #[pyo3(signature = (offset=None, page_size=None, max_items=None))]
+                pub fn iter_committees(
+                    &self,
+                    offset: Option<u64>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    self.iter::<CommitteesListResponse>("/committee", HashMap::new(), offset, page_size, max_items)
+                }
+
+                /// Get a list of committees, returning a `Page` with pagination metadata
+                #[pyo3(signature = (format=None, offset=None, limit=None))]
+                pub fn list_committees_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    self.page::<CommitteesListResponse>(py, "/committee", params)
+                }
+
+                /// Count committees, using a single `limit=1` request
+                pub fn count_committees<'py>(&self, py: Python<'py>) -> PyResult<Output<'py, Self, u64>> {
+                    self.count::<CommitteesListResponse>(py, "/committee", HashMap::new())
+                }
+
+                // ========== Congress/Session Endpoints ==========
+
+                /// Get a list of congresses and congressional sessions
+                #[pyo3(signature = (format=None, offset=None, limit=None))]
+                pub fn list_congresses<'py>(
+                    &self,
+                    py: Python<'py>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                ) -> PyResult<Output<'py, Self, Vec<Congress>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    self.fetch(py, "/congress", Some(params), |response: CongressesResponse| response.congresses)
+                }
+
+                /// Iterate over all congresses and congressional sessions, fetching pages lazily
+                #[pyo3(signature = (offset=None, page_size=None, max_items=None))]
+                pub fn iter_congresses(
+                    &self,
+                    offset: Option<u64>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    self.iter::<CongressesResponse>("/congress", HashMap::new(), offset, page_size, max_items)
+                }
+
+                /// Get a list of congresses and congressional sessions, returning a `Page` with pagination metadata
+                #[pyo3(signature = (format=None, offset=None, limit=None))]
+                pub fn list_congresses_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    self.page::<CongressesResponse>(py, "/congress", params)
+                }
+
+                /// Count congresses and congressional sessions, using a single `limit=1` request
+                pub fn count_congresses<'py>(&self, py: Python<'py>) -> PyResult<Output<'py, Self, u64>> {
+                    self.count::<CongressesResponse>(py, "/congress", HashMap::new())
+                }
+
+                /// Get information about a specific congress
+                #[pyo3(signature = (congress, format=None))]
+                pub fn get_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Congress>> {
+                    let mut params = HashMap::new();
+                    
+                    if let Some(f) = format {
+                        params.insert("format".to_string(), f);
+                    }
+                    
+                    let endpoint = format!("/congress/{}", congress);
+                    self.fetch(py, &endpoint, Some(params), |response: CongressResponse| response.congress)
+                }
+
+                /// Get information about the current congress
+                #[pyo3(signature = (format=None))]
+                pub fn get_current_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Congress>> {
+                    let mut params = HashMap::new();
+                    
+                    if let Some(f) = format {
+                        params.insert("format".to_string(), f);
+                    }
+                    
+                    self.fetch(py, "/congress/current", Some(params), |response: CongressResponse| response.congress)
+                }
+
+                // ========================================
+                // House Vote Operations
+                // ========================================
+
+                /// Get a list of house votes (BETA)
+                #[pyo3(signature = (offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
+                pub fn list_house_votes<'py>(
+                    &self,
+                    py: Python<'py>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<HouseVote>>> {
+                    let params = sorted_params(format, offset, limit, from_date, to_date, sort)?;
+                    
+                    self.fetch(py, "/house-vote", Some(params), |response: HouseVotesResponse| response.votes)
+                }
+
+                /// Iterate over all house votes (BETA), fetching pages lazily
+                #[pyo3(signature = (offset=None, from_date=None, to_date=None, sort=None, page_size=None, max_items=None))]
+                pub fn iter_house_votes(
+                    &self,
+                    offset: Option<u64>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = sorted_params(None, None, None, from_date, to_date, sort)?;
+                    
+                    self.iter::<HouseVotesResponse>("/house-vote", params, offset, page_size, max_items)
+                }
+
+                /// Get a list of house votes (BETA), returning a `Page` with pagination metadata
+                #[pyo3(signature = (offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
+                pub fn list_house_votes_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = sorted_params(format, offset, limit, from_date, to_date, sort)?;
+                    
+                    self.page::<HouseVotesResponse>(py, "/house-vote", params)
+                }
+
+                /// Count house votes (BETA), using a single `limit=1` request
+                #[pyo3(signature = (from_date=None, to_date=None, sort=None))]
+                pub fn count_house_votes<'py>(
+                    &self,
+                    py: Python<'py>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = sorted_params(None, None, None, from_date, to_date, sort)?;
+                    
+                    self.count::<HouseVotesResponse>(py, "/house-vote", params)
+                }
+
+                /// Get house votes for a specific congress (BETA)
+                #[pyo3(signature = (congress, offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
+                pub fn list_house_votes_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<HouseVote>>> {
+                    let params = sorted_params(format, offset, limit, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/house-vote/{}", congress);
+                    self.fetch(py, &endpoint, Some(params), |response: HouseVotesResponse| response.votes)
+                }
+
+                /// Iterate over all house votes for a specific congress (BETA), fetching pages lazily
+                #[pyo3(signature = (congress, offset=None, from_date=None, to_date=None, sort=None, page_size=None, max_items=None))]
+                pub fn iter_house_votes_by_congress(
+                    &self,
+                    congress: i32,
+                    offset: Option<u64>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = sorted_params(None, None, None, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/house-vote/{}", congress);
+                    self.iter::<HouseVotesResponse>(&endpoint, params, offset, page_size, max_items)
+                }
+
+                /// Get house votes for a specific congress (BETA), returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
+                pub fn list_house_votes_by_congress_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = sorted_params(format, offset, limit, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/house-vote/{}", congress);
+                    self.page::<HouseVotesResponse>(py, &endpoint, params)
+                }
+
+                /// Count house votes for a specific congress (BETA), using a single `limit=1` request
+                #[pyo3(signature = (congress, from_date=None, to_date=None, sort=None))]
+                pub fn count_house_votes_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = sorted_params(None, None, None, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/house-vote/{}", congress);
+                    self.count::<HouseVotesResponse>(py, &endpoint, params)
+                }
+
+                /// Get house votes for a specific congress and session (BETA)
+                #[pyo3(signature = (congress, session, offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
+                pub fn list_house_votes_by_session<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    session: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<HouseVote>>> {
+                    let params = sorted_params(format, offset, limit, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/house-vote/{}/{}", congress, session);
+                    self.fetch(py, &endpoint, Some(params), |response: HouseVotesResponse| response.votes)
+                }
+
+                /// Iterate over all house votes for a specific congress and session (BETA), fetching pages lazily
+                #[pyo3(signature = (congress, session, offset=None, from_date=None, to_date=None, sort=None, page_size=None, max_items=None))]
+                pub fn iter_house_votes_by_session(
+                    &self,
+                    congress: i32,
+                    session: i32,
+                    offset: Option<u64>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = sorted_params(None, None, None, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/house-vote/{}/{}", congress, session);
+                    self.iter::<HouseVotesResponse>(&endpoint, params, offset, page_size, max_items)
+                }
+
+                /// Get house votes for a specific congress and session (BETA), returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, session, offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
+                pub fn list_house_votes_by_session_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    session: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = sorted_params(format, offset, limit, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/house-vote/{}/{}", congress, session);
+                    self.page::<HouseVotesResponse>(py, &endpoint, params)
+                }
+
+                /// Count house votes for a specific congress and session (BETA), using a single `limit=1` request
+                #[pyo3(signature = (congress, session, from_date=None, to_date=None, sort=None))]
+                pub fn count_house_votes_by_session<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    session: i32,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = sorted_params(None, None, None, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/house-vote/{}/{}", congress, session);
+                    self.count::<HouseVotesResponse>(py, &endpoint, params)
+                }
+
+                /// Get detailed information about a specific house vote (BETA)
+                #[pyo3(signature = (congress, session, vote_number, format=None))]
+                pub fn get_house_vote<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    session: i32,
+                    vote_number: i32,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, HouseVoteDetail>> {
+                    let mut params = HashMap::new();
+                    
+                    if let Some(f) = format {
+                        params.insert("format".to_string(), f);
+                    }
+                    
+                    let endpoint = format!("/house-vote/{}/{}/{}", congress, session, vote_number);
+                    self.fetch(py, &endpoint, Some(params), |response: HouseVoteDetailResponse| response.vote)
+                }
+
+                /// Get how members voted on a specific house vote (BETA)
+                #[pyo3(signature = (congress, session, vote_number, offset=None, limit=None, format=None))]
+                pub fn get_house_vote_members<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    session: i32,
+                    vote_number: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, HouseVoteMembers>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/house-vote/{}/{}/{}/members", congress, session, vote_number);
+                    self.fetch(py, &endpoint, Some(params), |response: HouseVoteMembersResponse| response.vote)
+                }
+
+                // ========================================
+                // Committee Operations
+                // ========================================
+
+                /// Get committees filtered by chamber
+                #[pyo3(signature = (chamber, offset=None, limit=None, format=None))]
+                pub fn list_committees_by_chamber<'py>(
+                    &self,
+                    py: Python<'py>,
+                    chamber: EnumArg<Chamber>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<CommitteeItem>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/committee/{}", chamber);
+                    self.fetch(py, &endpoint, Some(params), |response: CommitteesListResponse| response.committees)
+                }
+
+                /// Iterate over all committees filtered by chamber, fetching pages lazily
+                #[pyo3(signature = (chamber, offset=None, page_size=None, max_items=None))]
+                pub fn iter_committees_by_chamber(
+                    &self,
+                    chamber: EnumArg<Chamber>,
+                    offset: Option<u64>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let endpoint = format!("/committee/{}", chamber);
+                    self.iter::<CommitteesListResponse>(&endpoint, HashMap::new(), offset, page_size, max_items)
+                }
+
+                /// Get committees filtered by chamber, returning a `Page` with pagination metadata
+                #[pyo3(signature = (chamber, offset=None, limit=None, format=None))]
+                pub fn list_committees_by_chamber_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    chamber: EnumArg<Chamber>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/committee/{}", chamber);
+                    self.page::<CommitteesListResponse>(py, &endpoint, params)
+                }
+
+                /// Count committees filtered by chamber, using a single `limit=1` request
+                #[pyo3(signature = (chamber))]
+                pub fn count_committees_by_chamber<'py>(
+                    &self,
+                    py: Python<'py>,
+                    chamber: EnumArg<Chamber>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let endpoint = format!("/committee/{}", chamber);
+                    self.count::<CommitteesListResponse>(py, &endpoint, HashMap::new())
+                }
+
+                /// Get committees filtered by congress
+                #[pyo3(signature = (congress, offset=None, limit=None, format=None))]
+                pub fn list_committees_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<CommitteeItem>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/committee/{}", congress);
+                    self.fetch(py, &endpoint, Some(params), |response: CommitteesListResponse| response.committees)
+                }
+
+                /// Iterate over all committees filtered by congress, fetching pages lazily
+                #[pyo3(signature = (congress, offset=None, page_size=None, max_items=None))]
+                pub fn iter_committees_by_congress(
+                    &self,
+                    congress: i32,
+                    offset: Option<u64>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let endpoint = format!("/committee/{}", congress);
+                    self.iter::<CommitteesListResponse>(&endpoint, HashMap::new(), offset, page_size, max_items)
+                }
+
+                /// Get committees filtered by congress, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, offset=None, limit=None, format=None))]
+                pub fn list_committees_by_congress_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/committee/{}", congress);
+                    self.page::<CommitteesListResponse>(py, &endpoint, params)
+                }
+
+                /// Count committees filtered by congress, using a single `limit=1` request
+                #[pyo3(signature = (congress))]
+                pub fn count_committees_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let endpoint = format!("/committee/{}", congress);
+                    self.count::<CommitteesListResponse>(py, &endpoint, HashMap::new())
+                }
+
+                /// Get committees filtered by congress and chamber
+                #[pyo3(signature = (congress, chamber, offset=None, limit=None, format=None))]
+                pub fn list_committees_by_congress_and_chamber<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<CommitteeItem>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/committee/{}/{}", congress, chamber);
+                    self.fetch(py, &endpoint, Some(params), |response: CommitteesListResponse| response.committees)
+                }
+
+                /// Iterate over all committees filtered by congress and chamber, fetching pages lazily
+                #[pyo3(signature = (congress, chamber, offset=None, page_size=None, max_items=None))]
+                pub fn iter_committees_by_congress_and_chamber(
+                    &self,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                    offset: Option<u64>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let endpoint = format!("/committee/{}/{}", congress, chamber);
+                    self.iter::<CommitteesListResponse>(&endpoint, HashMap::new(), offset, page_size, max_items)
+                }
+
+                /// Get committees filtered by congress and chamber, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, chamber, offset=None, limit=None, format=None))]
+                pub fn list_committees_by_congress_and_chamber_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/committee/{}/{}", congress, chamber);
+                    self.page::<CommitteesListResponse>(py, &endpoint, params)
+                }
+
+                /// Count committees filtered by congress and chamber, using a single `limit=1` request
+                #[pyo3(signature = (congress, chamber))]
+                pub fn count_committees_by_congress_and_chamber<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let endpoint = format!("/committee/{}/{}", congress, chamber);
+                    self.count::<CommitteesListResponse>(py, &endpoint, HashMap::new())
+                }
+
+                /// Get detailed information about a specific committee
+                #[pyo3(signature = (chamber, committee_code, format=None))]
+                pub fn get_committee<'py>(
+                    &self,
+                    py: Python<'py>,
+                    chamber: EnumArg<Chamber>,
+                    committee_code: String,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, CommitteeDetailInfo>> {
+                    let mut params = HashMap::new();
+                    
+                    if let Some(f) = format {
+                        params.insert("format".to_string(), f);
+                    }
+                    
+                    let endpoint = format!("/committee/{}/{}", chamber, committee_code);
+                    self.fetch(py, &endpoint, Some(params), |response: CommitteeDetailResponse| response.committee)
+                }
+
+                /// Get detailed information about a committee as it stood in a given congress
+                #[pyo3(signature = (congress, chamber, committee_code, format=None))]
+                pub fn get_committee_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                    committee_code: String,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, CommitteeDetailInfo>> {
+                    let mut params = HashMap::new();
+                    
+                    if let Some(f) = format {
+                        params.insert("format".to_string(), f);
+                    }
+                    
+                    let endpoint = format!("/committee/{}/{}/{}", congress, chamber, committee_code);
+                    self.fetch(py, &endpoint, Some(params), |response: CommitteeDetailResponse| response.committee)
+                }
+
+                /// Get bills associated with a committee
+                #[pyo3(signature = (chamber, committee_code, offset=None, limit=None, format=None))]
+                pub fn get_committee_bills<'py>(
+                    &self,
+                    py: Python<'py>,
+                    chamber: EnumArg<Chamber>,
+                    committee_code: String,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<CommitteeBill>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/committee/{}/{}/bills", chamber, committee_code);
+                    self.fetch(py, &endpoint, Some(params), |response: CommitteeBillsResponse| response.bills)
+                }
+
+                /// Get committee reports associated with a committee
+                #[pyo3(signature = (chamber, committee_code, offset=None, limit=None, format=None))]
+                pub fn get_committee_reports<'py>(
+                    &self,
+                    py: Python<'py>,
+                    chamber: EnumArg<Chamber>,
+                    committee_code: String,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<CommitteeReportItem>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/committee/{}/{}/reports", chamber, committee_code);
+                    self.fetch(py, &endpoint, Some(params), |response: CommitteeReportsResponse| response.reports)
+                }
+
+                /// Get nominations referred to a committee
+                #[pyo3(signature = (chamber, committee_code, offset=None, limit=None, format=None))]
+                pub fn get_committee_nominations<'py>(
+                    &self,
+                    py: Python<'py>,
+                    chamber: EnumArg<Chamber>,
+                    committee_code: String,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<Nomination>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/committee/{}/{}/nominations", chamber, committee_code);
+                    self.fetch(py, &endpoint, Some(params), |response: NominationsResponse| response.nominations)
+                }
+
+                /// Get House communications referred to a House committee
+                #[pyo3(signature = (committee_code, offset=None, limit=None, format=None))]
+                pub fn get_committee_house_communications<'py>(
+                    &self,
+                    py: Python<'py>,
+                    committee_code: String,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<Communication>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/committee/house/{}/house-communication", committee_code);
+                    self.fetch(py, &endpoint, Some(params), |response: CommunicationsResponse| response.communications)
+                }
+
+                /// Get Senate communications referred to a Senate committee
+                #[pyo3(signature = (committee_code, offset=None, limit=None, format=None))]
+                pub fn get_committee_senate_communications<'py>(
+                    &self,
+                    py: Python<'py>,
+                    committee_code: String,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<Communication>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/committee/senate/{}/senate-communication", committee_code);
+                    self.fetch(py, &endpoint, Some(params), |response: CommunicationsResponse| response.communications)
+                }
+
+                // ========================================
+                // Committee Report Operations
+                // ========================================
+
+                /// Get a list of all committee reports
+                #[pyo3(signature = (offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
+                pub fn list_committee_reports<'py>(
+                    &self,
+                    py: Python<'py>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<CommitteeReportItem>>> {
+                    let params = sorted_params(format, offset, limit, from_date, to_date, sort)?;
+                    
+                    self.fetch(py, "/committee-report", Some(params), |response: CommitteeReportsResponse| response.reports)
+                }
+
+                /// Iterate over all committee reports, fetching pages lazily
+                #[pyo3(signature = (offset=None, from_date=None, to_date=None, sort=None, page_size=None, max_items=None))]
+                pub fn iter_committee_reports(
+                    &self,
+                    offset: Option<u64>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = sorted_params(None, None, None, from_date, to_date, sort)?;
+                    
+                    self.iter::<CommitteeReportsResponse>("/committee-report", params, offset, page_size, max_items)
+                }
+
+                /// Get a list of all committee reports, returning a `Page` with pagination metadata
+                #[pyo3(signature = (offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
+                pub fn list_committee_reports_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = sorted_params(format, offset, limit, from_date, to_date, sort)?;
+                    
+                    self.page::<CommitteeReportsResponse>(py, "/committee-report", params)
+                }
+
+                /// Count committee reports, using a single `limit=1` request
+                #[pyo3(signature = (from_date=None, to_date=None, sort=None))]
+                pub fn count_committee_reports<'py>(
+                    &self,
+                    py: Python<'py>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = sorted_params(None, None, None, from_date, to_date, sort)?;
+                    
+                    self.count::<CommitteeReportsResponse>(py, "/committee-report", params)
+                }
+
+                /// Get committee reports filtered by congress
+                #[pyo3(signature = (congress, offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
+                pub fn list_committee_reports_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<CommitteeReportItem>>> {
+                    let params = sorted_params(format, offset, limit, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/committee-report/{}", congress);
+                    self.fetch(py, &endpoint, Some(params), |response: CommitteeReportsResponse| response.reports)
+                }
+
+                /// Iterate over all committee reports filtered by congress, fetching pages lazily
+                #[pyo3(signature = (congress, offset=None, from_date=None, to_date=None, sort=None, page_size=None, max_items=None))]
+                pub fn iter_committee_reports_by_congress(
+                    &self,
+                    congress: i32,
+                    offset: Option<u64>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = sorted_params(None, None, None, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/committee-report/{}", congress);
+                    self.iter::<CommitteeReportsResponse>(&endpoint, params, offset, page_size, max_items)
+                }
+
+                /// Get committee reports filtered by congress, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
+                pub fn list_committee_reports_by_congress_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = sorted_params(format, offset, limit, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/committee-report/{}", congress);
+                    self.page::<CommitteeReportsResponse>(py, &endpoint, params)
+                }
+
+                /// Count committee reports filtered by congress, using a single `limit=1` request
+                #[pyo3(signature = (congress, from_date=None, to_date=None, sort=None))]
+                pub fn count_committee_reports_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = sorted_params(None, None, None, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/committee-report/{}", congress);
+                    self.count::<CommitteeReportsResponse>(py, &endpoint, params)
+                }
+
+                /// Get committee reports filtered by congress and report type
+                #[pyo3(signature = (congress, report_type, offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
+                pub fn list_committee_reports_by_type<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    report_type: EnumArg<ReportType>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<CommitteeReportItem>>> {
+                    let params = sorted_params(format, offset, limit, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/committee-report/{}/{}", congress, report_type);
+                    self.fetch(py, &endpoint, Some(params), |response: CommitteeReportsResponse| response.reports)
+                }
+
+                /// Iterate over all committee reports filtered by congress and report type, fetching pages lazily
+                #[pyo3(signature = (congress, report_type, offset=None, from_date=None, to_date=None, sort=None, page_size=None, max_items=None))]
+                pub fn iter_committee_reports_by_type(
+                    &self,
+                    congress: i32,
+                    report_type: EnumArg<ReportType>,
+                    offset: Option<u64>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = sorted_params(None, None, None, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/committee-report/{}/{}", congress, report_type);
+                    self.iter::<CommitteeReportsResponse>(&endpoint, params, offset, page_size, max_items)
+                }
+
+                /// Get committee reports filtered by congress and report type, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, report_type, offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
+                pub fn list_committee_reports_by_type_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    report_type: EnumArg<ReportType>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = sorted_params(format, offset, limit, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/committee-report/{}/{}", congress, report_type);
+                    self.page::<CommitteeReportsResponse>(py, &endpoint, params)
+                }
+
+                /// Count committee reports filtered by congress and report type, using a single `limit=1` request
+                #[pyo3(signature = (congress, report_type, from_date=None, to_date=None, sort=None))]
+                pub fn count_committee_reports_by_type<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    report_type: EnumArg<ReportType>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = sorted_params(None, None, None, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/committee-report/{}/{}", congress, report_type);
+                    self.count::<CommitteeReportsResponse>(py, &endpoint, params)
+                }
+
+                /// Get detailed information about a specific committee report
+                #[pyo3(signature = (congress=None, report_type=None, report_number=None, format=None, *, id=None))]
+                pub fn get_committee_report<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: Option<i32>,
+                    report_type: Option<EnumArg<ReportType>>,
+                    report_number: Option<i32>,
+                    format: Option<String>,
+                    id: Option<IdArg<ReportId>>,
+                ) -> PyResult<Output<'py, Self, CommitteeReportDetail>> {
+                    let (congress, report_type, report_number) = IdArg::resolve(id, congress, report_type, report_number)?;
+                    let mut params = HashMap::new();
+                    
+                    if let Some(f) = format {
+                        params.insert("format".to_string(), f);
+                    }
+                    
+                    let endpoint = format!("/committee-report/{}/{}/{}", congress, report_type, report_number);
+                    self.fetch(py, &endpoint, Some(params), |response: CommitteeReportDetailResponse| response.report)
+                }
+
+                /// Get text formats available for a committee report
+                #[pyo3(signature = (congress=None, report_type=None, report_number=None, format=None, *, id=None))]
+                pub fn get_committee_report_text<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: Option<i32>,
+                    report_type: Option<EnumArg<ReportType>>,
+                    report_number: Option<i32>,
+                    format: Option<String>,
+                    id: Option<IdArg<ReportId>>,
+                ) -> PyResult<Output<'py, Self, Vec<CommitteeReportText>>> {
+                    let (congress, report_type, report_number) = IdArg::resolve(id, congress, report_type, report_number)?;
+                    let mut params = HashMap::new();
+                    
+                    if let Some(f) = format {
+                        params.insert("format".to_string(), f);
+                    }
+                    
+                    let endpoint = format!("/committee-report/{}/{}/{}/text", congress, report_type, report_number);
+                    self.fetch(py, &endpoint, Some(params), |response: CommitteeReportTextResponse| response.text)
+                }
+
+                // ========================================
+                // Committee Print Operations
+                // ========================================
+
+                /// Get a list of all committee prints
+                #[pyo3(signature = (offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
+                pub fn list_committee_prints<'py>(
+                    &self,
+                    py: Python<'py>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<CommitteePrintItem>>> {
+                    let params = sorted_params(format, offset, limit, from_date, to_date, sort)?;
+                    
+                    self.fetch(py, "/committee-print", Some(params), |response: CommitteePrintsResponse| response.committee_prints)
+                }
+
+                /// Iterate over all committee prints, fetching pages lazily
+                #[pyo3(signature = (offset=None, from_date=None, to_date=None, sort=None, page_size=None, max_items=None))]
+                pub fn iter_committee_prints(
+                    &self,
+                    offset: Option<u64>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = sorted_params(None, None, None, from_date, to_date, sort)?;
+                    
+                    self.iter::<CommitteePrintsResponse>("/committee-print", params, offset, page_size, max_items)
+                }
+
+                /// Get a list of all committee prints, returning a `Page` with pagination metadata
+                #[pyo3(signature = (offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
+                pub fn list_committee_prints_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = sorted_params(format, offset, limit, from_date, to_date, sort)?;
+                    
+                    self.page::<CommitteePrintsResponse>(py, "/committee-print", params)
+                }
+
+                /// Count committee prints, using a single `limit=1` request
+                #[pyo3(signature = (from_date=None, to_date=None, sort=None))]
+                pub fn count_committee_prints<'py>(
+                    &self,
+                    py: Python<'py>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = sorted_params(None, None, None, from_date, to_date, sort)?;
+                    
+                    self.count::<CommitteePrintsResponse>(py, "/committee-print", params)
+                }
+
+                /// Get committee prints filtered by congress
+                #[pyo3(signature = (congress, offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
+                pub fn list_committee_prints_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<CommitteePrintItem>>> {
+                    let params = sorted_params(format, offset, limit, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/committee-print/{}", congress);
+                    self.fetch(py, &endpoint, Some(params), |response: CommitteePrintsResponse| response.committee_prints)
+                }
+
+                /// Iterate over all committee prints filtered by congress, fetching pages lazily
+                #[pyo3(signature = (congress, offset=None, from_date=None, to_date=None, sort=None, page_size=None, max_items=None))]
+                pub fn iter_committee_prints_by_congress(
+                    &self,
+                    congress: i32,
+                    offset: Option<u64>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = sorted_params(None, None, None, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/committee-print/{}", congress);
+                    self.iter::<CommitteePrintsResponse>(&endpoint, params, offset, page_size, max_items)
+                }
+
+                /// Get committee prints filtered by congress, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
+                pub fn list_committee_prints_by_congress_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = sorted_params(format, offset, limit, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/committee-print/{}", congress);
+                    self.page::<CommitteePrintsResponse>(py, &endpoint, params)
+                }
+
+                /// Count committee prints filtered by congress, using a single `limit=1` request
+                #[pyo3(signature = (congress, from_date=None, to_date=None, sort=None))]
+                pub fn count_committee_prints_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = sorted_params(None, None, None, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/committee-print/{}", congress);
+                    self.count::<CommitteePrintsResponse>(py, &endpoint, params)
+                }
+
+                /// Get committee prints filtered by congress and chamber
+                #[pyo3(signature = (congress, chamber, offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
+                pub fn list_committee_prints_by_chamber<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<CommitteePrintItem>>> {
+                    let params = sorted_params(format, offset, limit, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/committee-print/{}/{}", congress, chamber);
+                    self.fetch(py, &endpoint, Some(params), |response: CommitteePrintsResponse| response.committee_prints)
+                }
+
+                /// Iterate over all committee prints filtered by congress and chamber, fetching pages lazily
+                #[pyo3(signature = (congress, chamber, offset=None, from_date=None, to_date=None, sort=None, page_size=None, max_items=None))]
+                pub fn iter_committee_prints_by_chamber(
+                    &self,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                    offset: Option<u64>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = sorted_params(None, None, None, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/committee-print/{}/{}", congress, chamber);
+                    self.iter::<CommitteePrintsResponse>(&endpoint, params, offset, page_size, max_items)
+                }
+
+                /// Get committee prints filtered by congress and chamber, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, chamber, offset=None, limit=None, from_date=None, to_date=None, sort=None, format=None))]
+                pub fn list_committee_prints_by_chamber_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = sorted_params(format, offset, limit, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/committee-print/{}/{}", congress, chamber);
+                    self.page::<CommitteePrintsResponse>(py, &endpoint, params)
+                }
+
+                /// Count committee prints filtered by congress and chamber, using a single `limit=1` request
+                #[pyo3(signature = (congress, chamber, from_date=None, to_date=None, sort=None))]
+                pub fn count_committee_prints_by_chamber<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                    from_date: Option<DateFilter>,
+                    to_date: Option<DateFilter>,
+                    sort: Option<String>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = sorted_params(None, None, None, from_date, to_date, sort)?;
+                    
+                    let endpoint = format!("/committee-print/{}/{}", congress, chamber);
+                    self.count::<CommitteePrintsResponse>(py, &endpoint, params)
+                }
+
+                /// Get detailed information about a specific committee print
+                #[pyo3(signature = (congress, chamber, jacket_number, format=None))]
+                pub fn get_committee_print<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                    jacket_number: i32,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, CommitteePrintDetail>> {
+                    let mut params = HashMap::new();
+                    
+                    if let Some(f) = format {
+                        params.insert("format".to_string(), f);
+                    }
+                    
+                    let endpoint = format!("/committee-print/{}/{}/{}", congress, chamber, jacket_number);
+                    self.fetch(py, &endpoint, Some(params), |response: CommitteePrintDetailResponse| response.committee_print)
+                }
+
+                /// Get text formats available for a committee print
+                #[pyo3(signature = (congress, chamber, jacket_number, format=None))]
+                pub fn get_committee_print_text<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                    jacket_number: i32,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<CommitteePrintText>>> {
+                    let mut params = HashMap::new();
+                    
+                    if let Some(f) = format {
+                        params.insert("format".to_string(), f);
+                    }
+                    
+                    let endpoint = format!("/committee-print/{}/{}/{}/text", congress, chamber, jacket_number);
+                    self.fetch(py, &endpoint, Some(params), |response: CommitteePrintTextResponse| response.text)
+                }
+
+                // ========================================
+                // Nomination Operations
+                // ========================================
+
+                /// Get a list of all nominations
+                #[pyo3(signature = (offset=None, limit=None, sort=None, format=None))]
+                pub fn list_nominations<'py>(
+                    &self,
+                    py: Python<'py>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<Nomination>>> {
+                    let params = sorted_params(format, offset, limit, None, None, sort)?;
+                    
+                    self.fetch(py, "/nomination", Some(params), |response: NominationsResponse| response.nominations)
+                }
+
+                /// Iterate over all nominations, fetching pages lazily
+                #[pyo3(signature = (offset=None, sort=None, page_size=None, max_items=None))]
+                pub fn iter_nominations(
+                    &self,
+                    offset: Option<u64>,
+                    sort: Option<String>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = sorted_params(None, None, None, None, None, sort)?;
+                    
+                    self.iter::<NominationsResponse>("/nomination", params, offset, page_size, max_items)
+                }
+
+                /// Get a list of all nominations, returning a `Page` with pagination metadata
+                #[pyo3(signature = (offset=None, limit=None, sort=None, format=None))]
+                pub fn list_nominations_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = sorted_params(format, offset, limit, None, None, sort)?;
+                    
+                    self.page::<NominationsResponse>(py, "/nomination", params)
+                }
+
+                /// Count nominations, using a single `limit=1` request
+                #[pyo3(signature = (sort=None))]
+                pub fn count_nominations<'py>(
+                    &self,
+                    py: Python<'py>,
+                    sort: Option<String>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = sorted_params(None, None, None, None, None, sort)?;
+                    
+                    self.count::<NominationsResponse>(py, "/nomination", params)
+                }
+
+                /// Get nominations by congress
+                #[pyo3(signature = (congress, offset=None, limit=None, sort=None, format=None))]
+                pub fn list_nominations_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<Nomination>>> {
+                    let params = sorted_params(format, offset, limit, None, None, sort)?;
+                    
+                    let endpoint = format!("/nomination/{}", congress);
+                    self.fetch(py, &endpoint, Some(params), |response: NominationsResponse| response.nominations)
+                }
+
+                /// Iterate over all nominations by congress, fetching pages lazily
+                #[pyo3(signature = (congress, offset=None, sort=None, page_size=None, max_items=None))]
+                pub fn iter_nominations_by_congress(
+                    &self,
+                    congress: i32,
+                    offset: Option<u64>,
+                    sort: Option<String>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = sorted_params(None, None, None, None, None, sort)?;
+                    
+                    let endpoint = format!("/nomination/{}", congress);
+                    self.iter::<NominationsResponse>(&endpoint, params, offset, page_size, max_items)
+                }
+
+                /// Get nominations by congress, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, offset=None, limit=None, sort=None, format=None))]
+                pub fn list_nominations_by_congress_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = sorted_params(format, offset, limit, None, None, sort)?;
+                    
+                    let endpoint = format!("/nomination/{}", congress);
+                    self.page::<NominationsResponse>(py, &endpoint, params)
+                }
+
+                /// Count nominations by congress, using a single `limit=1` request
+                #[pyo3(signature = (congress, sort=None))]
+                pub fn count_nominations_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    sort: Option<String>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = sorted_params(None, None, None, None, None, sort)?;
+                    
+                    let endpoint = format!("/nomination/{}", congress);
+                    self.count::<NominationsResponse>(py, &endpoint, params)
+                }
+
+                /// Get a specific nomination
+                #[pyo3(signature = (congress, nomination_number, format=None))]
+                pub fn get_nomination<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    nomination_number: String,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Nomination>> {
+                    let mut params = HashMap::new();
+                    
+                    if let Some(f) = format {
+                        params.insert("format".to_string(), f);
+                    }
+                    
+                    let endpoint = format!("/nomination/{}/{}", congress, nomination_number);
+                    self.fetch(py, &endpoint, Some(params), |response: NominationDetailResponse| response.nomination)
+                }
+
+                /// Get nominees for a nomination
+                #[pyo3(signature = (congress, nomination_number, offset=None, limit=None, format=None))]
+                pub fn get_nomination_nominees<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    nomination_number: String,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<Nominee>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/nomination/{}/{}/nominees", congress, nomination_number);
+                    self.fetch(py, &endpoint, Some(params), |response: NomineesResponse| response.nominees)
+                }
+
+                // ========================================
+                // Treaty Operations
+                // ========================================
+
+                /// Get a list of all treaties
+                #[pyo3(signature = (offset=None, limit=None, sort=None, format=None))]
+                pub fn list_treaties<'py>(
+                    &self,
+                    py: Python<'py>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<Treaty>>> {
+                    let params = sorted_params(format, offset, limit, None, None, sort)?;
+                    
+                    self.fetch(py, "/treaty", Some(params), |response: TreatiesResponse| response.treaties)
+                }
+
+                /// Iterate over all treaties, fetching pages lazily
+                #[pyo3(signature = (offset=None, sort=None, page_size=None, max_items=None))]
+                pub fn iter_treaties(
+                    &self,
+                    offset: Option<u64>,
+                    sort: Option<String>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = sorted_params(None, None, None, None, None, sort)?;
+                    
+                    self.iter::<TreatiesResponse>("/treaty", params, offset, page_size, max_items)
+                }
+
+                /// Get a list of all treaties, returning a `Page` with pagination metadata
+                #[pyo3(signature = (offset=None, limit=None, sort=None, format=None))]
+                pub fn list_treaties_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = sorted_params(format, offset, limit, None, None, sort)?;
+                    
+                    self.page::<TreatiesResponse>(py, "/treaty", params)
+                }
+
+                /// Count treaties, using a single `limit=1` request
+                #[pyo3(signature = (sort=None))]
+                pub fn count_treaties<'py>(
+                    &self,
+                    py: Python<'py>,
+                    sort: Option<String>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = sorted_params(None, None, None, None, None, sort)?;
+                    
+                    self.count::<TreatiesResponse>(py, "/treaty", params)
+                }
+
+                /// Get treaties by congress
+                #[pyo3(signature = (congress, offset=None, limit=None, sort=None, format=None))]
+                pub fn list_treaties_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<Treaty>>> {
+                    let params = sorted_params(format, offset, limit, None, None, sort)?;
+                    
+                    let endpoint = format!("/treaty/{}", congress);
+                    self.fetch(py, &endpoint, Some(params), |response: TreatiesResponse| response.treaties)
+                }
+
+                /// Iterate over all treaties by congress, fetching pages lazily
+                #[pyo3(signature = (congress, offset=None, sort=None, page_size=None, max_items=None))]
+                pub fn iter_treaties_by_congress(
+                    &self,
+                    congress: i32,
+                    offset: Option<u64>,
+                    sort: Option<String>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = sorted_params(None, None, None, None, None, sort)?;
+                    
+                    let endpoint = format!("/treaty/{}", congress);
+                    self.iter::<TreatiesResponse>(&endpoint, params, offset, page_size, max_items)
+                }
+
+                /// Get treaties by congress, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, offset=None, limit=None, sort=None, format=None))]
+                pub fn list_treaties_by_congress_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = sorted_params(format, offset, limit, None, None, sort)?;
+                    
+                    let endpoint = format!("/treaty/{}", congress);
+                    self.page::<TreatiesResponse>(py, &endpoint, params)
+                }
+
+                /// Count treaties by congress, using a single `limit=1` request
+                #[pyo3(signature = (congress, sort=None))]
+                pub fn count_treaties_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    sort: Option<String>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = sorted_params(None, None, None, None, None, sort)?;
+                    
+                    let endpoint = format!("/treaty/{}", congress);
+                    self.count::<TreatiesResponse>(py, &endpoint, params)
+                }
+
+                /// Get a specific treaty
+                #[pyo3(signature = (congress, treaty_number, format=None))]
+                pub fn get_treaty<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    treaty_number: String,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Treaty>> {
+                    let mut params = HashMap::new();
+                    
+                    if let Some(f) = format {
+                        params.insert("format".to_string(), f);
+                    }
+                    
+                    let endpoint = format!("/treaty/{}/{}", congress, treaty_number);
+                    self.fetch(py, &endpoint, Some(params), |response: TreatyDetailResponse| response.treaty)
+                }
+
+                // ========================================
+                // Hearing Operations
+                // ========================================
+
+                /// Get a list of all hearings
+                #[pyo3(signature = (offset=None, limit=None, sort=None, format=None))]
+                pub fn list_hearings<'py>(
+                    &self,
+                    py: Python<'py>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<Hearing>>> {
+                    let params = sorted_params(format, offset, limit, None, None, sort)?;
+                    
+                    self.fetch(py, "/hearing", Some(params), |response: HearingsResponse| response.hearings)
+                }
+
+                /// Iterate over all hearings, fetching pages lazily
+                #[pyo3(signature = (offset=None, sort=None, page_size=None, max_items=None))]
+                pub fn iter_hearings(
+                    &self,
+                    offset: Option<u64>,
+                    sort: Option<String>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = sorted_params(None, None, None, None, None, sort)?;
+                    
+                    self.iter::<HearingsResponse>("/hearing", params, offset, page_size, max_items)
+                }
+
+                /// Get a list of all hearings, returning a `Page` with pagination metadata
+                #[pyo3(signature = (offset=None, limit=None, sort=None, format=None))]
+                pub fn list_hearings_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = sorted_params(format, offset, limit, None, None, sort)?;
+                    
+                    self.page::<HearingsResponse>(py, "/hearing", params)
+                }
+
+                /// Count hearings, using a single `limit=1` request
+                #[pyo3(signature = (sort=None))]
+                pub fn count_hearings<'py>(
+                    &self,
+                    py: Python<'py>,
+                    sort: Option<String>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = sorted_params(None, None, None, None, None, sort)?;
+                    
+                    self.count::<HearingsResponse>(py, "/hearing", params)
+                }
+
+                /// Get hearings by congress
+                #[pyo3(signature = (congress, offset=None, limit=None, sort=None, format=None))]
+                pub fn list_hearings_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<Hearing>>> {
+                    let params = sorted_params(format, offset, limit, None, None, sort)?;
+                    
+                    let endpoint = format!("/hearing/{}", congress);
+                    self.fetch(py, &endpoint, Some(params), |response: HearingsResponse| response.hearings)
+                }
+
+                /// Iterate over all hearings by congress, fetching pages lazily
+                #[pyo3(signature = (congress, offset=None, sort=None, page_size=None, max_items=None))]
+                pub fn iter_hearings_by_congress(
+                    &self,
+                    congress: i32,
+                    offset: Option<u64>,
+                    sort: Option<String>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = sorted_params(None, None, None, None, None, sort)?;
+                    
+                    let endpoint = format!("/hearing/{}", congress);
+                    self.iter::<HearingsResponse>(&endpoint, params, offset, page_size, max_items)
+                }
+
+                /// Get hearings by congress, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, offset=None, limit=None, sort=None, format=None))]
+                pub fn list_hearings_by_congress_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = sorted_params(format, offset, limit, None, None, sort)?;
+                    
+                    let endpoint = format!("/hearing/{}", congress);
+                    self.page::<HearingsResponse>(py, &endpoint, params)
+                }
+
+                /// Count hearings by congress, using a single `limit=1` request
+                #[pyo3(signature = (congress, sort=None))]
+                pub fn count_hearings_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    sort: Option<String>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = sorted_params(None, None, None, None, None, sort)?;
+                    
+                    let endpoint = format!("/hearing/{}", congress);
+                    self.count::<HearingsResponse>(py, &endpoint, params)
+                }
+
+                /// Get hearings by congress and chamber
+                #[pyo3(signature = (congress, chamber, offset=None, limit=None, sort=None, format=None))]
+                pub fn list_hearings_by_chamber<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<Hearing>>> {
+                    let params = sorted_params(format, offset, limit, None, None, sort)?;
+                    
+                    let endpoint = format!("/hearing/{}/{}", congress, chamber);
+                    self.fetch(py, &endpoint, Some(params), |response: HearingsResponse| response.hearings)
+                }
+
+                /// Iterate over all hearings by congress and chamber, fetching pages lazily
+                #[pyo3(signature = (congress, chamber, offset=None, sort=None, page_size=None, max_items=None))]
+                pub fn iter_hearings_by_chamber(
+                    &self,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                    offset: Option<u64>,
+                    sort: Option<String>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = sorted_params(None, None, None, None, None, sort)?;
+                    
+                    let endpoint = format!("/hearing/{}/{}", congress, chamber);
+                    self.iter::<HearingsResponse>(&endpoint, params, offset, page_size, max_items)
+                }
+
+                /// Get hearings by congress and chamber, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, chamber, offset=None, limit=None, sort=None, format=None))]
+                pub fn list_hearings_by_chamber_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    sort: Option<String>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = sorted_params(format, offset, limit, None, None, sort)?;
+                    
+                    let endpoint = format!("/hearing/{}/{}", congress, chamber);
+                    self.page::<HearingsResponse>(py, &endpoint, params)
+                }
+
+                /// Count hearings by congress and chamber, using a single `limit=1` request
+                #[pyo3(signature = (congress, chamber, sort=None))]
+                pub fn count_hearings_by_chamber<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                    sort: Option<String>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = sorted_params(None, None, None, None, None, sort)?;
+                    
+                    let endpoint = format!("/hearing/{}/{}", congress, chamber);
+                    self.count::<HearingsResponse>(py, &endpoint, params)
+                }
+
+                /// Get a specific hearing
+                #[pyo3(signature = (congress, chamber, jacket_number, format=None))]
+                pub fn get_hearing<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                    jacket_number: i32,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Hearing>> {
+                    let mut params = HashMap::new();
+                    
+                    if let Some(f) = format {
+                        params.insert("format".to_string(), f);
+                    }
+                    
+                    let endpoint = format!("/hearing/{}/{}/{}", congress, chamber, jacket_number);
+                    self.fetch(py, &endpoint, Some(params), |response: HearingDetailResponse| response.hearing)
+                }
+
+                // ========================================
+                // Committee Meeting Operations
+                // ========================================
+
+                /// Get a list of committee meetings
+                #[pyo3(signature = (format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
+                pub fn list_committee_meetings<'py>(
+                    &self,
+                    py: Python<'py>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, Vec<CommitteeMeeting>>> {
+                    let params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+                    
+                    self.fetch(py, "/committee-meeting", Some(params), |response: CommitteeMeetingsResponse| response.committee_meetings)
+                }
+
+                /// Iterate over all committee meetings, fetching pages lazily
+                #[pyo3(signature = (offset=None, from_date_time=None, to_date_time=None, page_size=None, max_items=None))]
+                pub fn iter_committee_meetings(
+                    &self,
+                    offset: Option<u64>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = dated_params(None, None, None, from_date_time, to_date_time)?;
+                    
+                    self.iter::<CommitteeMeetingsResponse>("/committee-meeting", params, offset, page_size, max_items)
+                }
+
+                /// Get a list of committee meetings, returning a `Page` with pagination metadata
+                #[pyo3(signature = (format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
+                pub fn list_committee_meetings_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+                    
+                    self.page::<CommitteeMeetingsResponse>(py, "/committee-meeting", params)
+                }
+
+                /// Count committee meetings, using a single `limit=1` request
+                #[pyo3(signature = (from_date_time=None, to_date_time=None))]
+                pub fn count_committee_meetings<'py>(
+                    &self,
+                    py: Python<'py>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = dated_params(None, None, None, from_date_time, to_date_time)?;
+                    
+                    self.count::<CommitteeMeetingsResponse>(py, "/committee-meeting", params)
+                }
+
+                /// Get committee meetings filtered by congress
+                #[pyo3(signature = (congress, format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
+                pub fn list_committee_meetings_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, Vec<CommitteeMeeting>>> {
+                    let params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/committee-meeting/{}", congress);
+                    self.fetch(py, &endpoint, Some(params), |response: CommitteeMeetingsResponse| response.committee_meetings)
+                }
+
+                /// Iterate over all committee meetings filtered by congress, fetching pages lazily
+                #[pyo3(signature = (congress, offset=None, from_date_time=None, to_date_time=None, page_size=None, max_items=None))]
+                pub fn iter_committee_meetings_by_congress(
+                    &self,
+                    congress: i32,
+                    offset: Option<u64>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = dated_params(None, None, None, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/committee-meeting/{}", congress);
+                    self.iter::<CommitteeMeetingsResponse>(&endpoint, params, offset, page_size, max_items)
+                }
+
+                /// Get committee meetings filtered by congress, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
+                pub fn list_committee_meetings_by_congress_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/committee-meeting/{}", congress);
+                    self.page::<CommitteeMeetingsResponse>(py, &endpoint, params)
+                }
+
+                /// Count committee meetings filtered by congress, using a single `limit=1` request
+                #[pyo3(signature = (congress, from_date_time=None, to_date_time=None))]
+                pub fn count_committee_meetings_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = dated_params(None, None, None, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/committee-meeting/{}", congress);
+                    self.count::<CommitteeMeetingsResponse>(py, &endpoint, params)
+                }
+
+                /// Get committee meetings filtered by congress and chamber
+                #[pyo3(signature = (congress, chamber, format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
+                pub fn list_committee_meetings_by_chamber<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, Vec<CommitteeMeeting>>> {
+                    let params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/committee-meeting/{}/{}", congress, chamber);
+                    self.fetch(py, &endpoint, Some(params), |response: CommitteeMeetingsResponse| response.committee_meetings)
+                }
+
+                /// Iterate over all committee meetings filtered by congress and chamber, fetching pages lazily
+                #[pyo3(signature = (congress, chamber, offset=None, from_date_time=None, to_date_time=None, page_size=None, max_items=None))]
+                pub fn iter_committee_meetings_by_chamber(
+                    &self,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                    offset: Option<u64>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = dated_params(None, None, None, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/committee-meeting/{}/{}", congress, chamber);
+                    self.iter::<CommitteeMeetingsResponse>(&endpoint, params, offset, page_size, max_items)
+                }
+
+                /// Get committee meetings filtered by congress and chamber, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, chamber, format=None, offset=None, limit=None, from_date_time=None, to_date_time=None))]
+                pub fn list_committee_meetings_by_chamber_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/committee-meeting/{}/{}", congress, chamber);
+                    self.page::<CommitteeMeetingsResponse>(py, &endpoint, params)
+                }
+
+                /// Count committee meetings filtered by congress and chamber, using a single `limit=1` request
+                #[pyo3(signature = (congress, chamber, from_date_time=None, to_date_time=None))]
+                pub fn count_committee_meetings_by_chamber<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = dated_params(None, None, None, from_date_time, to_date_time)?;
+                    
+                    let endpoint = format!("/committee-meeting/{}/{}", congress, chamber);
+                    self.count::<CommitteeMeetingsResponse>(py, &endpoint, params)
+                }
+
+                /// Get detailed information for a specified committee meeting
+                #[pyo3(signature = (congress, chamber, event_id, format=None))]
+                pub fn get_committee_meeting<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    chamber: EnumArg<Chamber>,
+                    event_id: EventId,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, CommitteeMeetingDetail>> {
+                    let mut params = HashMap::new();
+                    
+                    if let Some(f) = format {
+                        params.insert("format".to_string(), f);
+                    }
+                    
+                    let endpoint = format!("/committee-meeting/{}/{}/{}", congress, chamber, event_id);
+                    self.fetch(py, &endpoint, Some(params), |response: CommitteeMeetingDetailResponse| response.committee_meeting)
+                }
+
+                // ========================================
+                // House Communication Operations
+                // ========================================
+
+                /// Get a list of House communications
+                #[pyo3(signature = (format=None, offset=None, limit=None))]
+                pub fn list_house_communications<'py>(
+                    &self,
+                    py: Python<'py>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                ) -> PyResult<Output<'py, Self, Vec<Communication>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    self.fetch(py, "/house-communication", Some(params), |response: CommunicationsResponse| response.communications)
+                }
+
+                /// Iterate over all House communications, fetching pages lazily
+                #[pyo3(signature = (offset=None, page_size=None, max_items=None))]
+                pub fn iter_house_communications(
+                    &self,
+                    offset: Option<u64>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    self.iter::<CommunicationsResponse>("/house-communication", HashMap::new(), offset, page_size, max_items)
+                }
+
+                /// Get a list of House communications, returning a `Page` with pagination metadata
+                #[pyo3(signature = (format=None, offset=None, limit=None))]
+                pub fn list_house_communications_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    self.page::<CommunicationsResponse>(py, "/house-communication", params)
+                }
+
+                /// Count House communications, using a single `limit=1` request
+                pub fn count_house_communications<'py>(&self, py: Python<'py>) -> PyResult<Output<'py, Self, u64>> {
+                    self.count::<CommunicationsResponse>(py, "/house-communication", HashMap::new())
+                }
+
+                /// Get House communications filtered by congress
+                #[pyo3(signature = (congress, format=None, offset=None, limit=None))]
+                pub fn list_house_communications_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                ) -> PyResult<Output<'py, Self, Vec<Communication>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/house-communication/{}", congress);
+                    self.fetch(py, &endpoint, Some(params), |response: CommunicationsResponse| response.communications)
+                }
+
+                /// Iterate over all House communications filtered by congress, fetching pages lazily
+                #[pyo3(signature = (congress, offset=None, page_size=None, max_items=None))]
+                pub fn iter_house_communications_by_congress(
+                    &self,
+                    congress: i32,
+                    offset: Option<u64>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let endpoint = format!("/house-communication/{}", congress);
+                    self.iter::<CommunicationsResponse>(&endpoint, HashMap::new(), offset, page_size, max_items)
+                }
+
+                /// Get House communications filtered by congress, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, format=None, offset=None, limit=None))]
+                pub fn list_house_communications_by_congress_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/house-communication/{}", congress);
+                    self.page::<CommunicationsResponse>(py, &endpoint, params)
+                }
+
+                /// Count House communications filtered by congress, using a single `limit=1` request
+                #[pyo3(signature = (congress))]
+                pub fn count_house_communications_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let endpoint = format!("/house-communication/{}", congress);
+                    self.count::<CommunicationsResponse>(py, &endpoint, HashMap::new())
+                }
+
+                /// Get House communications filtered by congress and communication type
+                #[pyo3(signature = (congress, communication_type, format=None, offset=None, limit=None))]
+                pub fn list_house_communications_by_type<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    communication_type: HouseCommunicationType,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                ) -> PyResult<Output<'py, Self, Vec<Communication>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/house-communication/{}/{}", congress, communication_type);
+                    self.fetch(py, &endpoint, Some(params), |response: CommunicationsResponse| response.communications)
+                }
+
+                /// Iterate over all House communications filtered by congress and communication type, fetching pages lazily
+                #[pyo3(signature = (congress, communication_type, offset=None, page_size=None, max_items=None))]
+                pub fn iter_house_communications_by_type(
+                    &self,
+                    congress: i32,
+                    communication_type: HouseCommunicationType,
+                    offset: Option<u64>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let endpoint = format!("/house-communication/{}/{}", congress, communication_type);
+                    self.iter::<CommunicationsResponse>(&endpoint, HashMap::new(), offset, page_size, max_items)
+                }
+
+                /// Get House communications filtered by congress and communication type, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, communication_type, format=None, offset=None, limit=None))]
+                pub fn list_house_communications_by_type_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    communication_type: HouseCommunicationType,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/house-communication/{}/{}", congress, communication_type);
+                    self.page::<CommunicationsResponse>(py, &endpoint, params)
+                }
+
+                /// Count House communications filtered by congress and communication type, using a single `limit=1` request
+                #[pyo3(signature = (congress, communication_type))]
+                pub fn count_house_communications_by_type<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    communication_type: HouseCommunicationType,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let endpoint = format!("/house-communication/{}/{}", congress, communication_type);
+                    self.count::<CommunicationsResponse>(py, &endpoint, HashMap::new())
+                }
+
+                /// Get detailed information for a specified House communication
+                #[pyo3(signature = (congress, communication_type, communication_number, format=None))]
+                pub fn get_house_communication<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    communication_type: HouseCommunicationType,
+                    communication_number: i32,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, CommunicationDetail>> {
+                    let mut params = HashMap::new();
+                    
+                    if let Some(f) = format {
+                        params.insert("format".to_string(), f);
+                    }
+                    
+                    let endpoint = format!("/house-communication/{}/{}/{}", congress, communication_type, communication_number);
+                    self.fetch(py, &endpoint, Some(params), |response: CommunicationDetailResponse| response.communication)
+                }
+
+                // ========================================
+                // Senate Communication Operations
+                // ========================================
+
+                /// Get a list of Senate communications
+                #[pyo3(signature = (format=None, offset=None, limit=None))]
+                pub fn list_senate_communications<'py>(
+                    &self,
+                    py: Python<'py>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                ) -> PyResult<Output<'py, Self, Vec<Communication>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    self.fetch(py, "/senate-communication", Some(params), |response: CommunicationsResponse| response.communications)
+                }
+
+                /// Iterate over all Senate communications, fetching pages lazily
+                #[pyo3(signature = (offset=None, page_size=None, max_items=None))]
+                pub fn iter_senate_communications(
+                    &self,
+                    offset: Option<u64>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    self.iter::<CommunicationsResponse>("/senate-communication", HashMap::new(), offset, page_size, max_items)
+                }
+
+                /// Get a list of Senate communications, returning a `Page` with pagination metadata
+                #[pyo3(signature = (format=None, offset=None, limit=None))]
+                pub fn list_senate_communications_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    self.page::<CommunicationsResponse>(py, "/senate-communication", params)
+                }
+
+                /// Count Senate communications, using a single `limit=1` request
+                pub fn count_senate_communications<'py>(&self, py: Python<'py>) -> PyResult<Output<'py, Self, u64>> {
+                    self.count::<CommunicationsResponse>(py, "/senate-communication", HashMap::new())
+                }
+
+                /// Get Senate communications filtered by congress
+                #[pyo3(signature = (congress, format=None, offset=None, limit=None))]
+                pub fn list_senate_communications_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                ) -> PyResult<Output<'py, Self, Vec<Communication>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/senate-communication/{}", congress);
+                    self.fetch(py, &endpoint, Some(params), |response: CommunicationsResponse| response.communications)
+                }
+
+                /// Iterate over all Senate communications filtered by congress, fetching pages lazily
+                #[pyo3(signature = (congress, offset=None, page_size=None, max_items=None))]
+                pub fn iter_senate_communications_by_congress(
+                    &self,
+                    congress: i32,
+                    offset: Option<u64>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let endpoint = format!("/senate-communication/{}", congress);
+                    self.iter::<CommunicationsResponse>(&endpoint, HashMap::new(), offset, page_size, max_items)
+                }
+
+                /// Get Senate communications filtered by congress, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, format=None, offset=None, limit=None))]
+                pub fn list_senate_communications_by_congress_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/senate-communication/{}", congress);
+                    self.page::<CommunicationsResponse>(py, &endpoint, params)
+                }
+
+                /// Count Senate communications filtered by congress, using a single `limit=1` request
+                #[pyo3(signature = (congress))]
+                pub fn count_senate_communications_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let endpoint = format!("/senate-communication/{}", congress);
+                    self.count::<CommunicationsResponse>(py, &endpoint, HashMap::new())
+                }
+
+                /// Get Senate communications filtered by congress and communication type
+                #[pyo3(signature = (congress, communication_type, format=None, offset=None, limit=None))]
+                pub fn list_senate_communications_by_type<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    communication_type: SenateCommunicationType,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                ) -> PyResult<Output<'py, Self, Vec<Communication>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/senate-communication/{}/{}", congress, communication_type);
+                    self.fetch(py, &endpoint, Some(params), |response: CommunicationsResponse| response.communications)
+                }
+
+                /// Iterate over all Senate communications filtered by congress and communication type, fetching pages lazily
+                #[pyo3(signature = (congress, communication_type, offset=None, page_size=None, max_items=None))]
+                pub fn iter_senate_communications_by_type(
+                    &self,
+                    congress: i32,
+                    communication_type: SenateCommunicationType,
+                    offset: Option<u64>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let endpoint = format!("/senate-communication/{}/{}", congress, communication_type);
+                    self.iter::<CommunicationsResponse>(&endpoint, HashMap::new(), offset, page_size, max_items)
+                }
+
+                /// Get Senate communications filtered by congress and communication type, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, communication_type, format=None, offset=None, limit=None))]
+                pub fn list_senate_communications_by_type_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    communication_type: SenateCommunicationType,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/senate-communication/{}/{}", congress, communication_type);
+                    self.page::<CommunicationsResponse>(py, &endpoint, params)
+                }
+
+                /// Count Senate communications filtered by congress and communication type, using a single `limit=1` request
+                #[pyo3(signature = (congress, communication_type))]
+                pub fn count_senate_communications_by_type<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    communication_type: SenateCommunicationType,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let endpoint = format!("/senate-communication/{}/{}", congress, communication_type);
+                    self.count::<CommunicationsResponse>(py, &endpoint, HashMap::new())
+                }
+
+                /// Get detailed information for a specified Senate communication
+                #[pyo3(signature = (congress, communication_type, communication_number, format=None))]
+                pub fn get_senate_communication<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    communication_type: SenateCommunicationType,
+                    communication_number: i32,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, CommunicationDetail>> {
+                    let mut params = HashMap::new();
+                    
+                    if let Some(f) = format {
+                        params.insert("format".to_string(), f);
+                    }
+                    
+                    let endpoint = format!("/senate-communication/{}/{}/{}", congress, communication_type, communication_number);
+                    self.fetch(py, &endpoint, Some(params), |response: CommunicationDetailResponse| response.communication)
+                }
+
+                // ========================================
+                // House Requirement Operations
+                // ========================================
+
+                /// Get a list of House requirements
+                #[pyo3(signature = (format=None, offset=None, limit=None))]
+                pub fn list_house_requirements<'py>(
+                    &self,
+                    py: Python<'py>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                ) -> PyResult<Output<'py, Self, Vec<HouseRequirement>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    self.fetch(py, "/house-requirement", Some(params), |response: HouseRequirementsResponse| response.house_requirements)
+                }
+
+                /// Iterate over all House requirements, fetching pages lazily
+                #[pyo3(signature = (offset=None, page_size=None, max_items=None))]
+                pub fn iter_house_requirements(
+                    &self,
+                    offset: Option<u64>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    self.iter::<HouseRequirementsResponse>("/house-requirement", HashMap::new(), offset, page_size, max_items)
+                }
+
+                /// Get a list of House requirements, returning a `Page` with pagination metadata
+                #[pyo3(signature = (format=None, offset=None, limit=None))]
+                pub fn list_house_requirements_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    format: Option<String>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    self.page::<HouseRequirementsResponse>(py, "/house-requirement", params)
+                }
+
+                /// Count House requirements, using a single `limit=1` request
+                pub fn count_house_requirements<'py>(&self, py: Python<'py>) -> PyResult<Output<'py, Self, u64>> {
+                    self.count::<HouseRequirementsResponse>(py, "/house-requirement", HashMap::new())
+                }
+
+                /// Get detailed information for a specified House requirement
+                #[pyo3(signature = (requirement_number, format=None))]
+                pub fn get_house_requirement<'py>(
+                    &self,
+                    py: Python<'py>,
+                    requirement_number: i32,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, HouseRequirement>> {
+                    let mut params = HashMap::new();
+                    
+                    if let Some(f) = format {
+                        params.insert("format".to_string(), f);
+                    }
+                    
+                    let endpoint = format!("/house-requirement/{}", requirement_number);
+                    self.fetch(py, &endpoint, Some(params), |response: HouseRequirementDetailResponse| response.house_requirement)
+                }
+
+                /// Get the communications filed under a House requirement
+                #[pyo3(signature = (requirement_number, offset=None, limit=None, format=None))]
+                pub fn get_house_requirement_matching_communications<'py>(
+                    &self,
+                    py: Python<'py>,
+                    requirement_number: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<Communication>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/house-requirement/{}/matching-communications", requirement_number);
+                    self.fetch(py, &endpoint, Some(params), |response: CommunicationsResponse| response.communications)
+                }
+
+                // ========================================
+                // Congressional Record Operations
+                // ========================================
+
+                /// Get daily congressional records
+                #[pyo3(signature = (offset=None, limit=None, format=None))]
+                pub fn list_congressional_records<'py>(
+                    &self,
+                    py: Python<'py>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<DailyCongressionalRecord>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    self.fetch(py, "/daily-congressional-record", Some(params), |response: DailyCongressionalRecordsResponse| response.daily_congressional_record)
+                }
+
+                /// Iterate over all daily congressional records, fetching pages lazily
+                #[pyo3(signature = (offset=None, page_size=None, max_items=None))]
+                pub fn iter_congressional_records(
+                    &self,
+                    offset: Option<u64>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    self.iter::<DailyCongressionalRecordsResponse>("/daily-congressional-record", HashMap::new(), offset, page_size, max_items)
+                }
+
+                /// Get daily congressional records, returning a `Page` with pagination metadata
+                #[pyo3(signature = (offset=None, limit=None, format=None))]
+                pub fn list_congressional_records_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    self.page::<DailyCongressionalRecordsResponse>(py, "/daily-congressional-record", params)
+                }
+
+                /// Count daily congressional records, using a single `limit=1` request
+                pub fn count_congressional_records<'py>(&self, py: Python<'py>) -> PyResult<Output<'py, Self, u64>> {
+                    self.count::<DailyCongressionalRecordsResponse>(py, "/daily-congressional-record", HashMap::new())
+                }
+
+                // ========================================
+                // Law Operations
+                // ========================================
+
+                /// Get a list of all laws
+                #[pyo3(signature = (offset=None, limit=None, format=None))]
+                pub fn list_laws<'py>(
+                    &self,
+                    py: Python<'py>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<LawItem>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    self.fetch(py, "/law", Some(params), |response: LawsResponse| response.bills)
+                }
+
+                /// Iterate over all laws, fetching pages lazily
+                #[pyo3(signature = (offset=None, page_size=None, max_items=None))]
+                pub fn iter_laws(
+                    &self,
+                    offset: Option<u64>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    self.iter::<LawsResponse>("/law", HashMap::new(), offset, page_size, max_items)
+                }
+
+                /// Get a list of all laws, returning a `Page` with pagination metadata
+                #[pyo3(signature = (offset=None, limit=None, format=None))]
+                pub fn list_laws_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    self.page::<LawsResponse>(py, "/law", params)
+                }
+
+                /// Count laws, using a single `limit=1` request
+                pub fn count_laws<'py>(&self, py: Python<'py>) -> PyResult<Output<'py, Self, u64>> {
+                    self.count::<LawsResponse>(py, "/law", HashMap::new())
+                }
+
+                /// Get laws by congress
+                #[pyo3(signature = (congress, offset=None, limit=None, format=None))]
+                pub fn list_laws_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<LawItem>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/law/{}", congress);
+                    self.fetch(py, &endpoint, Some(params), |response: LawsResponse| response.bills)
+                }
+
+                /// Iterate over all laws by congress, fetching pages lazily
+                #[pyo3(signature = (congress, offset=None, page_size=None, max_items=None))]
+                pub fn iter_laws_by_congress(
+                    &self,
+                    congress: i32,
+                    offset: Option<u64>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let endpoint = format!("/law/{}", congress);
+                    self.iter::<LawsResponse>(&endpoint, HashMap::new(), offset, page_size, max_items)
+                }
+
+                /// Get laws by congress, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, offset=None, limit=None, format=None))]
+                pub fn list_laws_by_congress_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/law/{}", congress);
+                    self.page::<LawsResponse>(py, &endpoint, params)
+                }
+
+                /// Count laws by congress, using a single `limit=1` request
+                #[pyo3(signature = (congress))]
+                pub fn count_laws_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let endpoint = format!("/law/{}", congress);
+                    self.count::<LawsResponse>(py, &endpoint, HashMap::new())
+                }
+
+                /// Get laws by congress and type
+                /// Parameters:
+                ///   - congress: The congress number (e.g., 118)
+                ///   - law_type: A `LawType`, or "pub" (public laws) / "priv" (private laws)
+                ///   - offset: Pagination offset (optional)
+                ///   - limit: Number of results to return (optional)
+                ///   - format: Response format (optional)
+                #[pyo3(signature = (congress, law_type, offset=None, limit=None, format=None))]
+                pub fn list_laws_by_type<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    law_type: EnumArg<LawType>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<LawItem>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/law/{}/{}", congress, law_type);
+                    self.fetch(py, &endpoint, Some(params), |response: LawsResponse| response.bills)
+                }
+
+                /// Iterate over all laws by congress and type, fetching pages lazily
+                #[pyo3(signature = (congress, law_type, offset=None, page_size=None, max_items=None))]
+                pub fn iter_laws_by_type(
+                    &self,
+                    congress: i32,
+                    law_type: EnumArg<LawType>,
+                    offset: Option<u64>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let endpoint = format!("/law/{}/{}", congress, law_type);
+                    self.iter::<LawsResponse>(&endpoint, HashMap::new(), offset, page_size, max_items)
+                }
+
+                /// Get laws by congress and type, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, law_type, offset=None, limit=None, format=None))]
+                pub fn list_laws_by_type_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    law_type: EnumArg<LawType>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/law/{}/{}", congress, law_type);
+                    self.page::<LawsResponse>(py, &endpoint, params)
+                }
+
+                /// Count laws by congress and type, using a single `limit=1` request
+                #[pyo3(signature = (congress, law_type))]
+                pub fn count_laws_by_type<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    law_type: EnumArg<LawType>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let endpoint = format!("/law/{}/{}", congress, law_type);
+                    self.count::<LawsResponse>(py, &endpoint, HashMap::new())
+                }
+
+                /// Get a specific law by bill type and bill number
+                /// 
+                /// Note: Despite the swagger documentation referring to "lawType" and "lawNumber",
+                /// the actual API endpoint uses the BILL's type and number, not the resulting law's type/number.
+                /// For example, to get the law that HR 4984 became, use law_type="hr" and law_number="4984"
+                /// 
+                /// Parameters:
+                ///   - congress: The congress number (e.g., 118)
+                ///   - law_type: A `BillType` or a bill type string like "hr", "s", "hjres", "sjres" (case-insensitive)
+                ///               This is NOT "pub"/"priv" - those are for list_laws_by_type()
+                ///   - law_number: The bill number as string (e.g., "346" or "4984")
+                ///   - id: In place of the three above, a `BillId` / bill citation such as
+                ///         "H.R. 346 (118th Congress)", or a `LawId` / law citation such as
+                ///         "Pub. L. 118-4", whose bill is then looked up in list_laws_by_type() first
+                #[pyo3(signature = (congress=None, law_type=None, law_number=None, format=None, *, id=None))]
+                pub fn get_law<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: Option<i32>,
+                    law_type: Option<EnumArg<BillType>>,
+                    law_number: Option<String>,
+                    format: Option<String>,
+                    id: Option<LawArg>,
+                ) -> PyResult<Output<'py, Self, LawDetail>> {
+                    // The bill to request, or the law whose bill is looked up first
+                    let bill = LawArg::resolve(id, congress, law_type, law_number)?;
+                    let mut params = HashMap::new();
+                    
+                    if let Some(f) = format {
+                        params.insert("format".to_string(), f);
+                    }
+                    
+                    let client = self.api();
+                    self.run(py, async move {
+                        let (congress, law_type, law_number) = match bill {
+                            Ok(bill) => bill,
+                            Err(law) => {
+                                let (bill_type, bill_number) = client.find_law_bill(&law).await?;
+                                (law.congress, bill_type, bill_number)
+                            }
+                        };
+                        let endpoint = format!("/law/{}/{}/{}", congress, law_type, law_number);
+                        let response: LawDetailResponse = client.get_async(&endpoint, Some(params)).await?;
+                        Ok(response.bill)
+                    })
+                }
+
+                // ========================================
+                // Summaries Operations
+                // ========================================
+
+                /// Get a list of summaries
+                #[pyo3(signature = (offset=None, limit=None, format=None))]
+                pub fn list_summaries<'py>(
+                    &self,
+                    py: Python<'py>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<SummaryItem>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    self.fetch(py, "/summaries", Some(params), |response: SummariesListResponse| response.summaries)
+                }
+
+                /// Iterate over all summaries, fetching pages lazily
+                #[pyo3(signature = (offset=None, page_size=None, max_items=None))]
+                pub fn iter_summaries(
+                    &self,
+                    offset: Option<u64>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    self.iter::<SummariesListResponse>("/summaries", HashMap::new(), offset, page_size, max_items)
+                }
+
+                /// Get a list of summaries, returning a `Page` with pagination metadata
+                #[pyo3(signature = (offset=None, limit=None, format=None))]
+                pub fn list_summaries_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    self.page::<SummariesListResponse>(py, "/summaries", params)
+                }
+
+                /// Count summaries, using a single `limit=1` request
+                pub fn count_summaries<'py>(&self, py: Python<'py>) -> PyResult<Output<'py, Self, u64>> {
+                    self.count::<SummariesListResponse>(py, "/summaries", HashMap::new())
+                }
+
+                /// Get summaries by congress
+                #[pyo3(signature = (congress, offset=None, limit=None, format=None))]
+                pub fn list_summaries_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<SummaryItem>>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/summaries/{}", congress);
+                    self.fetch(py, &endpoint, Some(params), |response: SummariesListResponse| response.summaries)
+                }
+
+                /// Iterate over all summaries by congress, fetching pages lazily
+                #[pyo3(signature = (congress, offset=None, page_size=None, max_items=None))]
+                pub fn iter_summaries_by_congress(
+                    &self,
+                    congress: i32,
+                    offset: Option<u64>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let endpoint = format!("/summaries/{}", congress);
+                    self.iter::<SummariesListResponse>(&endpoint, HashMap::new(), offset, page_size, max_items)
+                }
+
+                /// Get summaries by congress, returning a `Page` with pagination metadata
+                #[pyo3(signature = (congress, offset=None, limit=None, format=None))]
+                pub fn list_summaries_by_congress_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = list_params(format, offset, limit);
+                    
+                    let endpoint = format!("/summaries/{}", congress);
+                    self.page::<SummariesListResponse>(py, &endpoint, params)
+                }
+
+                /// Count summaries by congress, using a single `limit=1` request
+                #[pyo3(signature = (congress))]
+                pub fn count_summaries_by_congress<'py>(
+                    &self,
+                    py: Python<'py>,
+                    congress: i32,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let endpoint = format!("/summaries/{}", congress);
+                    self.count::<SummariesListResponse>(py, &endpoint, HashMap::new())
+                }
+
+                // ========================================
+                // CRS Report Operations
+                // ========================================
+
+                /// Get a list of CRS reports
+                #[pyo3(signature = (offset=None, limit=None, from_date_time=None, to_date_time=None, format=None))]
+                pub fn list_crs_reports<'py>(
+                    &self,
+                    py: Python<'py>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Vec<CrsReport>>> {
+                    let params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+                    
+                    self.fetch(py, "/crsreport", Some(params), |response: CrsReportsResponse| response.crs_reports)
+                }
+
+                /// Iterate over all CRS reports, fetching pages lazily
+                #[pyo3(signature = (offset=None, from_date_time=None, to_date_time=None, page_size=None, max_items=None))]
+                pub fn iter_crs_reports(
+                    &self,
+                    offset: Option<u64>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                    page_size: Option<u32>,
+                    max_items: Option<usize>,
+                ) -> PyResult<Iter<Self>> {
+                    let params = dated_params(None, None, None, from_date_time, to_date_time)?;
+                    
+                    self.iter::<CrsReportsResponse>("/crsreport", params, offset, page_size, max_items)
+                }
+
+                /// Get a list of CRS reports, returning a `Page` with pagination metadata
+                #[pyo3(signature = (offset=None, limit=None, from_date_time=None, to_date_time=None, format=None))]
+                pub fn list_crs_reports_page<'py>(
+                    &self,
+                    py: Python<'py>,
+                    offset: Option<i32>,
+                    limit: Option<i32>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, Page>> {
+                    let params = dated_params(format, offset, limit, from_date_time, to_date_time)?;
+                    
+                    self.page::<CrsReportsResponse>(py, "/crsreport", params)
+                }
+
+                /// Count CRS reports, using a single `limit=1` request
+                #[pyo3(signature = (from_date_time=None, to_date_time=None))]
+                pub fn count_crs_reports<'py>(
+                    &self,
+                    py: Python<'py>,
+                    from_date_time: Option<DateFilter>,
+                    to_date_time: Option<DateFilter>,
+                ) -> PyResult<Output<'py, Self, u64>> {
+                    let params = dated_params(None, None, None, from_date_time, to_date_time)?;
+                    
+                    self.count::<CrsReportsResponse>(py, "/crsreport", params)
+                }
+
+                /// Get detailed information for a specific CRS report
+                #[pyo3(signature = (report_number, format=None))]
+                pub fn get_crs_report<'py>(
+                    &self,
+                    py: Python<'py>,
+                    report_number: String,
+                    format: Option<String>,
+                ) -> PyResult<Output<'py, Self, CrsReportDetail>> {
+                    let mut params = HashMap::new();
+                    
+                    if let Some(f) = format {
+                        params.insert("format".to_string(), f);
+                    }
+                    
+                    let endpoint = format!("/crsreport/{}", report_number);
+                    self.fetch(py, &endpoint, Some(params), |response: CrsReportDetailResponse| response.report)
+                }
+            }
+        };
+    };
+}
+
+pub(crate) use endpoint_methods;
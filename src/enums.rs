@@ -0,0 +1,343 @@
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyCFunction, PyDict, PyString, PyTuple, PyType};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// A fixed set of codes used by the API, each with the spelling found in
+/// response data (`"HR"`, `"House"`) and the one used in URLs (`"hr"`,
+/// `"house"`)
+pub trait ApiEnum: Copy + PartialEq + Send + Sync + 'static {
+    /// Name of the Python class
+    const NAME: &'static str;
+    /// What the values are, for error messages
+    const KIND: &'static str;
+    const DOC: &'static str;
+    const ALL: &'static [Self];
+
+    /// Name of the member in Python
+    fn py_name(self) -> &'static str;
+    /// Spelling in response data
+    fn value(self) -> &'static str;
+    /// Spelling in request paths
+    fn path(self) -> &'static str;
+    /// Other accepted spellings
+    fn aliases(self) -> &'static [&'static str];
+    /// Holds the Python class once it is built
+    fn cell() -> &'static GILOnceCell<Py<PyType>>;
+
+    /// Case-insensitive lookup by value, path or alias
+    fn parse(text: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|variant| {
+            text.eq_ignore_ascii_case(variant.value())
+                || text.eq_ignore_ascii_case(variant.path())
+                || variant.aliases().iter().any(|alias| text.eq_ignore_ascii_case(alias))
+        })
+    }
+
+    /// The Python class, built on first use
+    fn py_type(py: Python<'_>) -> PyResult<&Bound<'_, PyType>> {
+        Self::cell().get_or_try_init(py, || build::<Self>(py)).map(|ty| ty.bind(py))
+    }
+
+    /// The Python member for this variant
+    fn to_py(self, py: Python<'_>) -> PyResult<Bound<'_, PyAny>> {
+        Self::py_type(py)?.getattr(self.py_name())
+    }
+}
+
+fn unknown<E: ApiEnum>(text: &str) -> PyErr {
+    let valid: Vec<&str> = E::ALL.iter().map(|variant| variant.path()).collect();
+    PyValueError::new_err(format!(
+        "unknown {} {:?}; expected one of {}",
+        E::KIND,
+        text,
+        valid.join(", ")
+    ))
+}
+
+/// Build `E` as a `str`-mixin `enum.Enum`, so members are iterable, can be
+/// looked up by name and still work as the strings the API sends.
+/// Calling the class with any accepted spelling finds the member through
+/// `_missing_`.
+fn build<E: ApiEnum>(py: Python<'_>) -> PyResult<Py<PyType>> {
+    let str_type = py.get_type::<PyString>();
+    let members: Vec<(&str, &str)> = E::ALL.iter().map(|variant| (variant.py_name(), variant.value())).collect();
+    let kwargs = PyDict::new(py);
+    kwargs.set_item("module", "cdg_python_client")?;
+    kwargs.set_item("qualname", E::NAME)?;
+    kwargs.set_item("type", &str_type)?;
+    let class = py.import("enum")?.getattr("Enum")?.call((E::NAME, members), Some(&kwargs))?;
+
+    // `str()` and f-strings give the value, as for `enum.StrEnum`
+    class.setattr("__str__", str_type.getattr("__str__")?)?;
+    class.setattr("__format__", str_type.getattr("__format__")?)?;
+    class.setattr("__doc__", E::DOC.trim())?;
+
+    let missing = PyCFunction::new_closure(py, None, None, |args: &Bound<'_, PyTuple>, _: Option<&Bound<'_, PyDict>>| {
+        let (_, value): (Bound<'_, PyAny>, Bound<'_, PyAny>) = args.extract()?;
+        let Ok(text) = value.downcast::<PyString>() else {
+            return Ok(value.py().None());
+        };
+        let text = text.to_str()?;
+        match E::parse(text) {
+            Some(variant) => Ok(variant.to_py(value.py())?.unbind()),
+            None => Err(unknown::<E>(text)),
+        }
+    })?;
+    let classmethod = py.import("builtins")?.getattr("classmethod")?.call1((missing,))?;
+    class.setattr("_missing_", classmethod)?;
+
+    Ok(class.downcast_into::<PyType>()?.unbind())
+}
+
+/// Add the Python class for `E` to the module
+pub fn add<E: ApiEnum>(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add(E::NAME, E::py_type(m.py())?)
+}
+
+macro_rules! api_enum {
+    (
+        $(#[doc = $doc:literal])*
+        $name:ident, $kind:literal {
+            $($variant:ident = $py_name:literal, $value:literal, $path:literal $(, $alias:literal)*;)*
+        }
+    ) => {
+        $(#[doc = $doc])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+        }
+
+        impl ApiEnum for $name {
+            const NAME: &'static str = stringify!($name);
+            const KIND: &'static str = $kind;
+            const DOC: &'static str = concat!($($doc, "\n"),*);
+            const ALL: &'static [Self] = &[$(Self::$variant),*];
+
+            fn py_name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $py_name,)*
+                }
+            }
+
+            fn value(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $value,)*
+                }
+            }
+
+            fn path(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $path,)*
+                }
+            }
+
+            fn aliases(self) -> &'static [&'static str] {
+                match self {
+                    $(Self::$variant => &[$($alias),*],)*
+                }
+            }
+
+            fn cell() -> &'static GILOnceCell<Py<PyType>> {
+                static CELL: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+                &CELL
+            }
+        }
+
+        impl<'py> IntoPyObject<'py> for $name {
+            type Target = PyAny;
+            type Output = Bound<'py, PyAny>;
+            type Error = PyErr;
+
+            fn into_pyobject(self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+                self.to_py(py)
+            }
+        }
+
+        impl<'py> IntoPyObject<'py> for &$name {
+            type Target = PyAny;
+            type Output = Bound<'py, PyAny>;
+            type Error = PyErr;
+
+            fn into_pyobject(self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+                self.to_py(py)
+            }
+        }
+    };
+}
+
+api_enum! {
+    /// Type of a bill or resolution
+    BillType, "bill type" {
+        Hr = "HR", "HR", "hr";
+        S = "S", "S", "s";
+        Hjres = "HJRES", "HJRES", "hjres";
+        Sjres = "SJRES", "SJRES", "sjres";
+        Hconres = "HCONRES", "HCONRES", "hconres";
+        Sconres = "SCONRES", "SCONRES", "sconres";
+        Hres = "HRES", "HRES", "hres";
+        Sres = "SRES", "SRES", "sres";
+    }
+}
+
+api_enum! {
+    /// Type of an amendment
+    AmendmentType, "amendment type" {
+        Hamdt = "HAMDT", "HAMDT", "hamdt";
+        Samdt = "SAMDT", "SAMDT", "samdt";
+        Suamdt = "SUAMDT", "SUAMDT", "suamdt", "supamdt";
+    }
+}
+
+api_enum! {
+    /// Chamber of Congress
+    Chamber, "chamber" {
+        House = "HOUSE", "House", "house", "House of Representatives";
+        Senate = "SENATE", "Senate", "senate";
+        Joint = "JOINT", "Joint", "joint";
+    }
+}
+
+api_enum! {
+    /// Public or private law
+    LawType, "law type" {
+        Public = "PUBLIC", "Public Law", "pub", "public";
+        Private = "PRIVATE", "Private Law", "priv", "private";
+    }
+}
+
+api_enum! {
+    /// How a member voted on a roll call
+    VoteCast, "vote cast" {
+        Yea = "YEA", "Yea", "yea";
+        Nay = "NAY", "Nay", "nay";
+        Aye = "AYE", "Aye", "aye";
+        No = "NO", "No", "no";
+        Present = "PRESENT", "Present", "present";
+        NotVoting = "NOT_VOTING", "Not Voting", "not voting", "not_voting";
+    }
+}
+
+api_enum! {
+    /// Party code on a roll call vote; named `PartyCode` because `Party` is
+    /// the model holding a party's name and code
+    PartyCode, "party code" {
+        Democrat = "D", "D", "d", "Democrat", "Democratic";
+        Republican = "R", "R", "r", "Republican";
+        Independent = "I", "I", "i", "Independent";
+        IndependentDemocrat = "ID", "ID", "id", "Independent Democrat";
+        Libertarian = "L", "L", "l", "Libertarian";
+    }
+}
+
+api_enum! {
+    /// Type of a committee report
+    ReportType, "report type" {
+        Hrpt = "HRPT", "HRPT", "hrpt", "hrept";
+        Srpt = "SRPT", "SRPT", "srpt", "srept";
+        Erpt = "ERPT", "ERPT", "erpt", "exrept";
+    }
+}
+
+api_enum! {
+    /// Type of a House or Senate communication
+    CommunicationType, "communication type" {
+        Ec = "EC", "EC", "ec", "Executive Communication";
+        Ml = "ML", "ML", "ml", "Memorial";
+        Pm = "PM", "PM", "pm", "Presidential Message";
+        Pt = "PT", "PT", "pt", "Petition";
+        Pom = "POM", "POM", "pom", "Petition or Memorial";
+    }
+}
+
+/// A code in response data: an enum member when it is known, otherwise the
+/// string the API sent, so new codes never break decoding
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum Code<E> {
+    Known(E),
+    Other(String),
+}
+
+impl<E: ApiEnum> Code<E> {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Code::Known(variant) => variant.value(),
+            Code::Other(text) => text,
+        }
+    }
+}
+
+// Debug shows the API's string so model reprs read as before
+impl<E: ApiEnum> fmt::Debug for Code<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<E: ApiEnum> Serialize for Code<E> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de, E: ApiEnum> Deserialize<'de> for Code<E> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        Ok(match E::parse(&text) {
+            Some(variant) => Code::Known(variant),
+            None => Code::Other(text),
+        })
+    }
+}
+
+impl<'py, E: ApiEnum> IntoPyObject<'py> for &Code<E> {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        Ok(match self {
+            Code::Known(variant) => variant.to_py(py)?,
+            Code::Other(text) => PyString::new(py, text).into_any(),
+        })
+    }
+}
+
+impl<'py, E: ApiEnum> IntoPyObject<'py> for Code<E> {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        (&self).into_pyobject(py)
+    }
+}
+
+/// A method argument given as an enum member or any accepted spelling.
+/// Unknown strings are rejected before a request is sent. Formats as the
+/// URL spelling.
+#[derive(Debug, Clone, Copy)]
+pub struct EnumArg<E>(pub E);
+
+impl<'py, E: ApiEnum> FromPyObject<'py> for EnumArg<E> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        // Members are `str` instances holding their value
+        if let Ok(text) = ob.downcast::<PyString>() {
+            let text = text.to_str()?;
+            return E::parse(text).map(EnumArg).ok_or_else(|| unknown::<E>(text));
+        }
+        Err(PyTypeError::new_err(format!(
+            "expected a {} or str, got {}",
+            E::KIND,
+            ob.get_type().name()?
+        )))
+    }
+}
+
+impl<E: ApiEnum> fmt::Display for EnumArg<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0.path())
+    }
+}
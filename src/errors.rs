@@ -0,0 +1,164 @@
+use pyo3::create_exception;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+use std::time::Duration;
+use thiserror::Error;
+
+// Python exception hierarchy. The base class derives from RuntimeError so
+// code written against older releases (which raised RuntimeError) still works.
+create_exception!(cdg_python_client, CongressApiError, PyRuntimeError, "Base class for all Congress.gov API errors.");
+create_exception!(cdg_python_client, BadRequestError, CongressApiError, "The API rejected the request parameters (HTTP 400/422).");
+create_exception!(cdg_python_client, AuthenticationError, CongressApiError, "The API key is missing, invalid or expired (HTTP 401/403).");
+create_exception!(cdg_python_client, NotFoundError, CongressApiError, "The requested resource does not exist (HTTP 404).");
+create_exception!(cdg_python_client, RateLimitError, CongressApiError, "The server (HTTP 429) or the client-side limiter refused the request.");
+create_exception!(cdg_python_client, ServerError, CongressApiError, "The API failed with a 5xx status.");
+create_exception!(cdg_python_client, DecodeError, CongressApiError, "The response body could not be decoded into the expected model.");
+create_exception!(cdg_python_client, NetworkError, CongressApiError, "The request could not be sent or the response could not be read.");
+create_exception!(cdg_python_client, CassetteError, CongressApiError, "A request has no recorded response in replay mode, or a cassette could not be written.");
+
+#[derive(Error, Debug)]
+pub enum ApiError {
+    /// The API answered with a non-success status
+    #[error("API error: status {status} for {endpoint}{}", message.as_deref().map(|m| format!(": {}", m)).unwrap_or_default())]
+    Http {
+        status: u16,
+        endpoint: String,
+        body: Option<String>,
+        message: Option<String>,
+        retry_after: Option<Duration>,
+    },
+
+    /// The request never produced a response (DNS, connect, timeout, ...).
+    /// The message is pre-rendered with the API key scrubbed out.
+    #[error("Request to {endpoint} failed: {message}")]
+    Network { endpoint: String, message: String },
+
+    /// The body was received but is not what the model expects
+    #[error("Could not decode response from {endpoint}: {message}")]
+    Decode {
+        endpoint: String,
+        message: String,
+        body: Option<String>,
+    },
+
+    /// Replay found no recorded response, or recording failed
+    #[error("Cassette error for {endpoint}: {message}")]
+    Cassette { endpoint: String, message: String },
+
+    /// The client-side token bucket has no budget left
+    #[error("Client-side rate limit exceeded; next request allowed in {:.1}s", .0.as_secs_f64())]
+    RateLimited(Duration),
+
+    #[allow(dead_code)]
+    #[error("Missing API key")]
+    MissingApiKey,
+}
+
+pub type ApiResult<T> = Result<T, ApiError>;
+
+impl ApiError {
+    /// Build an `Http` error, pulling a human-readable message out of the
+    /// API's JSON error body when there is one
+    pub fn http(status: u16, endpoint: &str, body: String, retry_after: Option<Duration>) -> Self {
+        let message = error_message(&body);
+        let body = if body.is_empty() { None } else { Some(body) };
+        ApiError::Http {
+            status,
+            endpoint: endpoint.to_string(),
+            body,
+            message,
+            retry_after,
+        }
+    }
+
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            ApiError::Http { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    pub fn endpoint(&self) -> Option<&str> {
+        match self {
+            ApiError::Http { endpoint, .. }
+            | ApiError::Network { endpoint, .. }
+            | ApiError::Decode { endpoint, .. }
+            | ApiError::Cassette { endpoint, .. } => Some(endpoint),
+            _ => None,
+        }
+    }
+
+    pub fn body(&self) -> Option<&str> {
+        match self {
+            ApiError::Http { body, .. } | ApiError::Decode { body, .. } => body.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            ApiError::Http { retry_after, .. } => *retry_after,
+            ApiError::RateLimited(wait) => Some(*wait),
+            _ => None,
+        }
+    }
+}
+
+impl From<ApiError> for PyErr {
+    fn from(error: ApiError) -> PyErr {
+        let message = error.to_string();
+        let err = match &error {
+            ApiError::Http { status, .. } => match status {
+                400 | 422 => BadRequestError::new_err(message),
+                401 | 403 => AuthenticationError::new_err(message),
+                404 => NotFoundError::new_err(message),
+                429 => RateLimitError::new_err(message),
+                500..=599 => ServerError::new_err(message),
+                _ => CongressApiError::new_err(message),
+            },
+            ApiError::Network { .. } => NetworkError::new_err(message),
+            ApiError::Decode { .. } => DecodeError::new_err(message),
+            ApiError::Cassette { .. } => CassetteError::new_err(message),
+            ApiError::RateLimited(_) => RateLimitError::new_err(message),
+            ApiError::MissingApiKey => AuthenticationError::new_err(message),
+        };
+
+        // Expose the structured details as attributes on the exception;
+        // setting attributes on a fresh exception instance cannot fail
+        Python::with_gil(|py| {
+            let value = err.value(py);
+            let _ = value.setattr("status_code", error.status_code());
+            let _ = value.setattr("endpoint", error.endpoint());
+            let _ = value.setattr("body", error.body());
+            let _ = value.setattr("retry_after", error.retry_after().map(|d| d.as_secs_f64()));
+        });
+
+        err
+    }
+}
+
+/// Add the exception classes to the Python module
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    m.add("CongressApiError", py.get_type::<CongressApiError>())?;
+    m.add("BadRequestError", py.get_type::<BadRequestError>())?;
+    m.add("AuthenticationError", py.get_type::<AuthenticationError>())?;
+    m.add("NotFoundError", py.get_type::<NotFoundError>())?;
+    m.add("RateLimitError", py.get_type::<RateLimitError>())?;
+    m.add("ServerError", py.get_type::<ServerError>())?;
+    m.add("DecodeError", py.get_type::<DecodeError>())?;
+    m.add("NetworkError", py.get_type::<NetworkError>())?;
+    m.add("CassetteError", py.get_type::<CassetteError>())?;
+    Ok(())
+}
+
+/// Extract the message from the error shapes the API is known to return:
+/// `{"error": "..."}`, `{"error": {"message": "..."}}` and `{"message": "..."}`
+fn error_message(body: &str) -> Option<String> {
+    let value: serde_json::Value = serde_json::from_str(body).ok()?;
+    let error = value.get("error").unwrap_or(&value);
+    error
+        .as_str()
+        .or_else(|| error.get("message").and_then(|m| m.as_str()))
+        .map(|m| m.to_string())
+}
@@ -1,24 +1,31 @@
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::dates::ApiDate;
+use crate::enums::{Chamber, Code};
+use crate::pagination::{Pagination, RequestEcho};
+use crate::serialization::model_methods;
+
 /// Represents a hearing date
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct HearingDate {
     #[pyo3(get)]
-    pub date: Option<String>,
+    pub date: Option<ApiDate>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[pymethods]
-impl HearingDate {
+model_methods!(HearingDate {
     fn __repr__(&self) -> String {
         format!("HearingDate(date={:?})", self.date)
     }
-}
+});
 
 /// Represents an associated meeting
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct AssociatedMeeting {
     #[pyo3(get)]
     #[serde(rename = "eventId")]
@@ -26,18 +33,20 @@ pub struct AssociatedMeeting {
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[pymethods]
-impl AssociatedMeeting {
+model_methods!(AssociatedMeeting {
     fn __repr__(&self) -> String {
         format!("AssociatedMeeting(event_id={:?})", self.event_id)
     }
-}
+});
 
 /// Represents a format option for a hearing
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct HearingFormat {
     #[pyo3(get)]
     #[serde(rename = "type")]
@@ -45,18 +54,20 @@ pub struct HearingFormat {
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[pymethods]
-impl HearingFormat {
+model_methods!(HearingFormat {
     fn __repr__(&self) -> String {
         format!("HearingFormat(type={:?})", self.format_type)
     }
-}
+});
 
 /// Represents a committee/subcommittee in a hearing
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct HearingCommittee {
     #[pyo3(get)]
     pub name: Option<String>,
@@ -67,33 +78,39 @@ pub struct HearingCommittee {
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[pymethods]
-impl HearingCommittee {
+model_methods!(HearingCommittee {
     fn __repr__(&self) -> String {
         format!("HearingCommittee(name={:?}, code={:?})", self.name, self.system_code)
     }
-}
+});
 
 /// Represents a hearing in list responses
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct Hearing {
     #[pyo3(get)]
-    pub chamber: Option<String>,
+    pub chamber: Option<Code<Chamber>>,
     
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub congress: Option<i32>,
     
     #[pyo3(get)]
     #[serde(rename = "jacketNumber")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub jacket_number: Option<i32>,
     
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub number: Option<i32>,
     
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub part: Option<i32>,
     
     #[pyo3(get)]
@@ -101,7 +118,7 @@ pub struct Hearing {
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
     
     #[pyo3(get)]
     pub url: Option<String>,
@@ -125,24 +142,33 @@ pub struct Hearing {
     #[pyo3(get)]
     #[serde(rename = "libraryOfCongressIdentifier")]
     pub library_of_congress_identifier: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl Hearing {
+model_methods!(raw Hearing {
     fn __repr__(&self) -> String {
         format!(
             "Hearing(congress={:?}, chamber={:?}, jacket_number={:?}, title={:?})",
             self.congress, self.chamber, self.jacket_number, self.title
         )
     }
-}
+});
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HearingsResponse {
+    #[serde(deserialize_with = "crate::raw::list")]
     pub hearings: Vec<Hearing>,
+    pub pagination: Option<Pagination>,
+    pub request: Option<RequestEcho>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HearingDetailResponse {
+    #[serde(deserialize_with = "crate::raw::one")]
     pub hearing: Hearing,
 }
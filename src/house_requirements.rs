@@ -0,0 +1,82 @@
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::committees::ResourceCount;
+use crate::dates::ApiDate;
+use crate::pagination::{Pagination, RequestEcho};
+use crate::serialization::model_methods;
+
+/// A reporting requirement the House places on executive agencies. List
+/// responses carry only the number, update date and url.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
+pub struct HouseRequirement {
+    #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub number: Option<i32>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "activeRecord")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub active_record: Option<bool>,
+    
+    #[pyo3(get)]
+    pub frequency: Option<String>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "legalAuthority")]
+    pub legal_authority: Option<String>,
+    
+    #[pyo3(get)]
+    pub nature: Option<String>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "submittingAgency")]
+    pub submitting_agency: Option<String>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "parentAgency")]
+    pub parent_agency: Option<String>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "matchingCommunications")]
+    pub matching_communications: Option<ResourceCount>,
+    
+    #[pyo3(get)]
+    #[serde(rename = "updateDate")]
+    pub update_date: Option<ApiDate>,
+    
+    #[pyo3(get)]
+    pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
+}
+
+model_methods!(raw HouseRequirement {
+    fn __repr__(&self) -> String {
+        format!(
+            "HouseRequirement(number={:?}, nature={:?})",
+            self.number, self.nature
+        )
+    }
+});
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HouseRequirementsResponse {
+    #[serde(rename = "houseRequirements")]
+    #[serde(deserialize_with = "crate::raw::list")]
+    pub house_requirements: Vec<HouseRequirement>,
+    pub pagination: Option<Pagination>,
+    pub request: Option<RequestEcho>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HouseRequirementDetailResponse {
+    #[serde(rename = "houseRequirement")]
+    #[serde(deserialize_with = "crate::raw::one")]
+    pub house_requirement: HouseRequirement,
+}
@@ -1,14 +1,21 @@
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::dates::ApiDate;
+use crate::enums::{Code, PartyCode, VoteCast};
+use crate::pagination::{Pagination, RequestEcho};
+use crate::serialization::model_methods;
+
 /// Represents a House of Representatives roll call vote
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct HouseVote {
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub congress: Option<i32>,
     
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub identifier: Option<i64>,
     
     #[pyo3(get)]
@@ -28,10 +35,12 @@ pub struct HouseVote {
     
     #[pyo3(get)]
     #[serde(rename = "rollCallNumber")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub roll_call_number: Option<i32>,
     
     #[pyo3(get)]
     #[serde(rename = "sessionNumber")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub session_number: Option<i32>,
     
     #[pyo3(get)]
@@ -40,11 +49,11 @@ pub struct HouseVote {
     
     #[pyo3(get)]
     #[serde(rename = "startDate")]
-    pub start_date: Option<String>,
+    pub start_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
     
     #[pyo3(get)]
     pub url: Option<String>,
@@ -52,83 +61,98 @@ pub struct HouseVote {
     #[pyo3(get)]
     #[serde(rename = "voteType")]
     pub vote_type: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl HouseVote {
+model_methods!(raw HouseVote {
     fn __repr__(&self) -> String {
         format!(
             "HouseVote(congress={:?}, session={:?}, roll_call={:?}, result={:?})",
             self.congress, self.session_number, self.roll_call_number, self.result
         )
     }
-}
+});
 
 /// Represents party information
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct Party {
     #[pyo3(get)]
     pub name: Option<String>,
     
     #[pyo3(get)]
     #[serde(rename = "type")]
-    pub party_type: Option<String>,
+    pub party_type: Option<Code<PartyCode>>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[pymethods]
-impl Party {
+model_methods!(Party {
     fn __repr__(&self) -> String {
         format!("Party(name={:?}, type={:?})", self.name, self.party_type)
     }
-}
+});
 
 /// Represents vote totals by party
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct VoteParty {
     #[pyo3(get)]
     #[serde(rename = "nayTotal")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub nay_total: Option<i32>,
     
     #[pyo3(get)]
     #[serde(rename = "notVotingTotal")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub not_voting_total: Option<i32>,
     
     #[pyo3(get)]
     #[serde(rename = "presentTotal")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub present_total: Option<i32>,
     
     #[pyo3(get)]
     #[serde(rename = "voteParty")]
-    pub vote_party: Option<String>,
+    pub vote_party: Option<Code<PartyCode>>,
     
     #[pyo3(get)]
     #[serde(rename = "yeaTotal")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub yea_total: Option<i32>,
     
     #[pyo3(get)]
     pub party: Option<Party>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[pymethods]
-impl VoteParty {
+model_methods!(VoteParty {
     fn __repr__(&self) -> String {
         format!(
             "VoteParty(party={:?}, yea={:?}, nay={:?})",
             self.vote_party, self.yea_total, self.nay_total
         )
     }
-}
+});
 
 /// Represents detailed house vote information with party totals
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct HouseVoteDetail {
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub congress: Option<i32>,
     
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub identifier: Option<i64>,
     
     #[pyo3(get)]
@@ -148,10 +172,12 @@ pub struct HouseVoteDetail {
     
     #[pyo3(get)]
     #[serde(rename = "rollCallNumber")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub roll_call_number: Option<i32>,
     
     #[pyo3(get)]
     #[serde(rename = "sessionNumber")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub session_number: Option<i32>,
     
     #[pyo3(get)]
@@ -160,11 +186,11 @@ pub struct HouseVoteDetail {
     
     #[pyo3(get)]
     #[serde(rename = "startDate")]
-    pub start_date: Option<String>,
+    pub start_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "voteType")]
@@ -177,21 +203,26 @@ pub struct HouseVoteDetail {
     #[pyo3(get)]
     #[serde(rename = "voteQuestion")]
     pub vote_question: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl HouseVoteDetail {
+model_methods!(raw HouseVoteDetail {
     fn __repr__(&self) -> String {
         format!(
             "HouseVoteDetail(congress={:?}, session={:?}, roll_call={:?}, question={:?})",
             self.congress, self.session_number, self.roll_call_number, self.vote_question
         )
     }
-}
+});
 
 /// Represents how a member voted
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct MemberVote {
     #[pyo3(get)]
     #[serde(rename = "bioguideID")]
@@ -207,35 +238,39 @@ pub struct MemberVote {
     
     #[pyo3(get)]
     #[serde(rename = "voteCast")]
-    pub vote_cast: Option<String>,
+    pub vote_cast: Option<Code<VoteCast>>,
     
     #[pyo3(get)]
     #[serde(rename = "voteParty")]
-    pub vote_party: Option<String>,
+    pub vote_party: Option<Code<PartyCode>>,
     
     #[pyo3(get)]
     #[serde(rename = "voteState")]
     pub vote_state: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[pymethods]
-impl MemberVote {
+model_methods!(MemberVote {
     fn __repr__(&self) -> String {
         format!(
             "MemberVote(name={:?} {:?}, vote={:?})",
             self.first_name, self.last_name, self.vote_cast
         )
     }
-}
+});
 
 /// Represents house vote with member voting details
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct HouseVoteMembers {
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub congress: Option<i32>,
     
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub identifier: Option<i64>,
     
     #[pyo3(get)]
@@ -255,10 +290,12 @@ pub struct HouseVoteMembers {
     
     #[pyo3(get)]
     #[serde(rename = "rollCallNumber")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub roll_call_number: Option<i32>,
     
     #[pyo3(get)]
     #[serde(rename = "sessionNumber")]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub session_number: Option<i32>,
     
     #[pyo3(get)]
@@ -267,11 +304,11 @@ pub struct HouseVoteMembers {
     
     #[pyo3(get)]
     #[serde(rename = "startDate")]
-    pub start_date: Option<String>,
+    pub start_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "voteType")]
@@ -283,10 +320,15 @@ pub struct HouseVoteMembers {
     #[pyo3(get)]
     #[serde(rename = "voteQuestion")]
     pub vote_question: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl HouseVoteMembers {
+model_methods!(raw HouseVoteMembers {
     fn __repr__(&self) -> String {
         format!(
             "HouseVoteMembers(congress={:?}, session={:?}, roll_call={:?}, members={:?})",
@@ -294,19 +336,23 @@ impl HouseVoteMembers {
             self.results.as_ref().map(|r| r.len())
         )
     }
-}
+});
 
 /// Response structure for list of house votes
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HouseVotesResponse {
     #[serde(rename = "houseRollCallVotes")]
+    #[serde(deserialize_with = "crate::raw::list")]
     pub votes: Vec<HouseVote>,
+    pub pagination: Option<Pagination>,
+    pub request: Option<RequestEcho>,
 }
 
 /// Response structure for a single house vote detail
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HouseVoteDetailResponse {
     #[serde(rename = "houseRollCallVote")]
+    #[serde(deserialize_with = "crate::raw::one")]
     pub vote: HouseVoteDetail,
 }
 
@@ -314,5 +360,6 @@ pub struct HouseVoteDetailResponse {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HouseVoteMembersResponse {
     #[serde(rename = "houseRollCallVoteMemberVotes")]
+    #[serde(deserialize_with = "crate::raw::one")]
     pub vote: HouseVoteMembers,
 }
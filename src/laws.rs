@@ -1,13 +1,18 @@
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::dates::ApiDate;
+use crate::enums::{BillType, Chamber, Code};
 use crate::bills::{LatestAction, Law};
+use crate::pagination::{Pagination, RequestEcho};
+use crate::serialization::model_methods;
 
 /// Represents a bill that became a law
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct LawItem {
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub congress: Option<i32>,
     
     #[pyo3(get)]
@@ -22,7 +27,7 @@ pub struct LawItem {
     
     #[pyo3(get)]
     #[serde(rename = "originChamber")]
-    pub origin_chamber: Option<String>,
+    pub origin_chamber: Option<Code<Chamber>>,
     
     #[pyo3(get)]
     #[serde(rename = "originChamberCode")]
@@ -33,34 +38,40 @@ pub struct LawItem {
     
     #[pyo3(get)]
     #[serde(rename = "type")]
-    pub law_type: Option<String>,
+    pub law_type: Option<Code<BillType>>,
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "updateDateIncludingText")]
-    pub update_date_including_text: Option<String>,
+    pub update_date_including_text: Option<ApiDate>,
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl LawItem {
+model_methods!(raw LawItem {
     fn __repr__(&self) -> String {
         format!(
             "LawItem(congress={:?}, type={:?}, number={:?}, title={:?})",
             self.congress, self.law_type, self.number, self.title
         )
     }
-}
+});
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct LawDetail {
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub congress: Option<i32>,
     
     #[pyo3(get)]
@@ -75,7 +86,7 @@ pub struct LawDetail {
     
     #[pyo3(get)]
     #[serde(rename = "originChamber")]
-    pub origin_chamber: Option<String>,
+    pub origin_chamber: Option<Code<Chamber>>,
     
     #[pyo3(get)]
     #[serde(rename = "originChamberCode")]
@@ -86,36 +97,45 @@ pub struct LawDetail {
     
     #[pyo3(get)]
     #[serde(rename = "type")]
-    pub law_type: Option<String>,
+    pub law_type: Option<Code<BillType>>,
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "updateDateIncludingText")]
-    pub update_date_including_text: Option<String>,
+    pub update_date_including_text: Option<ApiDate>,
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl LawDetail {
+model_methods!(raw LawDetail {
     fn __repr__(&self) -> String {
         format!(
             "LawDetail(congress={:?}, type={:?}, number={:?}, title={:?})",
             self.congress, self.law_type, self.number, self.title
         )
     }
-}
+});
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LawsResponse {
+    #[serde(deserialize_with = "crate::raw::list")]
     pub bills: Vec<LawItem>,
+    pub pagination: Option<Pagination>,
+    pub request: Option<RequestEcho>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LawDetailResponse {
+    #[serde(deserialize_with = "crate::raw::one")]
     pub bill: LawDetail,
 }
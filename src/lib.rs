@@ -1,7 +1,18 @@
 use pyo3::prelude::*;
 
+mod arrow;
+mod async_client;
+mod cache;
+mod cassette;
+mod citations;
 mod client;
+mod config;
+mod dates;
+mod endpoints;
+mod enums;
+mod errors;
 mod bills;
+mod amendments;
 mod members;
 mod sessions;
 mod house_votes;
@@ -9,12 +20,29 @@ mod committees;
 mod nominations;
 mod treaties;
 mod hearings;
+mod committee_meetings;
+mod communications;
+mod house_requirements;
 mod congressional_record;
 mod laws;
 mod summaries;
 mod crsreport;
+mod pagination;
+mod raw;
+mod rate_limit;
+mod redact;
+mod retry;
+mod serialization;
+mod xml;
 
+use async_client::AsyncCDGPythonClient;
+use cache::CacheStats;
+use citations::{AmendmentId, BillId, LawId, ReportId};
 use client::CDGPythonClient;
+use enums::{AmendmentType, BillType, Chamber, CommunicationType, LawType, PartyCode, ReportType, VoteCast};
+use pagination::{AsyncPageIterator, Page, PageIterator};
+use rate_limit::RateLimitStatus;
+use retry::RetryStats;
 
 use bills::{
     Action, Amendment, Bill, BillDetail, Committee, Cosponsor,
@@ -22,6 +50,8 @@ use bills::{
     Subject, Summary, TextFormat, TextVersion, Title,
 };
 
+use amendments::{AmendedBill, AmendedTreaty, AmendmentDetail};
+
 use members::Sponsor;
 
 use sessions::{
@@ -42,6 +72,15 @@ use committees::{
 use nominations::{Nomination, Nominee};
 use treaties::Treaty;
 use hearings::{AssociatedMeeting, Hearing, HearingCommittee, HearingDate, HearingFormat};
+use committee_meetings::{
+    CommitteeMeeting, CommitteeMeetingDetail, MeetingBill, MeetingDocument, MeetingLocation,
+    MeetingNomination, MeetingRelatedItems, MeetingTreaty, MeetingVideo, Witness, WitnessDocument,
+};
+use communications::{
+    Communication, CommunicationCommittee, CommunicationDetail, CommunicationTypeInfo,
+    MatchingRequirement,
+};
+use house_requirements::HouseRequirement;
 use congressional_record::DailyCongressionalRecord;
 use laws::{LawDetail, LawItem};
 use summaries::SummaryItem;
@@ -52,6 +91,35 @@ use crsreport::{CrsReport, CrsReportAuthor, CrsReportDetail, CrsReportFormat, Cr
 fn cdg_python_client(m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Add the main client
     m.add_class::<CDGPythonClient>()?;
+    m.add_class::<AsyncCDGPythonClient>()?;
+    errors::register(m)?;
+    m.add_class::<RetryStats>()?;
+    m.add_class::<RateLimitStatus>()?;
+    m.add_class::<CacheStats>()?;
+    m.add_class::<Page>()?;
+    m.add_class::<PageIterator>()?;
+    m.add_class::<AsyncPageIterator>()?;
+    
+    // Add table export helpers
+    m.add_function(wrap_pyfunction!(arrow::to_columns, m)?)?;
+    m.add_function(wrap_pyfunction!(arrow::to_arrow, m)?)?;
+    m.add_function(wrap_pyfunction!(arrow::to_pandas, m)?)?;
+    
+    // Add enums for codes used in paths and responses
+    enums::add::<BillType>(m)?;
+    enums::add::<AmendmentType>(m)?;
+    enums::add::<Chamber>(m)?;
+    enums::add::<LawType>(m)?;
+    enums::add::<VoteCast>(m)?;
+    enums::add::<PartyCode>(m)?;
+    enums::add::<ReportType>(m)?;
+    enums::add::<CommunicationType>(m)?;
+    
+    // Add citation ids
+    m.add_class::<BillId>()?;
+    m.add_class::<LawId>()?;
+    m.add_class::<AmendmentId>()?;
+    m.add_class::<ReportId>()?;
     
     // Add data structures
     m.add_class::<Bill>()?;
@@ -72,6 +140,11 @@ fn cdg_python_client(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<TextFormat>()?;
     m.add_class::<Title>()?;
     
+    // Add amendment-related structures
+    m.add_class::<AmendmentDetail>()?;
+    m.add_class::<AmendedBill>()?;
+    m.add_class::<AmendedTreaty>()?;
+    
     // Add session-related structures
     m.add_class::<Congress>()?;
     m.add_class::<Session>()?;
@@ -113,6 +186,29 @@ fn cdg_python_client(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<HearingCommittee>()?;
     m.add_class::<HearingDate>()?;
     
+    // Add committee meeting structures
+    m.add_class::<CommitteeMeeting>()?;
+    m.add_class::<CommitteeMeetingDetail>()?;
+    m.add_class::<MeetingLocation>()?;
+    m.add_class::<Witness>()?;
+    m.add_class::<WitnessDocument>()?;
+    m.add_class::<MeetingDocument>()?;
+    m.add_class::<MeetingVideo>()?;
+    m.add_class::<MeetingRelatedItems>()?;
+    m.add_class::<MeetingBill>()?;
+    m.add_class::<MeetingNomination>()?;
+    m.add_class::<MeetingTreaty>()?;
+    
+    // Add House and Senate communication structures
+    m.add_class::<Communication>()?;
+    m.add_class::<CommunicationDetail>()?;
+    m.add_class::<CommunicationTypeInfo>()?;
+    m.add_class::<CommunicationCommittee>()?;
+    m.add_class::<MatchingRequirement>()?;
+    
+    // Add House requirement structures
+    m.add_class::<HouseRequirement>()?;
+    
     // Add congressional record structures
     m.add_class::<DailyCongressionalRecord>()?;
     
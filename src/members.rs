@@ -2,10 +2,12 @@ use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::bills::Bill;
+use crate::pagination::{Pagination, RequestEcho};
+use crate::serialization::model_methods;
 
 /// Represents a congressional member (used as Sponsor in bills)
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct Sponsor {
     #[pyo3(get)]
     #[serde(rename = "bioguideId")]
@@ -31,27 +33,36 @@ pub struct Sponsor {
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl Sponsor {
+model_methods!(raw Sponsor {
     fn __repr__(&self) -> String {
         format!(
             "Sponsor(name={:?}, party={:?}, state={:?})",
             self.full_name, self.party, self.state
         )
     }
-}
+});
 
 /// Response structure for list of members
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MembersResponse {
+    #[serde(deserialize_with = "crate::raw::list")]
     pub members: Vec<Sponsor>,
+    pub pagination: Option<Pagination>,
+    pub request: Option<RequestEcho>,
 }
 
 /// Response structure for a single member
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MemberResponse {
+    #[serde(deserialize_with = "crate::raw::one")]
     pub member: Sponsor,
 }
 
@@ -59,6 +70,7 @@ pub struct MemberResponse {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SponsoredLegislationResponse {
     #[serde(rename = "sponsoredLegislation")]
+    #[serde(deserialize_with = "crate::raw::list")]
     pub sponsored_legislation: Vec<Bill>,
 }
 
@@ -66,5 +78,6 @@ pub struct SponsoredLegislationResponse {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CosponsoredLegislationResponse {
     #[serde(rename = "cosponsoredLegislation")]
+    #[serde(deserialize_with = "crate::raw::list")]
     pub cosponsored_legislation: Vec<Bill>,
 }
@@ -1,11 +1,16 @@
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::dates::ApiDate;
+use crate::pagination::{Pagination, RequestEcho};
+use crate::serialization::model_methods;
+
 /// Represents a nomination
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct Nomination {
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub congress: Option<i32>,
     
     #[pyo3(get)]
@@ -23,29 +28,34 @@ pub struct Nomination {
     
     #[pyo3(get)]
     #[serde(rename = "receivedDate")]
-    pub received_date: Option<String>,
+    pub received_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl Nomination {
+model_methods!(raw Nomination {
     fn __repr__(&self) -> String {
         format!(
             "Nomination(congress={:?}, number={:?}, citation={:?})",
             self.congress, self.number, self.citation
         )
     }
-}
+});
 
 /// Represents a nominee
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct Nominee {
     #[pyo3(get)]
     #[serde(rename = "firstName")]
@@ -63,26 +73,36 @@ pub struct Nominee {
     
     #[pyo3(get)]
     pub state: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl Nominee {
+model_methods!(raw Nominee {
     fn __repr__(&self) -> String {
         format!("Nominee(name={:?}, position={:?})", self.name, self.position)
     }
-}
+});
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NominationsResponse {
+    #[serde(deserialize_with = "crate::raw::list")]
     pub nominations: Vec<Nomination>,
+    pub pagination: Option<Pagination>,
+    pub request: Option<RequestEcho>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NominationDetailResponse {
+    #[serde(deserialize_with = "crate::raw::one")]
     pub nomination: Nomination,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NomineesResponse {
+    #[serde(deserialize_with = "crate::raw::list")]
     pub nominees: Vec<Nominee>,
 }
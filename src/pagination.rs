@@ -0,0 +1,482 @@
+use pyo3::exceptions::{PyStopAsyncIteration, PyValueError};
+use pyo3::prelude::*;
+use pyo3::pyclass::PyClass;
+use pyo3::pyclass_init::PyClassInitializer;
+use pyo3::types::PyList;
+use pyo3_async_runtimes::tokio::future_into_py;
+use reqwest::Url;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::bills::{Amendment, AmendmentsResponse, Bill, BillsResponse};
+use crate::client::CongressApiClient;
+use crate::committee_meetings::{CommitteeMeeting, CommitteeMeetingsResponse};
+use crate::communications::{Communication, CommunicationsResponse};
+use crate::house_requirements::{HouseRequirement, HouseRequirementsResponse};
+use crate::committees::{
+    CommitteeItem, CommitteePrintItem, CommitteePrintsResponse, CommitteeReportItem,
+    CommitteeReportsResponse, CommitteesResponse,
+};
+use crate::congressional_record::{DailyCongressionalRecord, DailyCongressionalRecordsResponse};
+use crate::crsreport::{CrsReport, CrsReportsResponse};
+use crate::hearings::{Hearing, HearingsResponse};
+use crate::house_votes::{HouseVote, HouseVotesResponse};
+use crate::laws::{LawItem, LawsResponse};
+use crate::members::{MembersResponse, Sponsor};
+use crate::nominations::{Nomination, NominationsResponse};
+use crate::sessions::{Congress, CongressesResponse};
+use crate::summaries::{SummariesListResponse, SummaryItem};
+use crate::treaties::{TreatiesResponse, Treaty};
+
+/// Largest page the API serves
+pub const MAX_PAGE_SIZE: u32 = 250;
+
+/// The `pagination` block of a list response
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Pagination {
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
+    pub count: Option<u64>,
+    pub next: Option<String>,
+    pub prev: Option<String>,
+}
+
+/// Value of the `offset` query parameter of a pagination link
+fn link_offset(link: &str) -> Option<u64> {
+    let url = Url::parse(link).ok()?;
+    let offset = url.query_pairs().find(|(key, _)| key == "offset")?.1;
+    offset.parse().ok()
+}
+
+/// The `request` block echoing the parameters the API saw. Values are kept
+/// as strings; numbers and booleans are converted, nulls dropped.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RequestEcho(pub HashMap<String, String>);
+
+impl<'de> Deserialize<'de> for RequestEcho {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = serde_json::Map::<String, serde_json::Value>::deserialize(deserializer)?;
+        let echo = fields
+            .into_iter()
+            .filter_map(|(key, value)| match value {
+                serde_json::Value::Null => None,
+                serde_json::Value::String(s) => Some((key, s)),
+                other => Some((key, other.to_string())),
+            })
+            .collect();
+        Ok(RequestEcho(echo))
+    }
+}
+
+/// One page of a list response, split into its parts
+pub struct ListPage<T> {
+    pub items: Vec<T>,
+    pub pagination: Option<Pagination>,
+    pub request: Option<RequestEcho>,
+}
+
+impl<T> ListPage<T> {
+    /// Total number of matching items, falling back to the page length
+    /// for endpoints that do not paginate
+    pub fn total(&self) -> u64 {
+        self.pagination
+            .as_ref()
+            .and_then(|p| p.count)
+            .unwrap_or(self.items.len() as u64)
+    }
+}
+
+/// A list response: one page of items plus the pagination block
+pub trait Paginated: DeserializeOwned + Send {
+    type Item: PyClass + Into<PyClassInitializer<Self::Item>>;
+
+    fn into_page(self) -> ListPage<Self::Item>;
+}
+
+macro_rules! impl_paginated {
+    ($($response:ty => $field:ident: $item:ty),* $(,)?) => {
+        $(
+            impl Paginated for $response {
+                type Item = $item;
+
+                fn into_page(self) -> ListPage<$item> {
+                    ListPage {
+                        items: self.$field,
+                        pagination: self.pagination,
+                        request: self.request,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_paginated! {
+    BillsResponse => bills: Bill,
+    AmendmentsResponse => amendments: Amendment,
+    MembersResponse => members: Sponsor,
+    CommitteesResponse => committees: CommitteeItem,
+    CongressesResponse => congresses: Congress,
+    HouseVotesResponse => votes: HouseVote,
+    CommitteeReportsResponse => reports: CommitteeReportItem,
+    CommitteePrintsResponse => committee_prints: CommitteePrintItem,
+    NominationsResponse => nominations: Nomination,
+    TreatiesResponse => treaties: Treaty,
+    HearingsResponse => hearings: Hearing,
+    CommitteeMeetingsResponse => committee_meetings: CommitteeMeeting,
+    CommunicationsResponse => communications: Communication,
+    HouseRequirementsResponse => house_requirements: HouseRequirement,
+    DailyCongressionalRecordsResponse => daily_congressional_record: DailyCongressionalRecord,
+    LawsResponse => bills: LawItem,
+    SummariesListResponse => summaries: SummaryItem,
+    CrsReportsResponse => crs_reports: CrsReport,
+}
+
+/// Fetches one page and converts its items to Python objects
+pub type PageFetcher = fn(Python<'_>, &CongressApiClient, &str, HashMap<String, String>) -> PyResult<Page>;
+
+/// Async counterpart of `PageFetcher`
+pub type AsyncPageFetcher =
+    fn(Arc<CongressApiClient>, String, HashMap<String, String>) -> Pin<Box<dyn Future<Output = PyResult<Page>> + Send>>;
+
+/// Convert a decoded list response into a `Page` of Python objects
+fn to_page<R: Paginated>(py: Python<'_>, response: R) -> PyResult<Page> {
+    let page = response.into_page();
+    let items = page
+        .items
+        .into_iter()
+        .map(|item| Ok(Py::new(py, item)?.into_any()))
+        .collect::<PyResult<Vec<_>>>()?;
+    let pagination = page.pagination.unwrap_or_default();
+
+    Ok(Page {
+        items,
+        count: pagination.count,
+        next: pagination.next,
+        prev: pagination.prev,
+        request: page.request.map(|r| r.0).unwrap_or_default(),
+    })
+}
+
+/// `PageFetcher` for the list response type `R`
+pub fn fetch_page<R: Paginated>(
+    py: Python<'_>,
+    client: &CongressApiClient,
+    endpoint: &str,
+    params: HashMap<String, String>,
+) -> PyResult<Page> {
+    let response: R = client.get(py, endpoint, Some(params))?;
+    to_page(py, response)
+}
+
+/// `AsyncPageFetcher` for the list response type `R`
+pub fn fetch_page_async<R: Paginated>(
+    client: Arc<CongressApiClient>,
+    endpoint: String,
+    params: HashMap<String, String>,
+) -> Pin<Box<dyn Future<Output = PyResult<Page>> + Send>> {
+    Box::pin(async move {
+        let response: R = client.get_async(&endpoint, Some(params)).await?;
+        Python::with_gil(|py| to_page(py, response))
+    })
+}
+
+/// Total number of items a list endpoint matches, fetched with `limit=1`
+pub async fn count_items_async<R: Paginated>(
+    client: Arc<CongressApiClient>,
+    endpoint: &str,
+    mut params: HashMap<String, String>,
+) -> PyResult<u64> {
+    params.insert("limit".to_string(), "1".to_string());
+    let response: R = client.get_async(endpoint, Some(params)).await?;
+    Ok(response.into_page().total())
+}
+
+/// One page of a list endpoint together with its pagination metadata
+#[pyclass]
+pub struct Page {
+    #[pyo3(get)]
+    pub items: Vec<PyObject>,
+
+    /// Total number of matching items across all pages
+    #[pyo3(get)]
+    pub count: Option<u64>,
+
+    /// URL of the next page, if any
+    #[pyo3(get)]
+    pub next: Option<String>,
+
+    /// URL of the previous page, if any
+    #[pyo3(get)]
+    pub prev: Option<String>,
+
+    /// Parameters echoed back by the API in its `request` block
+    #[pyo3(get)]
+    pub request: HashMap<String, String>,
+}
+
+#[pymethods]
+impl Page {
+    /// Offset to pass to fetch the next page, if there is one
+    #[getter]
+    fn next_offset(&self) -> Option<u64> {
+        self.next.as_deref().and_then(link_offset)
+    }
+
+    /// The items as a `pyarrow.RecordBatch`; see `to_arrow`
+    fn to_arrow<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        crate::arrow::to_arrow(py, PyList::new(py, &self.items)?.as_any())
+    }
+
+    /// The items as a `pandas.DataFrame`; see `to_pandas`
+    fn to_pandas<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        crate::arrow::to_pandas(py, PyList::new(py, &self.items)?.as_any())
+    }
+
+    fn __len__(&self) -> usize {
+        self.items.len()
+    }
+
+    fn __iter__(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let items = PyList::new(py, &self.items)?;
+        Ok(items.try_iter()?.into_any().unbind())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Page(items={}, count={:?}, next={:?})",
+            self.items.len(),
+            self.count,
+            self.next
+        )
+    }
+}
+
+/// Progress through a list endpoint: which page to request next and the
+/// items fetched but not yet yielded
+struct PageCursor {
+    endpoint: String,
+    params: HashMap<String, String>,
+    buffer: VecDeque<PyObject>,
+    /// Offset of the next page to request; `None` once the last page is in
+    next_offset: Option<u64>,
+    page_size: u32,
+    /// Items still to be yielded under `max_items`
+    remaining: Option<usize>,
+    pages_fetched: u64,
+}
+
+impl PageCursor {
+    fn new(
+        endpoint: String,
+        params: HashMap<String, String>,
+        offset: Option<u64>,
+        page_size: Option<u32>,
+        max_items: Option<usize>,
+    ) -> PyResult<Self> {
+        let page_size = page_size.unwrap_or(MAX_PAGE_SIZE);
+        if !(1..=MAX_PAGE_SIZE).contains(&page_size) {
+            return Err(PyValueError::new_err(format!(
+                "page_size must be between 1 and {}",
+                MAX_PAGE_SIZE
+            )));
+        }
+
+        Ok(Self {
+            endpoint,
+            params,
+            buffer: VecDeque::new(),
+            next_offset: Some(offset.unwrap_or(0)),
+            page_size,
+            remaining: max_items,
+            pages_fetched: 0,
+        })
+    }
+
+    /// Whether `max_items` have been yielded
+    fn is_done(&self) -> bool {
+        self.remaining == Some(0)
+    }
+
+    /// Offset and query parameters of the next page, if there is one
+    fn next_request(&self) -> Option<(u64, HashMap<String, String>)> {
+        let offset = self.next_offset?;
+        // Don't ask for more than max_items still allows
+        let limit = match self.remaining {
+            Some(remaining) => self.page_size.min(u32::try_from(remaining).unwrap_or(u32::MAX)),
+            None => self.page_size,
+        };
+
+        let mut params = self.params.clone();
+        params.insert("offset".to_string(), offset.to_string());
+        params.insert("limit".to_string(), limit.to_string());
+        Some((offset, params))
+    }
+
+    /// Buffer the items of the page requested at `offset`
+    fn absorb(&mut self, offset: u64, page: Page) {
+        self.pages_fetched += 1;
+
+        // Follow the server's `next` link; fall back to counting items if
+        // the link has no usable offset, and stop on an empty page
+        let fetched = page.items.len() as u64;
+        self.next_offset = if page.next.is_some() && fetched > 0 {
+            Some(page.next_offset().filter(|next| *next > offset).unwrap_or(offset + fetched))
+        } else {
+            None
+        };
+        self.buffer.extend(page.items);
+    }
+
+    /// Take the next buffered item, counting it against `max_items`
+    fn pop(&mut self) -> Option<PyObject> {
+        let item = self.buffer.pop_front()?;
+        if let Some(remaining) = self.remaining.as_mut() {
+            *remaining -= 1;
+        }
+        Some(item)
+    }
+}
+
+/// Iterator over every item of a list endpoint, fetching pages lazily
+#[pyclass]
+pub struct PageIterator {
+    client: Arc<CongressApiClient>,
+    fetch: PageFetcher,
+    cursor: PageCursor,
+}
+
+impl PageIterator {
+    pub fn new(
+        client: Arc<CongressApiClient>,
+        endpoint: String,
+        params: HashMap<String, String>,
+        offset: Option<u64>,
+        page_size: Option<u32>,
+        max_items: Option<usize>,
+        fetch: PageFetcher,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            client,
+            fetch,
+            cursor: PageCursor::new(endpoint, params, offset, page_size, max_items)?,
+        })
+    }
+}
+
+#[pymethods]
+impl PageIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        if self.cursor.is_done() {
+            return Ok(None);
+        }
+        while self.cursor.buffer.is_empty() {
+            let Some((offset, params)) = self.cursor.next_request() else {
+                return Ok(None);
+            };
+            let page = (self.fetch)(py, &self.client, &self.cursor.endpoint, params)?;
+            self.cursor.absorb(offset, page);
+        }
+
+        Ok(self.cursor.pop())
+    }
+
+    /// Number of pages requested so far
+    #[getter]
+    fn pages_fetched(&self) -> u64 {
+        self.cursor.pages_fetched
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "PageIterator(endpoint='{}', pages_fetched={})",
+            self.cursor.endpoint, self.cursor.pages_fetched
+        )
+    }
+}
+
+/// Async iterator over every item of a list endpoint, fetching pages lazily
+#[pyclass]
+pub struct AsyncPageIterator {
+    client: Arc<CongressApiClient>,
+    fetch: AsyncPageFetcher,
+    endpoint: String,
+    /// Locked for the whole of a `__anext__`, so concurrent calls take turns
+    cursor: Arc<Mutex<PageCursor>>,
+    /// Mirrors the cursor's count so it can be read mid-fetch
+    pages_fetched: Arc<AtomicU64>,
+}
+
+impl AsyncPageIterator {
+    pub fn new(
+        client: Arc<CongressApiClient>,
+        endpoint: String,
+        params: HashMap<String, String>,
+        offset: Option<u64>,
+        page_size: Option<u32>,
+        max_items: Option<usize>,
+        fetch: AsyncPageFetcher,
+    ) -> PyResult<Self> {
+        let cursor = PageCursor::new(endpoint.clone(), params, offset, page_size, max_items)?;
+        Ok(Self {
+            client,
+            fetch,
+            endpoint,
+            cursor: Arc::new(Mutex::new(cursor)),
+            pages_fetched: Arc::new(AtomicU64::new(0)),
+        })
+    }
+}
+
+#[pymethods]
+impl AsyncPageIterator {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = Arc::clone(&self.client);
+        let cursor = Arc::clone(&self.cursor);
+        let pages_fetched = Arc::clone(&self.pages_fetched);
+        let fetch = self.fetch;
+        future_into_py(py, async move {
+            let mut cursor = cursor.lock().await;
+            if cursor.is_done() {
+                return Err(PyStopAsyncIteration::new_err(()));
+            }
+            while cursor.buffer.is_empty() {
+                let Some((offset, params)) = cursor.next_request() else {
+                    return Err(PyStopAsyncIteration::new_err(()));
+                };
+                let page = fetch(Arc::clone(&client), cursor.endpoint.clone(), params).await?;
+                cursor.absorb(offset, page);
+                pages_fetched.store(cursor.pages_fetched, Ordering::Relaxed);
+            }
+
+            cursor.pop().ok_or_else(|| PyStopAsyncIteration::new_err(()))
+        })
+    }
+
+    /// Number of pages requested so far
+    #[getter]
+    fn pages_fetched(&self) -> u64 {
+        self.pages_fetched.load(Ordering::Relaxed)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "AsyncPageIterator(endpoint='{}', pages_fetched={})",
+            self.endpoint,
+            self.pages_fetched()
+        )
+    }
+}
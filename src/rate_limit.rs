@@ -0,0 +1,201 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use reqwest::header::HeaderMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+const LIMIT_HEADER: &str = "x-ratelimit-limit";
+const REMAINING_HEADER: &str = "x-ratelimit-remaining";
+
+/// What the limiter does when the local budget is used up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimiterMode {
+    /// Sleep until a token becomes available
+    Block,
+    /// Fail the request immediately
+    Raise,
+}
+
+impl LimiterMode {
+    pub fn parse(mode: &str) -> PyResult<Self> {
+        match mode.to_ascii_lowercase().as_str() {
+            "block" => Ok(Self::Block),
+            "raise" => Ok(Self::Raise),
+            other => Err(PyValueError::new_err(format!(
+                "rate_limit_mode must be 'block' or 'raise', got {:?}",
+                other
+            ))),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// Client-side token bucket allowing `capacity` requests per `period`.
+///
+/// The bucket starts full and refills continuously, so a fresh client may
+/// burst up to the whole budget and is then held to the average rate.
+#[derive(Debug)]
+pub struct TokenBucket {
+    capacity: f64,
+    refill_per_sec: f64,
+    mode: LimiterMode,
+    state: Mutex<Bucket>,
+}
+
+impl TokenBucket {
+    pub fn new(capacity: u32, period: Duration, mode: LimiterMode) -> Self {
+        let capacity = f64::from(capacity);
+        Self {
+            capacity,
+            refill_per_sec: capacity / period.as_secs_f64(),
+            mode,
+            state: Mutex::new(Bucket {
+                tokens: capacity,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    fn refill(&self, bucket: &mut Bucket) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        bucket.last_refill = now;
+    }
+
+    /// Take one token, waiting for it in `Block` mode.
+    ///
+    /// In `Raise` mode returns the time until the next token instead.
+    pub async fn acquire(&self) -> Result<(), Duration> {
+        loop {
+            let wait = {
+                let mut bucket = self.state.lock().unwrap_or_else(|e| e.into_inner());
+                self.refill(&mut bucket);
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return Ok(());
+                }
+                // A period near `Duration::MAX` can round just past it
+                Duration::try_from_secs_f64((1.0 - bucket.tokens) / self.refill_per_sec)
+                    .unwrap_or(Duration::MAX)
+            };
+
+            match self.mode {
+                LimiterMode::Block => tokio::time::sleep(wait).await,
+                LimiterMode::Raise => return Err(wait),
+            }
+        }
+    }
+
+    pub fn capacity(&self) -> u64 {
+        self.capacity as u64
+    }
+
+    pub fn available(&self) -> u64 {
+        let mut bucket = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        self.refill(&mut bucket);
+        bucket.tokens.floor() as u64
+    }
+}
+
+#[derive(Debug, Default)]
+struct Quota {
+    limit: Option<u64>,
+    remaining: Option<u64>,
+    updated_at: Option<Instant>,
+}
+
+/// Tracks the api.data.gov quota headers seen on responses
+#[derive(Debug, Default)]
+pub struct QuotaTracker {
+    requests_sent: AtomicU64,
+    quota: Mutex<Quota>,
+}
+
+impl QuotaTracker {
+    pub fn record_request(&self) {
+        self.requests_sent.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Update from `X-RateLimit-Limit`/`X-RateLimit-Remaining` when present
+    pub fn update(&self, headers: &HeaderMap) {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        };
+
+        let limit = header(LIMIT_HEADER);
+        let remaining = header(REMAINING_HEADER);
+        if limit.is_none() && remaining.is_none() {
+            return;
+        }
+
+        let mut quota = self.quota.lock().unwrap_or_else(|e| e.into_inner());
+        if limit.is_some() {
+            quota.limit = limit;
+        }
+        if remaining.is_some() {
+            quota.remaining = remaining;
+        }
+        quota.updated_at = Some(Instant::now());
+    }
+
+    pub fn status(&self, limiter: Option<&TokenBucket>) -> RateLimitStatus {
+        let quota = self.quota.lock().unwrap_or_else(|e| e.into_inner());
+        RateLimitStatus {
+            limit: quota.limit,
+            remaining: quota.remaining,
+            seconds_since_update: quota.updated_at.map(|t| t.elapsed().as_secs_f64()),
+            requests_sent: self.requests_sent.load(Ordering::Relaxed),
+            budget: limiter.map(TokenBucket::capacity),
+            budget_remaining: limiter.map(TokenBucket::available),
+        }
+    }
+}
+
+/// Quota reported by the server plus the state of the optional local limiter
+#[derive(Debug, Clone)]
+#[pyclass]
+pub struct RateLimitStatus {
+    /// Hourly request limit from the last `X-RateLimit-Limit` header
+    #[pyo3(get)]
+    pub limit: Option<u64>,
+
+    /// Requests left this hour from the last `X-RateLimit-Remaining` header
+    #[pyo3(get)]
+    pub remaining: Option<u64>,
+
+    /// Age of `limit`/`remaining` in seconds (None until a response carried them)
+    #[pyo3(get)]
+    pub seconds_since_update: Option<f64>,
+
+    /// HTTP requests sent by this client, retries included
+    #[pyo3(get)]
+    pub requests_sent: u64,
+
+    /// Configured local budget per period (None when the limiter is off)
+    #[pyo3(get)]
+    pub budget: Option<u64>,
+
+    /// Requests the local limiter would allow right now
+    #[pyo3(get)]
+    pub budget_remaining: Option<u64>,
+}
+
+#[pymethods]
+impl RateLimitStatus {
+    fn __repr__(&self) -> String {
+        format!(
+            "RateLimitStatus(limit={:?}, remaining={:?}, requests_sent={}, budget_remaining={:?})",
+            self.limit, self.remaining, self.requests_sent, self.budget_remaining
+        )
+    }
+}
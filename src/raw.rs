@@ -0,0 +1,213 @@
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDate, PyDict, PyFloat, PyInt, PyList, PyString, PyTuple};
+use serde::de::{DeserializeOwned, Error};
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Convert parsed JSON into native Python objects (dict, list, str, int,
+/// float, bool, None)
+pub fn to_py(py: Python<'_>, value: &Value) -> PyResult<PyObject> {
+    Ok(match value {
+        Value::Null => py.None(),
+        Value::Bool(b) => PyBool::new(py, *b).to_owned().into_any().unbind(),
+        Value::Number(n) => match (n.as_i64(), n.as_u64()) {
+            (Some(i), _) => i.into_pyobject(py)?.into_any().unbind(),
+            (None, Some(u)) => u.into_pyobject(py)?.into_any().unbind(),
+            _ => n.as_f64().unwrap_or(f64::NAN).into_pyobject(py)?.into_any().unbind(),
+        },
+        Value::String(s) => s.into_pyobject(py)?.into_any().unbind(),
+        Value::Array(items) => {
+            let items = items.iter().map(|item| to_py(py, item)).collect::<PyResult<Vec<_>>>()?;
+            PyList::new(py, items)?.into_any().unbind()
+        }
+        Value::Object(fields) => object_to_py(py, fields)?,
+    })
+}
+
+/// Convert JSON-compatible Python objects back into JSON. Dates and
+/// datetimes become ISO 8601 strings.
+pub fn from_py(value: &Bound<'_, PyAny>) -> PyResult<Value> {
+    if value.is_none() {
+        return Ok(Value::Null);
+    }
+    // bool is a subclass of int, so it has to be checked first
+    if let Ok(b) = value.downcast::<PyBool>() {
+        return Ok(Value::Bool(b.is_true()));
+    }
+    if value.is_instance_of::<PyInt>() {
+        return match value.extract::<i64>() {
+            Ok(i) => Ok(Value::from(i)),
+            Err(_) => Ok(Value::from(value.extract::<u64>()?)),
+        };
+    }
+    if let Ok(f) = value.downcast::<PyFloat>() {
+        return serde_json::Number::from_f64(f.value())
+            .map(Value::Number)
+            .ok_or_else(|| PyValueError::new_err(format!("{} is not valid JSON", f.value())));
+    }
+    if let Ok(s) = value.downcast::<PyString>() {
+        return Ok(Value::String(s.to_str()?.to_string()));
+    }
+    if value.is_instance_of::<PyDate>() {
+        return Ok(Value::String(value.call_method0("isoformat")?.extract()?));
+    }
+    if let Ok(dict) = value.downcast::<PyDict>() {
+        let mut fields = Map::new();
+        for (key, field) in dict.iter() {
+            let key = key.downcast_into::<PyString>().map_err(|_| {
+                PyTypeError::new_err("dict keys must be strings")
+            })?;
+            fields.insert(key.to_str()?.to_string(), from_py(&field)?);
+        }
+        return Ok(Value::Object(fields));
+    }
+    if value.is_instance_of::<PyList>() || value.is_instance_of::<PyTuple>() {
+        return value.try_iter()?.map(|item| from_py(&item?)).collect::<PyResult<Vec<_>>>().map(Value::Array);
+    }
+    Err(PyTypeError::new_err(format!(
+        "cannot convert {} to JSON",
+        value.get_type().name()?
+    )))
+}
+
+/// The `raw` getter on typed results
+pub fn raw_to_py(py: Python<'_>, raw: Option<&Value>) -> PyResult<PyObject> {
+    raw.map_or_else(|| Ok(py.None()), |value| to_py(py, value))
+}
+
+/// A JSON object as a dict; also backs the `extra` getter on models
+pub fn object_to_py(py: Python<'_>, extra: &Map<String, Value>) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+    for (key, field) in extra {
+        dict.set_item(key, to_py(py, field)?)?;
+    }
+    Ok(dict.into_any().unbind())
+}
+
+/// Normalize a `get_raw` path: relative to the base URL, leading `/`, and
+/// no query string (parameters go in `params`)
+pub fn endpoint(path: &str) -> PyResult<String> {
+    if path.contains("://") {
+        return Err(PyValueError::new_err(format!(
+            "path must be relative to base_url, e.g. '/bill/118', got {:?}",
+            path
+        )));
+    }
+    if path.contains(['?', '#']) {
+        return Err(PyValueError::new_err("path must not contain a query string; pass params instead"));
+    }
+    Ok(format!("/{}", path.trim_start_matches('/')))
+}
+
+/// Render `get_raw` parameters as query strings: booleans as `true`/`false`,
+/// `None` values dropped, anything else via `str()`
+pub fn query_params(params: Option<HashMap<String, Bound<'_, PyAny>>>) -> PyResult<HashMap<String, String>> {
+    let mut query = HashMap::new();
+    for (key, value) in params.unwrap_or_default() {
+        if value.is_none() {
+            continue;
+        }
+        let rendered = match value.downcast::<PyBool>() {
+            Ok(b) => b.is_true().to_string(),
+            Err(_) => value.str()?.to_string(),
+        };
+        query.insert(key, rendered);
+    }
+    Ok(query)
+}
+
+/// Models that keep the JSON object they were decoded from
+pub trait WithRaw: DeserializeOwned {
+    fn set_raw(&mut self, raw: Value);
+}
+
+macro_rules! impl_with_raw {
+    ($($model:ty),* $(,)?) => {
+        $(
+            impl WithRaw for $model {
+                fn set_raw(&mut self, raw: Value) {
+                    self.raw = Some(raw);
+                }
+            }
+        )*
+    };
+}
+
+impl_with_raw!(
+    crate::bills::Bill,
+    crate::bills::BillDetail,
+    crate::bills::Action,
+    crate::bills::Amendment,
+    crate::bills::Committee,
+    crate::bills::Cosponsor,
+    crate::bills::RelatedBill,
+    crate::bills::Subject,
+    crate::bills::PolicyArea,
+    crate::bills::Summary,
+    crate::bills::TextVersion,
+    crate::bills::Title,
+    crate::amendments::AmendmentDetail,
+    crate::committees::CommitteeItem,
+    crate::committees::CommitteeDetailInfo,
+    crate::committees::CommitteeBill,
+    crate::committees::CommitteeReportItem,
+    crate::committees::CommitteeReportDetail,
+    crate::committees::CommitteeReportText,
+    crate::committees::CommitteePrintItem,
+    crate::committees::CommitteePrintDetail,
+    crate::committees::CommitteePrintText,
+    crate::congressional_record::DailyCongressionalRecord,
+    crate::crsreport::CrsReport,
+    crate::crsreport::CrsReportDetail,
+    crate::hearings::Hearing,
+    crate::committee_meetings::CommitteeMeeting,
+    crate::committee_meetings::CommitteeMeetingDetail,
+    crate::communications::Communication,
+    crate::communications::CommunicationDetail,
+    crate::house_requirements::HouseRequirement,
+    crate::house_votes::HouseVote,
+    crate::house_votes::HouseVoteDetail,
+    crate::house_votes::HouseVoteMembers,
+    crate::laws::LawItem,
+    crate::laws::LawDetail,
+    crate::members::Sponsor,
+    crate::nominations::Nomination,
+    crate::nominations::Nominee,
+    crate::sessions::Congress,
+    crate::summaries::SummaryItem,
+    crate::treaties::Treaty,
+);
+
+/// Decode a model and keep the value it came from as its `raw`
+pub fn decode<T: WithRaw, E: Error>(value: Value) -> Result<T, E> {
+    let mut model = T::deserialize(&value).map_err(E::custom)?;
+    model.set_raw(value);
+    Ok(model)
+}
+
+// `deserialize_with` helpers for response fields holding models
+
+pub fn one<'de, D: Deserializer<'de>, T: WithRaw>(deserializer: D) -> Result<T, D::Error> {
+    decode(Value::deserialize(deserializer)?)
+}
+
+pub fn optional<'de, D: Deserializer<'de>, T: WithRaw>(deserializer: D) -> Result<Option<T>, D::Error> {
+    Option::<Value>::deserialize(deserializer)?.map(decode).transpose()
+}
+
+/// An empty list element in an XML response arrives as null
+pub fn list<'de, D: Deserializer<'de>, T: WithRaw>(deserializer: D) -> Result<Vec<T>, D::Error> {
+    Option::<Vec<Value>>::deserialize(deserializer)?
+        .unwrap_or_default()
+        .into_iter()
+        .map(decode)
+        .collect()
+}
+
+pub fn optional_list<'de, D: Deserializer<'de>, T: WithRaw>(deserializer: D) -> Result<Option<Vec<T>>, D::Error> {
+    Option::<Vec<Value>>::deserialize(deserializer)?
+        .map(|items| items.into_iter().map(decode).collect())
+        .transpose()
+}
@@ -0,0 +1,53 @@
+/// Placeholder substituted for secrets
+pub const REDACTED: &str = "REDACTED";
+
+/// Keys shorter than this are not replaced verbatim, since a short string
+/// could legitimately occur inside a response body
+const MIN_VERBATIM_KEY_LEN: usize = 8;
+
+/// Remove the API key from `text`.
+///
+/// Any `api_key=...` query parameter is blanked regardless of its value (this
+/// covers URLs the API echoes back), and the key itself is replaced wherever
+/// else it appears.
+pub fn scrub(text: &str, api_key: &str) -> String {
+    let mut scrubbed = scrub_query_param(text);
+    if api_key.len() >= MIN_VERBATIM_KEY_LEN && scrubbed.contains(api_key) {
+        scrubbed = scrubbed.replace(api_key, REDACTED);
+    }
+    scrubbed
+}
+
+fn scrub_query_param(text: &str) -> String {
+    const PARAM: &str = "api_key=";
+
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find(PARAM) {
+        let value_start = pos + PARAM.len();
+        out.push_str(&rest[..value_start]);
+        out.push_str(REDACTED);
+
+        let value_len = rest[value_start..]
+            .find(|c: char| {
+                matches!(c, '&' | '#' | '"' | '\'' | '<' | '>' | ')') || c.is_whitespace()
+            })
+            .unwrap_or(rest.len() - value_start);
+        rest = &rest[value_start + value_len..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Describe a transport error, following the chain of causes so the message
+/// says *why* the request failed, with the API key scrubbed out
+pub fn describe_error(error: &reqwest::Error, api_key: &str) -> String {
+    let mut message = error.to_string();
+    let mut source = std::error::Error::source(error);
+    while let Some(cause) = source {
+        message.push_str(": ");
+        message.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    scrub(&message, api_key)
+}
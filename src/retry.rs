@@ -0,0 +1,120 @@
+use pyo3::prelude::*;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::StatusCode;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Retry policy applied to every request made by `CongressApiClient`
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// Number of retries after the first attempt (0 disables retrying)
+    pub max_retries: u32,
+    /// Base delay in seconds; attempt `n` waits roughly `backoff_factor * 2^n`
+    pub backoff_factor: f64,
+    /// Upper bound for any single wait, including `Retry-After`
+    pub max_backoff: Duration,
+}
+
+impl RetryConfig {
+    /// 429 and the transient 5xx statuses are worth another attempt
+    pub fn is_retryable_status(&self, status: StatusCode) -> bool {
+        matches!(status.as_u16(), 429 | 500 | 502 | 503 | 504)
+    }
+
+    /// Connection failures and timeouts are retried; anything else (bad URL,
+    /// decode errors, ...) would fail the same way again
+    pub fn is_retryable_error(&self, error: &reqwest::Error) -> bool {
+        error.is_connect() || error.is_timeout()
+    }
+
+    /// Delay before retry number `attempt` (0-based).
+    ///
+    /// A server-provided `Retry-After` takes precedence over the computed
+    /// backoff. Returns `None` when the server asks us to wait longer than
+    /// `max_backoff`, in which case the caller should give up instead.
+    pub fn delay_for(&self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        if let Some(wait) = retry_after {
+            return if wait <= self.max_backoff { Some(wait) } else { None };
+        }
+
+        // Exponential backoff with "equal jitter": half the delay is fixed,
+        // the other half is random so that parallel crawlers spread out
+        let exp = self.backoff_factor * 2f64.powi(attempt.min(30) as i32);
+        let capped = exp.min(self.max_backoff.as_secs_f64());
+        let jittered = capped / 2.0 + fastrand::f64() * capped / 2.0;
+        // The f64 round trip can land just past a `max_backoff` near
+        // `Duration::MAX`
+        let wait = Duration::try_from_secs_f64(jittered).unwrap_or(self.max_backoff);
+        Some(wait.min(self.max_backoff))
+    }
+}
+
+/// Parse a `Retry-After` header given either as delay-seconds or an HTTP-date
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let wait = date.signed_duration_since(chrono::Utc::now());
+    Some(wait.to_std().unwrap_or(Duration::ZERO))
+}
+
+/// Running counters updated by the client; shared across threads
+#[derive(Debug, Default)]
+pub struct RetryCounters {
+    requests: AtomicU64,
+    retries: AtomicU64,
+    exhausted: AtomicU64,
+}
+
+impl RetryCounters {
+    pub fn record_request(&self) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_retry(&self) {
+        self.retries.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_exhausted(&self) {
+        self.exhausted.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> RetryStats {
+        RetryStats {
+            requests: self.requests.load(Ordering::Relaxed),
+            retries: self.retries.load(Ordering::Relaxed),
+            exhausted: self.exhausted.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Snapshot of the client's retry counters
+#[derive(Debug, Clone)]
+#[pyclass]
+pub struct RetryStats {
+    /// Logical requests issued (a request and all of its retries count once)
+    #[pyo3(get)]
+    pub requests: u64,
+
+    /// Extra attempts made after a retryable failure
+    #[pyo3(get)]
+    pub retries: u64,
+
+    /// Requests that still failed after using up every retry
+    #[pyo3(get)]
+    pub exhausted: u64,
+}
+
+#[pymethods]
+impl RetryStats {
+    fn __repr__(&self) -> String {
+        format!(
+            "RetryStats(requests={}, retries={}, exhausted={})",
+            self.requests, self.retries, self.exhausted
+        )
+    }
+}
@@ -0,0 +1,305 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::pyclass::PyClass;
+use pyo3::types::PyType;
+use pyo3::PyTypeInfo;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::ser::PrettyFormatter;
+use serde_json::Value;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use crate::raw;
+
+/// Models that convert to and from the API's JSON for `to_dict`,
+/// `from_dict`, pickling and value equality
+pub trait Model: PyClass + Serialize + DeserializeOwned + Default {
+    /// Decode the way a response item is decoded
+    fn from_value(value: Value) -> serde_json::Result<Self>;
+
+    /// The JSON the model was decoded from, if it keeps it
+    fn source(&self) -> Option<&Value> {
+        None
+    }
+}
+
+macro_rules! impl_model {
+    (raw: [$($raw:ty),* $(,)?], plain: [$($plain:ty),* $(,)?] $(,)?) => {
+        $(
+            impl Model for $raw {
+                fn from_value(value: Value) -> serde_json::Result<Self> {
+                    raw::decode(value)
+                }
+
+                fn source(&self) -> Option<&Value> {
+                    self.raw.as_ref()
+                }
+            }
+        )*
+        $(
+            impl Model for $plain {
+                fn from_value(value: Value) -> serde_json::Result<Self> {
+                    serde_json::from_value(value)
+                }
+            }
+        )*
+
+        /// How to read the fields of instances of `ty`, or `None` when it is
+        /// not a model. Callers converting many items look this up once per
+        /// type rather than once per item.
+        pub fn model_reader(ty: &Bound<'_, PyType>) -> Option<ReadModel> {
+            let py = ty.py();
+            $(
+                if ty.is(&<$raw as PyTypeInfo>::type_object(py)) {
+                    return Some(read::<$raw>);
+                }
+            )*
+            $(
+                if ty.is(&<$plain as PyTypeInfo>::type_object(py)) {
+                    return Some(read::<$plain>);
+                }
+            )*
+            None
+        }
+    };
+}
+
+impl_model!(
+    raw: [
+        crate::bills::Bill,
+        crate::bills::BillDetail,
+        crate::bills::Action,
+        crate::bills::Amendment,
+        crate::bills::Committee,
+        crate::bills::Cosponsor,
+        crate::bills::RelatedBill,
+        crate::bills::Subject,
+        crate::bills::PolicyArea,
+        crate::bills::Summary,
+        crate::bills::TextVersion,
+        crate::bills::Title,
+        crate::amendments::AmendmentDetail,
+        crate::committees::CommitteeItem,
+        crate::committees::CommitteeDetailInfo,
+        crate::committees::CommitteeBill,
+        crate::committees::CommitteeReportItem,
+        crate::committees::CommitteeReportDetail,
+        crate::committees::CommitteeReportText,
+        crate::committees::CommitteePrintItem,
+        crate::committees::CommitteePrintDetail,
+        crate::committees::CommitteePrintText,
+        crate::congressional_record::DailyCongressionalRecord,
+        crate::crsreport::CrsReport,
+        crate::crsreport::CrsReportDetail,
+        crate::hearings::Hearing,
+        crate::committee_meetings::CommitteeMeeting,
+        crate::committee_meetings::CommitteeMeetingDetail,
+        crate::communications::Communication,
+        crate::communications::CommunicationDetail,
+        crate::house_requirements::HouseRequirement,
+        crate::house_votes::HouseVote,
+        crate::house_votes::HouseVoteDetail,
+        crate::house_votes::HouseVoteMembers,
+        crate::laws::LawItem,
+        crate::laws::LawDetail,
+        crate::members::Sponsor,
+        crate::nominations::Nomination,
+        crate::nominations::Nominee,
+        crate::sessions::Congress,
+        crate::summaries::SummaryItem,
+        crate::treaties::Treaty,
+    ],
+    plain: [
+        crate::bills::LatestAction,
+        crate::bills::Law,
+        crate::bills::RelationshipDetail,
+        crate::bills::TextFormat,
+        crate::amendments::AmendedBill,
+        crate::amendments::AmendedTreaty,
+        crate::committees::Subcommittee,
+        crate::committees::ParentCommittee,
+        crate::committees::CommitteeHistory,
+        crate::committees::ResourceCount,
+        crate::crsreport::CrsReportFormat,
+        crate::crsreport::CrsReportAuthor,
+        crate::crsreport::CrsReportTopic,
+        crate::crsreport::CrsReportRelatedMaterial,
+        crate::hearings::HearingDate,
+        crate::hearings::AssociatedMeeting,
+        crate::hearings::HearingFormat,
+        crate::hearings::HearingCommittee,
+        crate::committee_meetings::MeetingLocation,
+        crate::committee_meetings::Witness,
+        crate::committee_meetings::WitnessDocument,
+        crate::committee_meetings::MeetingDocument,
+        crate::committee_meetings::MeetingVideo,
+        crate::committee_meetings::MeetingBill,
+        crate::committee_meetings::MeetingNomination,
+        crate::committee_meetings::MeetingTreaty,
+        crate::committee_meetings::MeetingRelatedItems,
+        crate::communications::CommunicationTypeInfo,
+        crate::communications::CommunicationCommittee,
+        crate::communications::MatchingRequirement,
+        crate::house_votes::Party,
+        crate::house_votes::VoteParty,
+        crate::house_votes::MemberVote,
+        crate::sessions::Session,
+    ],
+);
+
+/// The `#[pymethods]` block of a model: its own methods, such as `__repr__`,
+/// followed by the ones every model shares. Models listed under `raw` in
+/// `impl_model!` are invoked as `model_methods!(raw Name { ... })` to also
+/// get the `raw` getter.
+macro_rules! model_methods {
+    (raw $model:ident { $($body:tt)* }) => {
+        $crate::serialization::model_methods!($model {
+            $($body)*
+
+            /// The JSON object this result was decoded from; None for models nested
+            /// inside another result
+            #[getter]
+            fn raw(&self, py: ::pyo3::Python<'_>) -> ::pyo3::PyResult<::pyo3::PyObject> {
+                $crate::raw::raw_to_py(py, self.raw.as_ref())
+            }
+        });
+    };
+    ($model:ident { $($body:tt)* }) => {
+        #[::pyo3::pymethods]
+        impl $model {
+            $($body)*
+
+            /// Fields returned by the API that this model does not declare
+            #[getter]
+            fn extra(&self, py: ::pyo3::Python<'_>) -> ::pyo3::PyResult<::pyo3::PyObject> {
+                $crate::raw::object_to_py(py, &self.extra)
+            }
+
+            /// Fields as a JSON-compatible dict keyed by the API's names
+            fn to_dict(&self, py: ::pyo3::Python<'_>) -> ::pyo3::PyResult<::pyo3::PyObject> {
+                $crate::serialization::to_dict(py, self)
+            }
+
+            /// Fields as a JSON string keyed by the API's names
+            #[pyo3(signature = (indent=None))]
+            fn to_json(&self, indent: Option<usize>) -> ::pyo3::PyResult<String> {
+                $crate::serialization::to_json(self, indent)
+            }
+
+            /// Build from a dict shaped like the API's JSON, such as `to_dict()`
+            #[classmethod]
+            fn from_dict(
+                _cls: &::pyo3::Bound<'_, ::pyo3::types::PyType>,
+                data: &::pyo3::Bound<'_, ::pyo3::PyAny>,
+            ) -> ::pyo3::PyResult<Self> {
+                $crate::serialization::from_dict(data)
+            }
+
+            /// Build from a JSON string shaped like the API's, such as `to_json()`
+            #[classmethod]
+            fn from_json(_cls: &::pyo3::Bound<'_, ::pyo3::types::PyType>, text: &str) -> ::pyo3::PyResult<Self> {
+                $crate::serialization::from_json(text)
+            }
+
+            /// The JSON the model was decoded from, or its fields when it has none
+            fn __getstate__(&self) -> ::pyo3::PyResult<String> {
+                $crate::serialization::get_state(self)
+            }
+
+            /// Replace every field with those decoded from `__getstate__`'s JSON
+            fn __setstate__(&mut self, state: &str) -> ::pyo3::PyResult<()> {
+                *self = $crate::serialization::from_json(state)?;
+                Ok(())
+            }
+
+            /// Models have no constructor, so pickle and `copy` start from an
+            /// empty model built by `from_json` and then call `__setstate__`
+            fn __reduce__<'py>(
+                slf: &::pyo3::Bound<'py, Self>,
+            ) -> ::pyo3::PyResult<(::pyo3::Bound<'py, ::pyo3::PyAny>, (&'static str,), String)> {
+                use ::pyo3::types::PyAnyMethods;
+                let from_json = slf.get_type().getattr("from_json")?;
+                Ok((from_json, ("{}",), $crate::serialization::get_state(&*slf.borrow())?))
+            }
+
+            fn __eq__(&self, other: &Self) -> ::pyo3::PyResult<bool> {
+                $crate::serialization::eq(self, other)
+            }
+
+            fn __hash__(&self) -> ::pyo3::PyResult<u64> {
+                $crate::serialization::hash(self)
+            }
+        }
+    };
+}
+
+pub(crate) use model_methods;
+
+/// Reads the fields of one model; see `model_reader`
+pub type ReadModel = fn(&Bound<'_, PyAny>) -> PyResult<Value>;
+
+fn read<T: Model>(item: &Bound<'_, PyAny>) -> PyResult<Value> {
+    to_value(&*item.downcast::<T>()?.borrow())
+}
+
+fn to_value<T: Model>(model: &T) -> PyResult<Value> {
+    serde_json::to_value(model).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+fn decode<T: Model>(value: Value) -> PyResult<T> {
+    T::from_value(value).map_err(|e| {
+        PyValueError::new_err(format!("invalid {} data: {}", <T as PyTypeInfo>::NAME, e))
+    })
+}
+
+/// The model's fields keyed by the API's JSON names
+pub fn to_dict<T: Model>(py: Python<'_>, model: &T) -> PyResult<PyObject> {
+    raw::to_py(py, &to_value(model)?)
+}
+
+/// The model as JSON, compact or indented by `indent` spaces
+pub fn to_json<T: Model>(model: &T, indent: Option<usize>) -> PyResult<String> {
+    let value = to_value(model)?;
+    let Some(indent) = indent else {
+        return Ok(value.to_string());
+    };
+    let indent = b" ".repeat(indent);
+    let mut out = Vec::new();
+    let mut serializer = serde_json::Serializer::with_formatter(&mut out, PrettyFormatter::with_indent(&indent));
+    value
+        .serialize(&mut serializer)
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    String::from_utf8(out).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+pub fn from_dict<T: Model>(data: &Bound<'_, PyAny>) -> PyResult<T> {
+    decode(raw::from_py(data)?)
+}
+
+pub fn from_json<T: Model>(text: &str) -> PyResult<T> {
+    let value = serde_json::from_str(text).map_err(|e| PyValueError::new_err(format!("invalid JSON: {}", e)))?;
+    decode(value)
+}
+
+/// Pickled state: the JSON the model was decoded from, so `raw` survives,
+/// or its fields when it has none
+pub fn get_state<T: Model>(model: &T) -> PyResult<String> {
+    match model.source() {
+        Some(source) => Ok(source.to_string()),
+        None => to_json(model, None),
+    }
+}
+
+/// Models are equal when their fields are; `raw` is not compared
+pub fn eq<T: Model>(model: &T, other: &T) -> PyResult<bool> {
+    Ok(to_value(model)? == to_value(other)?)
+}
+
+/// Hash of the fields; object keys serialize sorted, so equal models hash
+/// the same
+pub fn hash<T: Model>(model: &T) -> PyResult<u64> {
+    let mut hasher = DefaultHasher::new();
+    to_value(model)?.to_string().hash(&mut hasher);
+    Ok(hasher.finish())
+}
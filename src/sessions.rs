@@ -1,38 +1,46 @@
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::dates::ApiDate;
+use crate::enums::{Chamber, Code};
+use crate::pagination::{Pagination, RequestEcho};
+use crate::serialization::model_methods;
+
 /// Represents a Congressional session
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct Session {
     #[pyo3(get)]
-    pub chamber: Option<String>,
+    pub chamber: Option<Code<Chamber>>,
     
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub number: Option<i32>,
     
     #[pyo3(get)]
     #[serde(rename = "startDate")]
-    pub start_date: Option<String>,
+    pub start_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "endDate")]
-    pub end_date: Option<String>,
+    pub end_date: Option<ApiDate>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[pymethods]
-impl Session {
+model_methods!(Session {
     fn __repr__(&self) -> String {
         format!(
             "Session(chamber={:?}, number={:?}, start_date={:?}, end_date={:?})",
             self.chamber, self.number, self.start_date, self.end_date
         )
     }
-}
+});
 
 /// Represents a Congress with its sessions
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct Congress {
     #[pyo3(get)]
     #[serde(rename = "endYear")]
@@ -50,26 +58,35 @@ pub struct Congress {
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl Congress {
+model_methods!(raw Congress {
     fn __repr__(&self) -> String {
         format!(
             "Congress(name={:?}, start_year={:?}, end_year={:?})",
             self.name, self.start_year, self.end_year
         )
     }
-}
+});
 
 /// Response structure for congress list
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CongressesResponse {
+    #[serde(deserialize_with = "crate::raw::list")]
     pub congresses: Vec<Congress>,
+    pub pagination: Option<Pagination>,
+    pub request: Option<RequestEcho>,
 }
 
 /// Response structure for a single congress
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CongressResponse {
+    #[serde(deserialize_with = "crate::raw::one")]
     pub congress: Congress,
 }
@@ -1,13 +1,17 @@
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::dates::ApiDate;
+use crate::pagination::{Pagination, RequestEcho};
+use crate::serialization::model_methods;
+
 /// Represents a summary item
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct SummaryItem {
     #[pyo3(get)]
     #[serde(rename = "actionDate")]
-    pub action_date: Option<String>,
+    pub action_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "actionDesc")]
@@ -18,24 +22,32 @@ pub struct SummaryItem {
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "versionCode")]
     pub version_code: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl SummaryItem {
+model_methods!(raw SummaryItem {
     fn __repr__(&self) -> String {
         format!(
             "SummaryItem(action={:?}, date={:?})",
             self.action_desc, self.action_date
         )
     }
-}
+});
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SummariesListResponse {
+    #[serde(deserialize_with = "crate::raw::list")]
     pub summaries: Vec<SummaryItem>,
+    pub pagination: Option<Pagination>,
+    pub request: Option<RequestEcho>,
 }
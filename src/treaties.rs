@@ -1,11 +1,16 @@
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::dates::ApiDate;
+use crate::pagination::{Pagination, RequestEcho};
+use crate::serialization::model_methods;
+
 /// Represents a treaty
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[pyclass]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[pyclass(module = "cdg_python_client")]
 pub struct Treaty {
     #[pyo3(get)]
+    #[serde(default, deserialize_with = "crate::xml::scalar")]
     pub congress: Option<i32>,
     
     #[pyo3(get)]
@@ -24,36 +29,45 @@ pub struct Treaty {
     
     #[pyo3(get)]
     #[serde(rename = "inForceDate")]
-    pub in_force_date: Option<String>,
+    pub in_force_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "transmittedDate")]
-    pub transmitted_date: Option<String>,
+    pub transmitted_date: Option<ApiDate>,
     
     #[pyo3(get)]
     #[serde(rename = "updateDate")]
-    pub update_date: Option<String>,
+    pub update_date: Option<ApiDate>,
     
     #[pyo3(get)]
     pub url: Option<String>,
+    
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+    
+    #[serde(skip)]
+    pub raw: Option<serde_json::Value>,
 }
 
-#[pymethods]
-impl Treaty {
+model_methods!(raw Treaty {
     fn __repr__(&self) -> String {
         format!(
             "Treaty(congress={:?}, number={:?}, topic={:?})",
             self.congress, self.number, self.topic
         )
     }
-}
+});
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TreatiesResponse {
+    #[serde(deserialize_with = "crate::raw::list")]
     pub treaties: Vec<Treaty>,
+    pub pagination: Option<Pagination>,
+    pub request: Option<RequestEcho>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TreatyDetailResponse {
+    #[serde(deserialize_with = "crate::raw::one")]
     pub treaty: Treaty,
 }
@@ -0,0 +1,111 @@
+use roxmltree::{Document, Node};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+
+/// Whether a response body is XML (requested with `format="xml"`) rather
+/// than JSON
+pub fn is_xml(body: &str) -> bool {
+    body.trim_start().starts_with('<')
+}
+
+/// Convert an XML response into the shape of the equivalent JSON response,
+/// so both formats decode into the same models.
+///
+/// The root element (`<api-root>`) becomes the top-level object and every
+/// element becomes a key named after its tag. An element becomes a list when
+/// its children are `<item>`s, when it repeats one child tag, or when its
+/// only child is the singular of its own tag (`<bills><bill>`). Leaf text is
+/// kept as a string and empty elements become null; numeric and boolean
+/// fields accept the text form via [`scalar`].
+pub fn to_json(body: &str) -> Result<Value, String> {
+    let document = Document::parse(body).map_err(|e| format!("invalid XML: {}", e))?;
+    Ok(element(document.root_element()))
+}
+
+fn element(node: Node<'_, '_>) -> Value {
+    let children: Vec<Node<'_, '_>> = node.children().filter(Node::is_element).collect();
+    if children.is_empty() {
+        let text: String = node.children().filter_map(|child| child.text()).collect();
+        let text = text.trim();
+        return if text.is_empty() { Value::Null } else { Value::String(text.to_string()) };
+    }
+
+    if is_list(node.tag_name().name(), &children) {
+        return Value::Array(children.into_iter().map(element).collect());
+    }
+    let mut fields = Map::new();
+    for child in children {
+        fields.insert(child.tag_name().name().to_string(), element(child));
+    }
+    Value::Object(fields)
+}
+
+fn is_list(tag: &str, children: &[Node<'_, '_>]) -> bool {
+    let first = children[0].tag_name().name();
+    if !children.iter().all(|child| child.tag_name().name() == first) {
+        return false;
+    }
+    first == "item" || children.len() > 1 || is_plural_of(tag, first)
+}
+
+fn is_plural_of(plural: &str, singular: &str) -> bool {
+    plural.strip_suffix('s') == Some(singular)
+        || matches!(
+            (plural.strip_suffix("ies"), singular.strip_suffix('y')),
+            (Some(a), Some(b)) if a == b
+        )
+}
+
+/// Scalars that may arrive as text
+pub trait FromText: Sized {
+    fn from_text(text: &str) -> Option<Self>;
+}
+
+macro_rules! impl_from_text {
+    ($($ty:ty),*) => {
+        $(
+            impl FromText for $ty {
+                fn from_text(text: &str) -> Option<Self> {
+                    text.trim().parse().ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_text!(i32, i64, u64);
+
+impl FromText for bool {
+    fn from_text(text: &str) -> Option<Self> {
+        match text.trim().to_ascii_lowercase().as_str() {
+            "true" => Some(true),
+            "false" => Some(false),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Scalar<T> {
+    Native(T),
+    Text(String),
+}
+
+/// `deserialize_with` helper for optional number and boolean fields, which
+/// JSON sends as such and XML sends as text
+pub fn scalar<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromText,
+{
+    match Option::<Scalar<T>>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Scalar::Native(value)) => Ok(Some(value)),
+        Some(Scalar::Text(text)) if text.trim().is_empty() => Ok(None),
+        Some(Scalar::Text(text)) => T::from_text(&text)
+            .map(Some)
+            .ok_or_else(|| D::Error::custom(format!("invalid value {:?}", text))),
+    }
+}
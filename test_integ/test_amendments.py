@@ -52,3 +52,41 @@ class TestAmendmentsList:
         if amendment.latest_action is not None:
             assert hasattr(amendment.latest_action, "action_date")
             assert hasattr(amendment.latest_action, "text")
+
+
+class TestAmendmentDetail:
+    """Test the amendment detail endpoint and its children."""
+    
+    def test_list_amendments_by_type(self, client):
+        """Test listing amendments by congress and type."""
+        amendments = client.list_amendments_by_type(117, "samdt", limit=3)
+        
+        assert isinstance(amendments, list)
+        assert len(amendments) > 0
+        for amendment in amendments:
+            assert amendment.amendment_type == "SAMDT"
+    
+    def test_get_amendment(self, client):
+        """Test getting S.Amdt. 2137, the substitute to H.R. 3684 (117th Congress)."""
+        amendment = client.get_amendment(117, "samdt", 2137)
+        
+        assert amendment.congress == 117
+        assert amendment.number == "2137"
+        assert amendment.chamber == "Senate"
+        assert amendment.purpose is not None
+        assert amendment.sponsors
+        assert amendment.amended_bill is not None
+        assert amendment.amended_bill.number == "3684"
+    
+    def test_amendment_children(self, client):
+        """Test the actions, cosponsors, amendments and text endpoints."""
+        actions = client.get_amendment_actions(117, "samdt", 2137, limit=5)
+        cosponsors = client.get_amendment_cosponsors(117, "samdt", 2137, limit=5)
+        amendments = client.get_amendment_amendments(117, "samdt", 2137, limit=5)
+        texts = client.get_amendment_text(117, "samdt", 2137)
+        
+        assert len(actions) > 0
+        assert actions[0].action_date is not None
+        assert isinstance(cosponsors, list)
+        assert isinstance(amendments, list)
+        assert len(texts) > 0
@@ -0,0 +1,52 @@
+"""Integration tests for the asyncio client."""
+
+import asyncio
+
+import pytest
+
+
+@pytest.fixture
+def async_client(api_key):
+    """Provide an AsyncCDGPythonClient instance for integration testing."""
+    from cdg_python_client import AsyncCDGPythonClient
+    return AsyncCDGPythonClient(api_key=api_key, max_concurrency=4)
+
+
+class TestAsyncClient:
+    """Test AsyncCDGPythonClient against the live API."""
+
+    def test_matches_sync_client(self, client, async_client):
+        """Test that a coroutine returns the same data as the sync method."""
+        async def run():
+            return await async_client.list_congresses(limit=3)
+
+        congresses = asyncio.run(run())
+
+        assert [c.name for c in congresses] == [c.name for c in client.list_congresses(limit=3)]
+
+    def test_gather(self, async_client):
+        """Test several endpoints fetched concurrently."""
+        async def run():
+            return await asyncio.gather(
+                async_client.list_bills(limit=2),
+                async_client.get_bill(118, "hr", 1),
+                async_client.count_congresses(),
+            )
+
+        bills, bill, congress_count = asyncio.run(run())
+
+        assert len(bills) == 2
+        assert bill.congress == 118
+        assert congress_count > 100
+
+    def test_async_iteration(self, async_client):
+        """Test that `async for` spans several pages."""
+        async def run():
+            iterator = async_client.iter_bills(page_size=2, max_items=5)
+            bills = [bill async for bill in iterator]
+            return bills, iterator.pages_fetched
+
+        bills, pages_fetched = asyncio.run(run())
+
+        assert len(bills) == 5
+        assert pages_fetched == 3
@@ -0,0 +1,54 @@
+"""Integration tests for committee meeting API endpoints."""
+
+import pytest
+
+
+class TestCommitteeMeetingsList:
+    """Test committee meeting listing endpoints."""
+    
+    def test_list_committee_meetings(self, client):
+        """Test listing committee meetings returns valid data."""
+        meetings = client.list_committee_meetings(limit=5)
+        
+        assert isinstance(meetings, list)
+        assert len(meetings) > 0
+        assert len(meetings) <= 5
+        
+        meeting = meetings[0]
+        assert hasattr(meeting, "congress")
+        assert hasattr(meeting, "chamber")
+        assert hasattr(meeting, "event_id")
+        
+        if meeting.event_id is not None:
+            assert isinstance(meeting.event_id, str)
+            assert meeting.event_id.isdigit()
+    
+    def test_list_committee_meetings_by_chamber(self, client):
+        """Test listing committee meetings for one chamber of a congress."""
+        meetings = client.list_committee_meetings_by_chamber(118, "house", limit=5)
+        
+        assert isinstance(meetings, list)
+        for meeting in meetings:
+            if meeting.congress is not None:
+                assert meeting.congress == 118
+            if meeting.chamber is not None:
+                assert meeting.chamber == "House"
+
+
+class TestCommitteeMeetingDetail:
+    """Test committee meeting detail endpoint."""
+    
+    def test_get_committee_meeting(self, client):
+        """Test getting a listed committee meeting's details."""
+        meetings = client.list_committee_meetings_by_chamber(118, "house", limit=1)
+        if not meetings or meetings[0].event_id is None:
+            pytest.skip("No committee meetings available")
+        
+        meeting = client.get_committee_meeting(118, "house", meetings[0].event_id)
+        
+        assert meeting.event_id == meetings[0].event_id
+        assert meeting.congress == 118
+        if meeting.title is not None:
+            assert isinstance(meeting.title, str)
+        if meeting.witnesses is not None:
+            assert isinstance(meeting.witnesses, list)
@@ -39,3 +39,43 @@ class TestCommitteesList:
             # At least name should be present for all committees
             assert committee.name is not None
             assert len(committee.name) > 0
+
+
+class TestCommitteeResources:
+    """Test per-committee detail and sub-resource endpoints."""
+    
+    def test_get_committee_by_congress(self, client):
+        """Test getting a committee as it stood in an earlier congress."""
+        committee = client.get_committee_by_congress(117, "house", "hsif00")
+        
+        assert committee.system_code == "hsif00"
+        if committee.reports is not None:
+            assert committee.reports.count >= 0
+    
+    def test_get_committee_reports(self, client):
+        """Test listing a committee's reports."""
+        reports = client.get_committee_reports("house", "hsif00", limit=5)
+        
+        assert isinstance(reports, list)
+        assert len(reports) <= 5
+        for report in reports:
+            if report.citation is not None:
+                assert isinstance(report.citation, str)
+    
+    def test_get_committee_nominations(self, client):
+        """Test listing nominations referred to a Senate committee."""
+        nominations = client.get_committee_nominations("senate", "ssas00", limit=5)
+        
+        assert isinstance(nominations, list)
+        assert len(nominations) <= 5
+    
+    def test_get_committee_communications(self, client):
+        """Test listing communications referred to a committee in each chamber."""
+        house = client.get_committee_house_communications("hsif00", limit=5)
+        senate = client.get_committee_senate_communications("ssas00", limit=5)
+        
+        assert isinstance(house, list)
+        assert isinstance(senate, list)
+        for communication in house:
+            if communication.chamber is not None:
+                assert communication.chamber == "House"
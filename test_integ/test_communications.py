@@ -0,0 +1,64 @@
+"""Integration tests for House and Senate communication API endpoints."""
+
+import pytest
+
+
+class TestHouseCommunications:
+    """Test House communication endpoints."""
+    
+    def test_list_house_communications(self, client):
+        """Test listing House communications returns valid data."""
+        communications = client.list_house_communications(limit=5)
+        
+        assert isinstance(communications, list)
+        assert len(communications) > 0
+        assert len(communications) <= 5
+        
+        communication = communications[0]
+        assert hasattr(communication, "congress")
+        assert hasattr(communication, "number")
+        assert hasattr(communication, "communication_type")
+        
+        if communication.number is not None:
+            assert isinstance(communication.number, int)
+        if communication.communication_type is not None:
+            assert communication.communication_type.code in ["EC", "ML", "PM", "PT"]
+    
+    def test_get_house_communication(self, client):
+        """Test getting a listed House communication's details."""
+        communications = client.list_house_communications_by_type(118, "ec", limit=1)
+        if not communications or communications[0].number is None:
+            pytest.skip("No House communications available")
+        
+        communication = client.get_house_communication(118, "ec", communications[0].number)
+        
+        assert communication.number == communications[0].number
+        assert communication.congress == 118
+        if communication.abstract_text is not None:
+            assert isinstance(communication.abstract_text, str)
+        if communication.committees is not None:
+            assert isinstance(communication.committees, list)
+
+
+class TestSenateCommunications:
+    """Test Senate communication endpoints."""
+    
+    def test_list_senate_communications_by_congress(self, client):
+        """Test listing Senate communications for a congress."""
+        communications = client.list_senate_communications_by_congress(118, limit=5)
+        
+        assert isinstance(communications, list)
+        for communication in communications:
+            if communication.congress is not None:
+                assert communication.congress == 118
+    
+    def test_get_senate_communication(self, client):
+        """Test getting a listed Senate communication's details."""
+        communications = client.list_senate_communications_by_type(118, "ec", limit=1)
+        if not communications or communications[0].number is None:
+            pytest.skip("No Senate communications available")
+        
+        communication = client.get_senate_communication(118, "ec", communications[0].number)
+        
+        assert communication.number == communications[0].number
+        assert communication.chamber == "Senate"
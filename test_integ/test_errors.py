@@ -0,0 +1,69 @@
+"""Integration tests for typed API errors."""
+
+import pytest
+from cdg_python_client import (
+    AuthenticationError,
+    CDGPythonClient,
+    CongressApiError,
+    NotFoundError,
+)
+
+
+class TestApiErrors:
+    """Test that API failures map onto the exception hierarchy."""
+
+    def test_missing_bill_raises_not_found(self, client):
+        """Test that a nonexistent bill raises NotFoundError."""
+        with pytest.raises(NotFoundError) as excinfo:
+            client.get_bill(congress=118, bill_type="hr", bill_number=999999)
+
+        error = excinfo.value
+        assert isinstance(error, CongressApiError)
+        assert error.status_code == 404
+        assert error.endpoint == "/bill/118/hr/999999"
+
+    def test_invalid_key_raises_authentication_error(self, api_key):
+        """Test that a bad API key raises AuthenticationError."""
+        bad_client = CDGPythonClient(api_key="not-a-real-key", max_retries=0)
+
+        with pytest.raises(AuthenticationError) as excinfo:
+            bad_client.list_bills(limit=1)
+
+        error = excinfo.value
+        assert error.status_code in (401, 403)
+        assert error.body
+
+
+class TestApiKeyRedaction:
+    """Test that the API key is not exposed by live responses or errors."""
+
+    def test_not_found_error_does_not_contain_key(self, client, api_key):
+        """Test that a 404 error carries no trace of the key."""
+        with pytest.raises(NotFoundError) as excinfo:
+            client.get_bill(congress=118, bill_type="hr", bill_number=999999)
+
+        error = excinfo.value
+        for text in (str(error), repr(error), error.endpoint, error.body or ""):
+            assert api_key not in text
+
+    def test_authentication_error_does_not_contain_key(self, api_key):
+        """Test that a rejected key is not echoed in the error."""
+        bad_key = "x" * 40
+        bad_client = CDGPythonClient(api_key=bad_key, max_retries=0)
+
+        with pytest.raises(AuthenticationError) as excinfo:
+            bad_client.list_bills(limit=1)
+
+        error = excinfo.value
+        for text in (str(error), repr(error), error.body or ""):
+            assert bad_key not in text
+
+    def test_response_urls_do_not_contain_key(self, client, api_key):
+        """Test that `url` fields echoed back by the API omit the key."""
+        bills = client.list_bills(limit=5)
+
+        assert bills
+        for bill in bills:
+            assert bill.url
+            assert api_key not in bill.url
+            assert "api_key=" not in bill.url
@@ -0,0 +1,41 @@
+"""Integration tests for House requirement API endpoints."""
+
+import pytest
+
+
+class TestHouseRequirements:
+    """Test House requirement endpoints."""
+    
+    def test_list_house_requirements(self, client):
+        """Test listing House requirements returns valid data."""
+        requirements = client.list_house_requirements(limit=5)
+        
+        assert isinstance(requirements, list)
+        assert len(requirements) > 0
+        assert len(requirements) <= 5
+        
+        requirement = requirements[0]
+        if requirement.number is not None:
+            assert isinstance(requirement.number, int)
+            assert requirement.number > 0
+    
+    def test_get_house_requirement(self, client):
+        """Test getting a House requirement's details."""
+        requirement = client.get_house_requirement(8070)
+        
+        assert requirement.number == 8070
+        if requirement.submitting_agency is not None:
+            assert isinstance(requirement.submitting_agency, str)
+        if requirement.matching_communications is not None:
+            assert requirement.matching_communications.count >= 0
+    
+    def test_get_house_requirement_matching_communications(self, client):
+        """Test listing the communications filed under a requirement."""
+        communications = client.get_house_requirement_matching_communications(8070, limit=5)
+        
+        assert isinstance(communications, list)
+        if not communications:
+            pytest.skip("No matching communications available")
+        assert len(communications) <= 5
+        if communications[0].chamber is not None:
+            assert communications[0].chamber == "House"
@@ -0,0 +1,23 @@
+"""Integration tests for pagination metadata."""
+
+
+class TestPages:
+    """Test Page results and count_* against the live API."""
+
+    def test_bills_page_metadata(self, client):
+        """Test that a page carries the total count and a next link."""
+        page = client.list_bills_by_congress_page(118, limit=5)
+
+        assert len(page) == 5
+        assert page.count > 5
+        assert page.next is not None
+        assert page.next_offset == 5
+
+    def test_count_matches_page_count(self, client):
+        """Test that count_* agrees with the page metadata."""
+        page = client.list_laws_by_congress_page(118, limit=1)
+        assert client.count_laws_by_congress(118) == page.count
+
+    def test_house_votes_count(self, client):
+        """Test counting a BETA endpoint."""
+        assert client.count_house_votes_by_congress(118) > 0
@@ -0,0 +1,29 @@
+"""Integration tests for auto-paginating iterators."""
+
+
+class TestIterators:
+    """Test that iter_* methods walk across real pages."""
+
+    def test_iter_bills_spans_pages(self, client):
+        """Test that items from several pages are yielded in order."""
+        iterator = client.iter_bills(page_size=2, max_items=5)
+        bills = list(iterator)
+
+        assert len(bills) == 5
+        assert iterator.pages_fetched == 3
+        keys = {(b.congress, b.bill_type, b.number) for b in bills}
+        assert len(keys) == 5
+
+    def test_iter_matches_list(self, client):
+        """Test that the first page of an iterator matches list_*."""
+        listed = client.list_congresses(limit=3)
+        iterated = list(client.iter_congresses(page_size=3, max_items=3))
+
+        assert [c.name for c in iterated] == [c.name for c in listed]
+
+    def test_iter_laws_by_congress(self, client):
+        """Test iterating a filtered endpoint."""
+        laws = list(client.iter_laws_by_congress(118, max_items=3))
+
+        assert len(laws) == 3
+        assert all(law.congress == 118 for law in laws)
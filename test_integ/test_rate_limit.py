@@ -0,0 +1,18 @@
+"""Integration tests for API quota tracking."""
+
+import pytest
+
+
+class TestRateLimitStatus:
+    """Test that quota headers are picked up from live responses."""
+
+    def test_quota_recorded_after_request(self, client):
+        """Test that limit/remaining are populated once a response is seen."""
+        client.list_bills(limit=1)
+
+        status = client.rate_limit_status()
+        assert status.requests_sent >= 1
+        assert status.limit is not None
+        assert status.remaining is not None
+        assert 0 <= status.remaining <= status.limit
+        assert status.seconds_since_update is not None
@@ -9,8 +9,82 @@ def api_key():
     return "test_api_key_12345"
 
 
+class MockApi:
+    """Client for a scripted stand-in of the Congress.gov API.
+
+    The server runs in a subprocess (see `mock_server.py`) so it keeps
+    answering while a client call blocks this interpreter.
+    """
+
+    def __init__(self, url):
+        self.url = url
+
+    def _control(self, method, payload=None):
+        import json
+        import urllib.request
+
+        data = None if payload is None else json.dumps(payload).encode()
+        request = urllib.request.Request(self.url + "/_mock", data=data, method=method)
+        with urllib.request.urlopen(request, timeout=5) as response:
+            return json.loads(response.read())
+
+    def add(self, body, status=200, headers=None, delay=0.0):
+        """Queue a response; `body` may be a dict (sent as JSON) or a string."""
+        import json
+
+        if not isinstance(body, str):
+            body = json.dumps(body)
+        self._control("POST", {"status": status, "headers": headers or {}, "body": body, "delay": delay})
+
+    @property
+    def requests(self):
+        """Requests received so far (path, target, query and lowercased headers)."""
+        return self._control("GET")
+
+
+@pytest.fixture
+def mock_api():
+    """Serve queued responses on localhost and record incoming requests.
+
+    Requests beyond the queued responses get a 404. Point a client at
+    `mock_api.url` via `base_url` (or `proxy`).
+    """
+    import subprocess
+    import sys
+    from pathlib import Path
+
+    server = subprocess.Popen(
+        [sys.executable, str(Path(__file__).with_name("mock_server.py"))],
+        stdout=subprocess.PIPE,
+        text=True,
+    )
+    try:
+        port = int(server.stdout.readline())
+        yield MockApi(f"http://127.0.0.1:{port}")
+    finally:
+        server.terminate()
+        server.wait()
+
+
 @pytest.fixture
-def client(api_key):
-    """Provide a CDGPythonClient instance for testing."""
+def make_client(mock_api, api_key):
+    """Build a CDGPythonClient pointed at `mock_api`, with any other options."""
     from cdg_python_client import CDGPythonClient
-    return CDGPythonClient(api_key=api_key)
+
+    def make(**options):
+        return CDGPythonClient(api_key=api_key, base_url=mock_api.url, **options)
+
+    return make
+
+
+@pytest.fixture
+def client(make_client):
+    """Provide a CDGPythonClient pointed at `mock_api`."""
+    return make_client()
+
+
+@pytest.fixture
+def async_client(mock_api, api_key):
+    """Provide an AsyncCDGPythonClient pointed at `mock_api`."""
+    from cdg_python_client import AsyncCDGPythonClient
+    return AsyncCDGPythonClient(api_key=api_key, base_url=mock_api.url)
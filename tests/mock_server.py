@@ -0,0 +1,62 @@
+"""Scripted HTTP server used by the `mock_api` fixture.
+
+Responses are queued with `POST /_mock` and the recorded requests are
+returned by `GET /_mock`; every other GET pops the next queued response.
+The chosen port is printed on stdout once the server is listening.
+"""
+
+import json
+import time
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+from urllib.parse import parse_qs, urlsplit
+
+responses = []
+requests = []
+
+
+class Handler(BaseHTTPRequestHandler):
+    def _send(self, status, body, headers=None):
+        payload = body.encode()
+        try:
+            self.send_response(status)
+            self.send_header("Content-Type", "application/json")
+            self.send_header("Content-Length", str(len(payload)))
+            for name, value in (headers or {}).items():
+                self.send_header(name, value)
+            self.end_headers()
+            self.wfile.write(payload)
+        except (BrokenPipeError, ConnectionResetError):
+            pass
+
+    def do_POST(self):
+        length = int(self.headers.get("Content-Length", 0))
+        responses.append(json.loads(self.rfile.read(length)))
+        self._send(200, "{}")
+
+    def do_GET(self):
+        if self.path == "/_mock":
+            self._send(200, json.dumps(requests))
+            return
+
+        url = urlsplit(self.path)
+        requests.append({
+            "path": url.path,
+            "target": self.path,
+            "query": parse_qs(url.query),
+            "headers": {name.lower(): value for name, value in self.headers.items()},
+        })
+        if responses:
+            response = responses.pop(0)
+        else:
+            response = {"status": 404, "headers": {}, "body": '{"error": "Unknown resource"}', "delay": 0.0}
+        time.sleep(response["delay"])
+        self._send(response["status"], response["body"], response["headers"])
+
+    def log_message(self, *args):
+        pass
+
+
+if __name__ == "__main__":
+    server = ThreadingHTTPServer(("127.0.0.1", 0), Handler)
+    print(server.server_port, flush=True)
+    server.serve_forever()
@@ -0,0 +1,127 @@
+"""Tests for the amendment detail endpoints and AmendmentDetail model."""
+
+from datetime import date, datetime, timezone
+
+import pytest
+
+
+AMENDMENT = {
+    "actions": {"count": 19, "url": "https://api.congress.gov/v3/amendment/117/samdt/2137/actions"},
+    "amendedBill": {
+        "congress": 117,
+        "number": "3684",
+        "originChamber": "House",
+        "originChamberCode": "H",
+        "title": "Infrastructure Investment and Jobs Act",
+        "type": "HR",
+        "url": "https://api.congress.gov/v3/bill/117/hr/3684",
+    },
+    "chamber": "Senate",
+    "congress": 117,
+    "latestAction": {"actionDate": "2021-08-08", "text": "Amendment SA 2137 agreed to in Senate by Yea-Nay Vote. 69 - 28."},
+    "number": "2137",
+    "proposedDate": "2021-08-01T04:00:00Z",
+    "purpose": "In the nature of a substitute.",
+    "sponsors": [{"bioguideId": "S001191", "fullName": "Sen. Sinema, Kyrsten [D-AZ]"}],
+    "submittedDate": "2021-08-01T04:00:00Z",
+    "type": "SAMDT",
+    "updateDate": "2022-02-08T17:27:59Z",
+}
+
+
+def test_get_amendment(mock_api, client):
+    """Test decoding the detail response into AmendmentDetail."""
+    from cdg_python_client import AmendmentType, BillType, Chamber
+
+    mock_api.add({"amendment": AMENDMENT})
+
+    amendment = client.get_amendment(117, "samdt", 2137)
+
+    assert mock_api.requests[0]["path"] == "/amendment/117/samdt/2137"
+    assert amendment.amendment_type == AmendmentType.SAMDT
+    assert amendment.chamber == Chamber.SENATE
+    assert amendment.purpose == "In the nature of a substitute."
+    assert amendment.submitted_date == datetime(2021, 8, 1, 4, tzinfo=timezone.utc)
+    assert amendment.latest_action.action_date == date(2021, 8, 8)
+    assert amendment.sponsors[0].bioguide_id == "S001191"
+    assert amendment.amended_bill.bill_type == BillType.HR
+    assert amendment.amended_bill.number == "3684"
+    assert amendment.amended_treaty is None
+    assert amendment.extra["actions"]["count"] == 19
+    assert amendment.raw == AMENDMENT
+    assert repr(amendment) == (
+        'AmendmentDetail(congress=Some(117), number=Some("2137"), '
+        'type=Some("SAMDT"), purpose=Some("In the nature of a substitute."))'
+    )
+
+
+def test_amended_amendment_and_treaty():
+    """Test the amendment and treaty targets of other amendments."""
+    from cdg_python_client import AmendmentDetail
+
+    amendment = AmendmentDetail.from_dict({
+        "amendedAmendment": {"congress": 117, "number": "2137", "type": "SAMDT"},
+        "amendedTreaty": {"congress": 117, "treatyNumber": "3"},
+    })
+
+    assert amendment.amended_amendment.number == "2137"
+    assert amendment.amended_treaty.treaty_number == 3
+
+
+@pytest.mark.parametrize("method, key, body, attr", [
+    ("get_amendment_actions", "actions", {"actionDate": "2021-08-08", "type": "Floor"}, "action_type"),
+    ("get_amendment_cosponsors", "cosponsors", {"bioguideId": "P000449", "isOriginalCosponsor": True}, "bioguide_id"),
+    ("get_amendment_amendments", "amendments", {"congress": 117, "number": "2138", "type": "SAMDT"}, "number"),
+    ("get_amendment_text", "text", {"date": "2021-08-01T04:00:00Z", "type": "Submitted"}, "text_type"),
+])
+def test_child_endpoints(mock_api, client, method, key, body, attr):
+    """Test the actions, cosponsors, amendments and text children."""
+    response_key = "textVersions" if key == "text" else key
+    mock_api.add({response_key: [body]})
+
+    items = getattr(client, method)(id="S.Amdt. 2137 (117th Congress)", limit=5)
+
+    assert mock_api.requests[0]["path"] == f"/amendment/117/samdt/2137/{key}"
+    assert mock_api.requests[0]["query"]["limit"] == ["5"]
+    assert getattr(items[0], attr) is not None
+    assert items[0].raw == body
+
+
+def test_list_amendments_by_type(mock_api, client):
+    """Test listing by congress and amendment type, including the page helpers."""
+    from cdg_python_client import AmendmentType
+
+    mock_api.add({"amendments": [{"number": "1", "type": "HAMDT"}], "pagination": {"count": 1}})
+    mock_api.add({"amendments": [], "pagination": {"count": 42}})
+
+    amendments = client.list_amendments_by_type(118, AmendmentType.HAMDT, limit=1)
+    total = client.count_amendments_by_type(118, "hamdt")
+
+    assert amendments[0].amendment_type == AmendmentType.HAMDT
+    assert total == 42
+    assert [request["path"] for request in mock_api.requests] == [
+        "/amendment/118/hamdt",
+        "/amendment/118/hamdt",
+    ]
+
+
+def test_bill_type_is_rejected(mock_api, client):
+    """Test that a bill type is not accepted as an amendment type."""
+    with pytest.raises(ValueError, match="unknown amendment type"):
+        client.get_amendment(117, "hr", 1)
+    assert mock_api.requests == []
+
+
+def test_async_get_amendment(mock_api, async_client):
+    """Test the async client's amendment detail."""
+    import asyncio
+    from cdg_python_client import AmendmentId
+
+    mock_api.add({"amendment": AMENDMENT})
+
+    async def run():
+        return await async_client.get_amendment(id=AmendmentId(117, "samdt", 2137))
+
+    amendment = asyncio.run(run())
+    assert mock_api.requests[0]["path"] == "/amendment/117/samdt/2137"
+    assert amendment.amended_bill.title == "Infrastructure Investment and Jobs Act"
@@ -0,0 +1,115 @@
+"""Tests that the API key never shows up in error output."""
+
+import socket
+
+import pytest
+
+SECRET = "SeCrEtKeY0123456789abcdefghijklmnopqrstu"
+
+
+def _closed_port():
+    """Return a local port with nothing listening on it."""
+    with socket.socket(socket.AF_INET, socket.SOCK_STREAM) as sock:
+        sock.bind(("127.0.0.1", 0))
+        return sock.getsockname()[1]
+
+
+def _assert_key_absent(error):
+    """Assert the key appears nowhere on the exception."""
+    exposed = [str(error), repr(error), *map(str, error.args)]
+    exposed += [str(getattr(error, name)) for name in ("endpoint", "body", "status_code", "retry_after")]
+    for text in exposed:
+        assert SECRET not in text
+        assert "api_key=" + SECRET[:8] not in text
+
+
+def test_network_error_does_not_contain_key(monkeypatch):
+    """Test that connection failures do not echo the request URL or key."""
+    from cdg_python_client import CDGPythonClient, NetworkError
+
+    monkeypatch.setenv("HTTPS_PROXY", f"http://127.0.0.1:{_closed_port()}")
+    client = CDGPythonClient(api_key=SECRET, max_retries=0)
+
+    with pytest.raises(NetworkError) as excinfo:
+        client.get_bill(congress=118, bill_type="hr", bill_number=1)
+
+    _assert_key_absent(excinfo.value)
+    # The cause is still reported even though the URL is gone
+    assert "/bill/118/hr/1" in str(excinfo.value)
+
+
+def test_rate_limit_error_does_not_contain_key(monkeypatch):
+    """Test that client-side rate limit errors do not contain the key."""
+    from cdg_python_client import CDGPythonClient, RateLimitError
+
+    monkeypatch.setenv("HTTPS_PROXY", f"http://127.0.0.1:{_closed_port()}")
+    client = CDGPythonClient(
+        api_key=SECRET,
+        max_retries=0,
+        rate_limit=1,
+        rate_limit_mode="raise",
+    )
+
+    with pytest.raises(Exception):
+        client.list_bills(limit=1)
+    with pytest.raises(RateLimitError) as excinfo:
+        client.list_bills(limit=1)
+
+    _assert_key_absent(excinfo.value)
+
+
+def test_invalid_key_characters_rejected_without_echo():
+    """Test that a key that cannot be sent as a header is rejected up front."""
+    from cdg_python_client import CDGPythonClient
+
+    bad_key = SECRET + "\n"
+    with pytest.raises(ValueError) as excinfo:
+        CDGPythonClient(api_key=bad_key)
+
+    assert SECRET not in str(excinfo.value)
+
+
+def test_http_error_body_is_scrubbed(mock_api):
+    """Test that a key echoed in an error response is removed from the body."""
+    from cdg_python_client import AuthenticationError, CDGPythonClient
+
+    mock_api.add(
+        {"error": {"code": "API_KEY_INVALID", "message": f"Key {SECRET} is invalid"}},
+        status=403,
+    )
+    client = CDGPythonClient(api_key=SECRET, base_url=mock_api.url)
+
+    with pytest.raises(AuthenticationError) as excinfo:
+        client.list_bills()
+
+    _assert_key_absent(excinfo.value)
+    assert "REDACTED" in excinfo.value.body
+
+
+def test_decode_error_body_is_scrubbed(mock_api):
+    """Test that an undecodable body is scrubbed before it is attached."""
+    from cdg_python_client import CDGPythonClient, DecodeError
+
+    mock_api.add(f"<html>bad gateway for ?api_key={SECRET}</html>")
+    client = CDGPythonClient(api_key=SECRET, base_url=mock_api.url)
+
+    with pytest.raises(DecodeError) as excinfo:
+        client.list_bills()
+
+    _assert_key_absent(excinfo.value)
+
+
+def test_echoed_urls_are_scrubbed(mock_api):
+    """Test that `url` fields carrying an api_key parameter are redacted."""
+    from cdg_python_client import CDGPythonClient
+
+    mock_api.add({"bills": [{
+        "congress": 118,
+        "url": f"https://api.congress.gov/v3/bill/118/hr/1?format=json&api_key={SECRET}",
+    }]})
+    client = CDGPythonClient(api_key=SECRET, base_url=mock_api.url)
+
+    bill = client.list_bills()[0]
+
+    assert SECRET not in bill.url
+    assert bill.url == "https://api.congress.gov/v3/bill/118/hr/1?format=json&api_key=REDACTED"
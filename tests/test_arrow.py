@@ -0,0 +1,201 @@
+"""Tests for flattening list results into columns, Arrow and pandas."""
+
+import ctypes
+import sys
+import types
+from datetime import date, datetime, timezone
+
+import pytest
+
+
+BILLS = [
+    {
+        "congress": 118,
+        "number": "1",
+        "type": "HR",
+        "updateDateIncludingText": "2024-07-23T08:05:37Z",
+        "latestAction": {"actionDate": "2023-03-30", "text": "Received in the Senate."},
+    },
+    {
+        "congress": 118,
+        "number": "2",
+        "type": "S",
+        "latestAction": None,
+    },
+]
+
+
+def _bills(mock_api, client):
+    mock_api.add({"bills": BILLS})
+    return client.list_bills_by_congress(118)
+
+
+def test_columns_are_flattened_and_typed(mock_api, client):
+    """Test dotted names for nested objects and typed values per column."""
+    from cdg_python_client import to_columns
+
+    columns = to_columns(_bills(mock_api, client))
+
+    assert columns["number"] == ["1", "2"]
+    assert columns["congress"] == [118, 118]
+    assert columns["type"] == ["HR", "S"]
+    assert columns["latestAction.actionDate"] == [date(2023, 3, 30), None]
+    assert columns["latestAction.text"] == ["Received in the Senate.", None]
+    assert columns["updateDateIncludingText"] == [
+        datetime(2024, 7, 23, 8, 5, 37, tzinfo=timezone.utc),
+        None,
+    ]
+    assert "latestAction" not in columns
+
+
+def test_columns_from_dicts_and_mixed_values():
+    """Test raw dicts, lists as JSON text and mixed types falling back to text."""
+    from cdg_python_client import to_columns
+
+    columns = to_columns([
+        {"id": 1, "score": 2, "tags": ["a", "b"], "when": "2023-01-09T12:53:00-05:00"},
+        {"id": "x", "score": 2.5, "tags": [], "when": "2023-01-10", "new": True},
+    ])
+
+    assert columns["id"] == ["1", "x"]
+    assert columns["score"] == [2, 2.5]
+    assert columns["tags"] == ['["a","b"]', "[]"]
+    assert columns["when"] == [
+        datetime(2023, 1, 9, 17, 53, tzinfo=timezone.utc),
+        datetime(2023, 1, 10, tzinfo=timezone.utc),
+    ]
+    assert columns["new"] == [None, True]
+    assert to_columns([]) == {}
+
+
+def test_rejects_other_items():
+    """Test that items other than models and dicts are refused."""
+    from cdg_python_client import to_columns
+
+    with pytest.raises(TypeError, match="expected a model or dict, got int"):
+        to_columns([1])
+
+
+class _ArrowSchema(ctypes.Structure):
+    pass
+
+
+_ArrowSchema._fields_ = [
+    ("format", ctypes.c_char_p),
+    ("name", ctypes.c_char_p),
+    ("metadata", ctypes.c_char_p),
+    ("flags", ctypes.c_int64),
+    ("n_children", ctypes.c_int64),
+    ("children", ctypes.POINTER(ctypes.POINTER(_ArrowSchema))),
+    ("dictionary", ctypes.POINTER(_ArrowSchema)),
+    ("release", ctypes.c_void_p),
+    ("private_data", ctypes.c_void_p),
+]
+
+
+class _ArrowArray(ctypes.Structure):
+    _fields_ = [
+        ("length", ctypes.c_int64),
+        ("null_count", ctypes.c_int64),
+        ("offset", ctypes.c_int64),
+        ("n_buffers", ctypes.c_int64),
+        ("n_children", ctypes.c_int64),
+        ("buffers", ctypes.POINTER(ctypes.c_void_p)),
+        ("children", ctypes.c_void_p),
+        ("dictionary", ctypes.c_void_p),
+        ("release", ctypes.c_void_p),
+        ("private_data", ctypes.c_void_p),
+    ]
+
+
+_capsule_pointer = ctypes.pythonapi.PyCapsule_GetPointer
+_capsule_pointer.restype = ctypes.c_void_p
+_capsule_pointer.argtypes = [ctypes.py_object, ctypes.c_char_p]
+
+
+def _read_arrow(column):
+    """Read an exported column as (format, null count, values) for int64 and utf8."""
+    schema_capsule, array_capsule = column.__arrow_c_array__()
+    schema = _ArrowSchema.from_address(_capsule_pointer(schema_capsule, b"arrow_schema"))
+    array = _ArrowArray.from_address(_capsule_pointer(array_capsule, b"arrow_array"))
+    kind = schema.format.decode()
+    type_capsule = column.__arrow_c_schema__()
+    assert _ArrowSchema.from_address(_capsule_pointer(type_capsule, b"arrow_schema")).format.decode() == kind
+    values = None
+    if kind == "l":
+        values = list((ctypes.c_int64 * array.length).from_address(array.buffers[1]))
+    elif kind == "u":
+        offsets = (ctypes.c_int32 * (array.length + 1)).from_address(array.buffers[1])
+        values = [
+            ctypes.string_at(array.buffers[2] + offsets[i], offsets[i + 1] - offsets[i]).decode()
+            for i in range(array.length)
+        ]
+    return kind, array.null_count, values
+
+
+def test_to_arrow_builds_typed_arrays(mock_api, client, monkeypatch):
+    """Test the arrays handed over the Arrow PyCapsule interface, using a stand-in module."""
+    from cdg_python_client import to_arrow
+
+    fake = types.ModuleType("pyarrow")
+    fake.array = _read_arrow
+    fake.RecordBatch = types.SimpleNamespace(from_arrays=lambda arrays, names: dict(zip(names, arrays)))
+    monkeypatch.setitem(sys.modules, "pyarrow", fake)
+
+    batch = to_arrow(_bills(mock_api, client))
+
+    assert batch["congress"] == ("l", 0, [118, 118])
+    assert batch["number"] == ("u", 0, ["1", "2"])
+    assert batch["latestAction.text"] == ("u", 1, ["Received in the Senate.", ""])
+    assert batch["latestAction.actionDate"][:2] == ("tdD", 1)
+    assert batch["updateDateIncludingText"][:2] == ("tsu:UTC", 1)
+
+
+def test_to_arrow_with_pyarrow(mock_api, client):
+    """Test the record batch built by the real pyarrow when it is installed."""
+    pa = pytest.importorskip("pyarrow")
+    from cdg_python_client import to_arrow
+
+    batch = to_arrow(_bills(mock_api, client))
+
+    assert isinstance(batch, pa.RecordBatch)
+    assert batch.schema.field("congress").type == pa.int64()
+    assert batch.schema.field("number").type == pa.string()
+    assert batch.schema.field("latestAction.actionDate").type == pa.date32()
+    assert batch.schema.field("updateDateIncludingText").type == pa.timestamp("us", tz="UTC")
+    assert batch.column("congress").to_pylist() == [118, 118]
+    assert batch.column("latestAction.text").to_pylist() == ["Received in the Senate.", None]
+    assert batch.column("latestAction.actionDate").to_pylist() == [date(2023, 3, 30), None]
+    assert batch.column("updateDateIncludingText").to_pylist() == [
+        datetime(2024, 7, 23, 8, 5, 37, tzinfo=timezone.utc),
+        None,
+    ]
+
+
+@pytest.mark.parametrize("helper, module", [
+    ("to_arrow", "pyarrow"),
+    ("to_pandas", "pandas"),
+])
+def test_missing_dependency(monkeypatch, helper, module):
+    """Test that a missing optional package names what to install."""
+    import cdg_python_client
+
+    monkeypatch.setitem(sys.modules, module, None)
+
+    with pytest.raises(ImportError, match=f"{helper}\\(\\) requires {module}; install it"):
+        getattr(cdg_python_client, helper)([])
+
+
+def test_pandas_round_trip(mock_api, client):
+    """Test the real pyarrow and pandas conversion when they are installed."""
+    pytest.importorskip("pyarrow")
+    pytest.importorskip("pandas")
+    from cdg_python_client import to_arrow
+
+    bills = _bills(mock_api, client)
+    batch = to_arrow(bills)
+    df = batch.to_pandas()
+
+    assert batch.num_rows == 2
+    assert str(batch.schema.field("latestAction.actionDate").type) == "date32[day]"
+    assert list(df["number"]) == ["1", "2"]
@@ -0,0 +1,180 @@
+"""Tests for AsyncCDGPythonClient."""
+
+import pytest
+
+
+def _bills(*numbers):
+    return [{"congress": 118, "number": str(n), "type": "HR"} for n in numbers]
+
+
+def _page(items, next_offset=None):
+    """Build a bills list response with an optional `next` link."""
+    pagination = {"count": 3}
+    if next_offset is not None:
+        pagination["next"] = f"https://api.congress.gov/v3/bill?offset={next_offset}&limit=2&format=json"
+    return {"bills": items, "pagination": pagination}
+
+
+def test_list_method_returns_shared_models(mock_api, async_client, api_key):
+    """Test that coroutines resolve to the same model types as the sync client."""
+    import asyncio
+    from cdg_python_client import Bill
+
+    mock_api.add({"bills": _bills(1, 2)})
+
+    async def run():
+        return await async_client.list_bills_by_congress(118, limit=2)
+
+    bills = asyncio.run(run())
+
+    assert all(isinstance(b, Bill) for b in bills)
+    assert [b.number for b in bills] == ["1", "2"]
+    request = mock_api.requests[0]
+    assert request["path"] == "/bill/118"
+    assert request["query"]["limit"] == ["2"]
+    assert request["headers"]["x-api-key"] == api_key
+
+
+def test_get_method_returns_detail(mock_api, async_client):
+    """Test a detail endpoint through the async client."""
+    import asyncio
+
+    mock_api.add({"bill": {"congress": 118, "number": "1", "type": "HR", "title": "Lower Energy Costs Act"}})
+
+    async def run():
+        return await async_client.get_bill(118, "hr", 1)
+
+    bill = asyncio.run(run())
+
+    assert bill.title == "Lower Energy Costs Act"
+    assert mock_api.requests[0]["path"] == "/bill/118/hr/1"
+
+
+def test_errors_are_raised_on_await(mock_api, async_client):
+    """Test that API errors surface as the usual typed exceptions."""
+    import asyncio
+    from cdg_python_client import NotFoundError
+
+    mock_api.add({"error": "Unknown resource"}, status=404)
+
+    async def run():
+        return await async_client.get_bill(118, "hr", 99999)
+
+    with pytest.raises(NotFoundError):
+        asyncio.run(run())
+
+
+def test_retries_apply(mock_api):
+    """Test that transient failures are retried as in the sync client."""
+    import asyncio
+    from cdg_python_client import AsyncCDGPythonClient
+
+    mock_api.add({"error": "unavailable"}, status=503)
+    mock_api.add({"bills": _bills(1)})
+    client = AsyncCDGPythonClient(api_key="test_key", base_url=mock_api.url, backoff_factor=0.0)
+
+    async def run():
+        return await client.list_bills()
+
+    bills = asyncio.run(run())
+
+    assert len(bills) == 1
+    assert client.retry_stats().retries == 1
+
+
+def test_async_iterator_follows_next_links(mock_api, async_client):
+    """Test that `async for` walks every page, fetching lazily."""
+    import asyncio
+
+    mock_api.add(_page(_bills(1, 2), next_offset=2))
+    mock_api.add(_page(_bills(3)))
+
+    async def collect():
+        iterator = async_client.iter_bills(page_size=2)
+        assert iterator.pages_fetched == 0
+        numbers = [bill.number async for bill in iterator]
+        return numbers, iterator.pages_fetched
+
+    numbers, pages_fetched = asyncio.run(collect())
+
+    assert numbers == ["1", "2", "3"]
+    assert pages_fetched == 2
+    assert [r["query"]["offset"] for r in mock_api.requests] == [["0"], ["2"]]
+
+
+def test_async_iterator_respects_max_items(mock_api, async_client):
+    """Test that max_items stops async iteration and shrinks the last page."""
+    import asyncio
+
+    mock_api.add(_page(_bills(1, 2), next_offset=2))
+    mock_api.add(_page(_bills(3), next_offset=4))
+
+    async def collect():
+        return [bill.number async for bill in async_client.iter_bills(page_size=2, max_items=3)]
+
+    assert asyncio.run(collect()) == ["1", "2", "3"]
+    assert [r["query"]["limit"] for r in mock_api.requests] == [["2"], ["1"]]
+
+
+def test_page_and_count(mock_api, async_client):
+    """Test the async list_*_page and count_* variants."""
+    import asyncio
+
+    mock_api.add(_page(_bills(1, 2), next_offset=2))
+    mock_api.add(_page(_bills(1)))
+
+    async def run():
+        page = await async_client.list_bills_page(limit=2)
+        count = await async_client.count_bills()
+        return page, count
+
+    page, count = asyncio.run(run())
+
+    assert len(page) == 2
+    assert page.next_offset == 2
+    assert count == 3
+    assert mock_api.requests[1]["query"]["limit"] == ["1"]
+
+
+def test_requests_run_concurrently(mock_api, async_client):
+    """Test that gathered calls overlap instead of running back to back."""
+    import asyncio
+    import time
+
+    for _ in range(3):
+        mock_api.add({"bills": []}, delay=0.5)
+
+    async def run():
+        return await asyncio.gather(*(async_client.list_bills() for _ in range(3)))
+
+    start = time.monotonic()
+    asyncio.run(run())
+
+    assert time.monotonic() - start < 1.2
+
+
+def test_max_concurrency_limits_requests_in_flight(mock_api):
+    """Test that max_concurrency=1 serializes gathered calls."""
+    import asyncio
+    import time
+    from cdg_python_client import AsyncCDGPythonClient
+
+    for _ in range(3):
+        mock_api.add({"bills": []}, delay=0.3)
+    client = AsyncCDGPythonClient(api_key="test_key", base_url=mock_api.url, max_concurrency=1)
+
+    async def run():
+        return await asyncio.gather(*(client.list_bills() for _ in range(3)))
+
+    start = time.monotonic()
+    asyncio.run(run())
+
+    assert time.monotonic() - start >= 0.9
+
+
+def test_invalid_max_concurrency():
+    """Test that max_concurrency must be at least 1."""
+    from cdg_python_client import AsyncCDGPythonClient
+
+    with pytest.raises(ValueError, match="max_concurrency"):
+        AsyncCDGPythonClient(api_key="test_key", max_concurrency=0)
@@ -0,0 +1,170 @@
+"""Tests for the on-disk response cache."""
+
+import pytest
+
+
+BILL = {"bill": {"congress": 118, "number": "1", "type": "HR", "title": "Original"}}
+UPDATED_BILL = {"bill": {"congress": 118, "number": "1", "type": "HR", "title": "Updated"}}
+
+
+def test_repeated_request_is_served_from_cache(mock_api, make_client, tmp_path):
+    """Test that a fresh entry answers without contacting the API."""
+    mock_api.add(BILL)
+    client = make_client(cache_dir=tmp_path)
+
+    first = client.get_bill(118, "hr", 1)
+    second = client.get_bill(118, "hr", 1)
+
+    assert first.title == second.title == "Original"
+    assert len(mock_api.requests) == 1
+    stats = client.cache_stats()
+    assert (stats.hits, stats.misses, stats.entries) == (1, 1, 1)
+    assert stats.size_bytes > 0
+    assert stats.directory == str(tmp_path)
+
+
+def test_params_are_part_of_the_key(mock_api, make_client, tmp_path):
+    """Test that different query parameters get separate entries."""
+    mock_api.add({"bills": []})
+    mock_api.add({"bills": []})
+    client = make_client(cache_dir=tmp_path)
+
+    client.list_bills(limit=1)
+    client.list_bills(limit=2)
+    client.list_bills(limit=1)
+
+    assert len(mock_api.requests) == 2
+    assert client.cache_stats().entries == 2
+
+
+def test_api_key_is_never_stored(mock_api, make_client, api_key, tmp_path):
+    """Test that neither file names nor contents carry the API key."""
+    mock_api.add({"bill": {"number": "1", "url": f"https://api.congress.gov/v3/bill?api_key={api_key}"}})
+    client = make_client(cache_dir=tmp_path)
+
+    client.get_bill(118, "hr", 1)
+
+    files = list(tmp_path.iterdir())
+    assert len(files) == 1
+    assert api_key not in files[0].name
+    assert api_key not in files[0].read_text()
+
+
+def test_entries_persist_across_clients(mock_api, make_client, tmp_path):
+    """Test that a new client reuses entries written by an earlier one."""
+    mock_api.add(BILL)
+    make_client(cache_dir=tmp_path).get_bill(118, "hr", 1)
+
+    client = make_client(cache_dir=tmp_path)
+    assert client.get_bill(118, "hr", 1).title == "Original"
+    assert len(mock_api.requests) == 1
+
+
+def test_stale_entry_is_revalidated_with_etag(mock_api, make_client, tmp_path):
+    """Test that a 304 answer to a conditional request reuses the entry."""
+    mock_api.add(BILL, headers={"ETag": '"v1"', "Last-Modified": "Mon, 01 Jan 2024 00:00:00 GMT"})
+    mock_api.add("", status=304)
+    client = make_client(cache_dir=tmp_path, cache_ttl=0)
+
+    client.get_bill(118, "hr", 1)
+    bill = client.get_bill(118, "hr", 1)
+
+    assert bill.title == "Original"
+    conditional = mock_api.requests[1]["headers"]
+    assert conditional["if-none-match"] == '"v1"'
+    assert conditional["if-modified-since"] == "Mon, 01 Jan 2024 00:00:00 GMT"
+    stats = client.cache_stats()
+    assert (stats.hits, stats.misses, stats.revalidated) == (0, 1, 1)
+
+
+def test_stale_entry_is_replaced_when_changed(mock_api, make_client, tmp_path):
+    """Test that a full response to a conditional request replaces the entry."""
+    mock_api.add(BILL, headers={"ETag": '"v1"'})
+    mock_api.add(UPDATED_BILL, headers={"ETag": '"v2"'})
+    mock_api.add("", status=304)
+    client = make_client(cache_dir=tmp_path, cache_ttl=0)
+
+    client.get_bill(118, "hr", 1)
+    assert client.get_bill(118, "hr", 1).title == "Updated"
+    assert client.get_bill(118, "hr", 1).title == "Updated"
+
+    assert mock_api.requests[2]["headers"]["if-none-match"] == '"v2"'
+    assert client.cache_stats().misses == 2
+
+
+def test_stale_entry_without_validators_is_refetched(mock_api, make_client, tmp_path):
+    """Test that no conditional headers are sent without an ETag or Last-Modified."""
+    mock_api.add(BILL)
+    mock_api.add(UPDATED_BILL)
+    client = make_client(cache_dir=tmp_path, cache_ttl=0)
+
+    client.get_bill(118, "hr", 1)
+    assert client.get_bill(118, "hr", 1).title == "Updated"
+
+    assert "if-none-match" not in mock_api.requests[1]["headers"]
+
+
+def test_per_endpoint_ttls(mock_api, make_client, tmp_path):
+    """Test that the longest matching cache_ttls prefix wins."""
+    for _ in range(3):
+        mock_api.add({"bills": []})
+    client = make_client(cache_dir=tmp_path, cache_ttl=0, cache_ttls={"/law": None, "/law/117": 0})
+
+    client.list_laws_by_congress(118)
+    client.list_laws_by_congress(118)
+    client.list_laws_by_congress(117)
+    client.list_laws_by_congress(117)
+
+    assert [r["path"] for r in mock_api.requests] == ["/law/118", "/law/117", "/law/117"]
+
+
+def test_errors_are_not_cached(mock_api, make_client, tmp_path):
+    """Test that failed requests leave nothing behind."""
+    from cdg_python_client import NotFoundError
+
+    mock_api.add({"error": "Unknown resource"}, status=404)
+    mock_api.add(BILL)
+    client = make_client(cache_dir=tmp_path)
+
+    with pytest.raises(NotFoundError):
+        client.get_bill(118, "hr", 1)
+    assert client.get_bill(118, "hr", 1).title == "Original"
+    assert client.cache_stats().entries == 1
+
+
+def test_clear_cache(mock_api, make_client, tmp_path):
+    """Test that clear_cache deletes every entry."""
+    mock_api.add(BILL)
+    mock_api.add(BILL)
+    client = make_client(cache_dir=tmp_path)
+
+    client.get_bill(118, "hr", 1)
+    assert client.clear_cache() == 1
+    assert client.cache_stats().entries == 0
+
+    client.get_bill(118, "hr", 1)
+    assert len(mock_api.requests) == 2
+
+
+def test_cache_disabled_by_default(client):
+    """Test the cache methods on a client without cache_dir."""
+    stats = client.cache_stats()
+
+    assert stats.directory is None
+    assert (stats.hits, stats.misses, stats.entries) == (0, 0, 0)
+    assert client.clear_cache() == 0
+
+
+@pytest.mark.parametrize("kwargs, match", [
+    ({"cache_ttl": -1}, "cache_ttl"),
+    ({"cache_ttls": {"law": 60}}, "cache_ttls"),
+    ({"cache_ttls": {"/law": float("nan")}}, "cache_ttls"),
+    ({"cache_ttl": 1e300}, "cache_ttl"),
+    ({"cache_ttls": {"/law": 1e300}}, "cache_ttls"),
+])
+def test_invalid_cache_options(tmp_path, kwargs, match):
+    """Test that bad TTLs are rejected."""
+    from cdg_python_client import CDGPythonClient
+
+    with pytest.raises(ValueError, match=match):
+        CDGPythonClient(api_key="test_key", cache_dir=tmp_path, **kwargs)
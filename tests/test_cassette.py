@@ -0,0 +1,143 @@
+"""Tests for record/replay cassette mode."""
+
+from pathlib import Path
+
+import pytest
+
+
+CASSETTES = Path(__file__).with_name("cassettes")
+BILL = {"bill": {"congress": 118, "number": "1", "type": "HR", "title": "Lower Energy Costs Act"}}
+
+
+def _replay_client(cassette_dir, **kwargs):
+    """A client that must never reach the network: its base URL is unroutable."""
+    from cdg_python_client import CDGPythonClient
+    return CDGPythonClient(
+        api_key="any_key", base_url="http://127.0.0.1:9", mode="replay", cassette_dir=cassette_dir, **kwargs
+    )
+
+
+def test_recorded_responses_replay_offline(mock_api, tmp_path):
+    """Test that replay serves what record saw without any request."""
+    from cdg_python_client import CDGPythonClient
+
+    mock_api.add(BILL)
+    mock_api.add({"bills": [{"congress": 118, "number": "2", "type": "HR"}]})
+    recorder = CDGPythonClient(api_key="test_key", base_url=mock_api.url, mode="record", cassette_dir=tmp_path)
+    recorder.get_bill(118, "hr", 1)
+    recorder.list_bills_by_congress(118, limit=1)
+
+    client = _replay_client(tmp_path)
+    assert client.get_bill(118, "hr", 1).title == "Lower Energy Costs Act"
+    assert [b.number for b in client.list_bills_by_congress(118, limit=1)] == ["2"]
+    assert len(mock_api.requests) == 2
+
+
+def test_api_key_is_redacted_on_tape(mock_api, tmp_path):
+    """Test that tapes never contain the API key."""
+    from cdg_python_client import CDGPythonClient
+
+    mock_api.add({"bill": {"number": "1", "url": "https://api.congress.gov/v3/bill/118/hr/1?api_key=secret_api_key_123"}})
+    client = CDGPythonClient(api_key="secret_api_key_123", base_url=mock_api.url, mode="record", cassette_dir=tmp_path)
+    client.get_bill(118, "hr", 1)
+
+    tapes = list(tmp_path.iterdir())
+    assert [t.name.split("-")[0] for t in tapes] == ["bill_118_hr_1"]
+    assert "secret_api_key_123" not in tapes[0].read_text()
+
+
+def test_unmatched_request_fails_loudly(tmp_path):
+    """Test that replaying an unrecorded request raises CassetteError."""
+    from cdg_python_client import CassetteError, CongressApiError
+
+    client = _replay_client(tmp_path)
+
+    with pytest.raises(CassetteError, match="no recorded response") as excinfo:
+        client.list_bills_by_congress(117, limit=5)
+    assert isinstance(excinfo.value, CongressApiError)
+    assert excinfo.value.endpoint == "/bill/117"
+    assert '"limit": "5"' in str(excinfo.value)
+
+
+def test_retried_requests_replay_in_order(mock_api, tmp_path):
+    """Test that a 503 followed by a success replays as a retry."""
+    from cdg_python_client import CDGPythonClient
+
+    mock_api.add({"error": "unavailable"}, status=503)
+    mock_api.add(BILL)
+    recorder = CDGPythonClient(
+        api_key="test_key", base_url=mock_api.url, backoff_factor=0.0, mode="record", cassette_dir=tmp_path
+    )
+    recorder.get_bill(118, "hr", 1)
+
+    client = _replay_client(tmp_path)
+    assert client.get_bill(118, "hr", 1).title == "Lower Energy Costs Act"
+    assert client.retry_stats().retries == 1
+    # Once the tape is used up its last response keeps being served
+    assert client.get_bill(118, "hr", 1).title == "Lower Energy Costs Act"
+
+
+def test_errors_replay(mock_api, tmp_path):
+    """Test that recorded API errors are raised again on replay."""
+    from cdg_python_client import CDGPythonClient, NotFoundError
+
+    mock_api.add({"error": "Unknown resource"}, status=404)
+    recorder = CDGPythonClient(api_key="test_key", base_url=mock_api.url, mode="record", cassette_dir=tmp_path)
+    with pytest.raises(NotFoundError):
+        recorder.get_bill(118, "hr", 99999)
+
+    with pytest.raises(NotFoundError, match="Unknown resource"):
+        _replay_client(tmp_path).get_bill(118, "hr", 99999)
+
+
+def test_rerecording_replaces_tape(mock_api, tmp_path):
+    """Test that a new recording session overwrites the old responses."""
+    from cdg_python_client import CDGPythonClient
+
+    mock_api.add(BILL)
+    mock_api.add({"bill": {"congress": 118, "number": "1", "type": "HR", "title": "Renamed"}})
+    for _ in range(2):
+        CDGPythonClient(
+            api_key="test_key", base_url=mock_api.url, mode="record", cassette_dir=tmp_path
+        ).get_bill(118, "hr", 1)
+
+    assert _replay_client(tmp_path).get_bill(118, "hr", 1).title == "Renamed"
+
+
+def test_replay_checked_in_cassette():
+    """Test replaying a cassette committed to the repository."""
+    client = _replay_client(CASSETTES)
+
+    bills = client.list_bills_by_congress(118, limit=2)
+
+    assert [b.number for b in bills] == ["8070", "7024"]
+    assert client.count_bills_by_congress(118) == 19315
+
+
+def test_async_client_replays(tmp_path, mock_api):
+    """Test that the async client shares the cassette support."""
+    import asyncio
+    from cdg_python_client import AsyncCDGPythonClient, CDGPythonClient
+
+    mock_api.add(BILL)
+    CDGPythonClient(api_key="test_key", base_url=mock_api.url, mode="record", cassette_dir=tmp_path).get_bill(118, "hr", 1)
+    client = AsyncCDGPythonClient(api_key="any_key", base_url="http://127.0.0.1:9", mode="replay", cassette_dir=tmp_path)
+
+    async def run():
+        return await client.get_bill(118, "hr", 1)
+
+    assert asyncio.run(run()).title == "Lower Energy Costs Act"
+
+
+@pytest.mark.parametrize("kwargs, match", [
+    ({"mode": "record"}, "requires cassette_dir"),
+    ({"mode": "tape"}, "mode must be"),
+    ({"cassette_dir": "."}, "cassette_dir requires"),
+    ({"mode": "replay", "cassette_dir": "/nonexistent/cassettes"}, "does not exist"),
+])
+def test_invalid_mode_options(kwargs, match):
+    """Test validation of mode and cassette_dir."""
+    from cdg_python_client import CDGPythonClient
+
+    with pytest.raises(ValueError, match=match):
+        CDGPythonClient(api_key="test_key", **kwargs)
@@ -0,0 +1,185 @@
+"""Tests for the citation id types and methods accepting them."""
+
+import pytest
+
+
+@pytest.mark.parametrize("text", [
+    "H.R. 1234 (118th Congress)",
+    "hr1234-118",
+    "HR1234",
+    "118 H.R. 1234",
+    "H.R.1234, 118th Congress",
+    "https://www.congress.gov/bill/118th-congress/house-bill/1234/text",
+    "https://api.congress.gov/v3/bill/118/hr/1234?format=json",
+])
+def test_bill_citation_styles(text):
+    """Test that common bill citation styles parse to the same id."""
+    from cdg_python_client import BillId
+
+    assert BillId.parse(text, congress=118) == BillId(118, "hr", 1234)
+
+
+def test_bill_formats():
+    """Test the canonical citation, short form and congress.gov URL."""
+    from cdg_python_client import BillId, BillType
+
+    bill = BillId.parse("S. J. Res. 5-118")
+
+    assert bill.bill_type == BillType.SJRES
+    assert bill.citation == "S.J.Res. 5"
+    assert str(bill) == "S.J.Res. 5 (118th Congress)"
+    assert bill.slug == "sjres5-118"
+    assert bill.url == "https://www.congress.gov/bill/118th-congress/senate-joint-resolution/5"
+    assert repr(bill) == 'BillId(congress=118, bill_type="SJRES", number=5)'
+
+
+@pytest.mark.parametrize("cls, text, citation", [
+    ("LawId", "Pub. L. 118-5", "Pub. L. 118-5"),
+    ("LawId", "Public Law 118-5", "Pub. L. 118-5"),
+    ("LawId", "P.L. 118-5", "Pub. L. 118-5"),
+    ("LawId", "Private Law 117-2", "Priv. L. 117-2"),
+    ("ReportId", "H. Rept. 118-12", "H. Rept. 118-12"),
+    ("ReportId", "s.rept.118-3", "S. Rept. 118-3"),
+    ("ReportId", "Ex. Rept. 118-1", "Ex. Rept. 118-1"),
+    ("AmendmentId", "S.Amdt. 45-118", "S.Amdt. 45"),
+    ("AmendmentId", "S. Up. Amdt. 3 (117th Congress)", "S.Up.Amdt. 3"),
+])
+def test_other_citations(cls, text, citation):
+    """Test parsing and formatting laws, reports and amendments."""
+    import cdg_python_client
+
+    assert getattr(cdg_python_client, cls).parse(text).citation == citation
+
+
+@pytest.mark.parametrize("text, match", [
+    ("H.R. 1234", "no congress"),
+    ("H.R. 12-118 (117th Congress)", "not a bill citation"),
+    ("Pub. L. 118-5", "not a bill citation"),
+])
+def test_bad_citations(text, match):
+    """Test that missing or conflicting parts are rejected."""
+    from cdg_python_client import BillId
+
+    with pytest.raises(ValueError, match=match):
+        BillId.parse(text)
+
+
+def test_ids_are_hashable():
+    """Test equality and hashing so ids can key dicts."""
+    from cdg_python_client import BillId, ReportId
+
+    seen = {BillId(118, "hr", 1): "first"}
+
+    assert seen[BillId.parse("H.R. 1 (118th Congress)")] == "first"
+    assert ReportId(118, "hrpt", 12) != ReportId(118, "srpt", 12)
+
+
+def test_methods_accept_ids_and_citations(mock_api, client):
+    """Test passing an id or a citation string instead of three arguments."""
+    from cdg_python_client import BillId, ReportId
+
+    for _ in range(4):
+        mock_api.add({"bill": {}, "actions": [], "report": {}})
+
+    client.get_bill(id=BillId(118, "hr", 1))
+    client.get_bill_actions(id="S. 5 (117th Congress)", limit=10)
+    client.get_committee_report(id=ReportId.parse("H. Rept. 118-12"))
+    client.get_law(id="H.R. 346 (118th Congress)")
+
+    assert [request["path"] for request in mock_api.requests] == [
+        "/bill/118/hr/1",
+        "/bill/117/s/5/actions",
+        "/committee-report/118/hrpt/12",
+        "/law/118/hr/346",
+    ]
+    assert mock_api.requests[1]["query"]["limit"] == ["10"]
+
+
+def test_get_law_accepts_law_ids(mock_api, client):
+    """Test that a LawId is looked up in the law list, then fetched by bill."""
+    from cdg_python_client import LawId
+
+    other = {"laws": [{"number": "118-3", "type": "Public Law"}], "number": "1", "type": "HR"}
+    target = {"laws": [{"number": "118-4", "type": "Public Law"}], "number": "346", "type": "HR"}
+    mock_api.add({"bills": [other], "pagination": {"count": 2, "next": "https://api.congress.gov/v3/law/118/pub?offset=1"}})
+    mock_api.add({"bills": [target], "pagination": {"count": 2}})
+    mock_api.add({"bill": {"congress": 118, "number": "346"}})
+    mock_api.add({"bills": [target], "pagination": {"count": 1}})
+    mock_api.add({"bill": {"congress": 118, "number": "346"}})
+
+    law = client.get_law(id=LawId(118, "pub", 4))
+    client.get_law(id="Pub. L. 118-4")
+
+    assert law.number == "346"
+    assert [request["path"] for request in mock_api.requests] == [
+        "/law/118/pub",
+        "/law/118/pub",
+        "/law/118/hr/346",
+        "/law/118/pub",
+        "/law/118/hr/346",
+    ]
+    assert mock_api.requests[1]["query"]["offset"] == ["1"]
+
+
+def test_get_law_unknown_law_id(mock_api, client):
+    """Test that a law missing from the list raises NotFoundError."""
+    from cdg_python_client import NotFoundError
+
+    mock_api.add({"bills": [], "pagination": {"count": 0}})
+
+    with pytest.raises(NotFoundError, match="Priv. L. 118-1"):
+        client.get_law(id="Priv. L. 118-1")
+    assert mock_api.requests[0]["path"] == "/law/118/priv"
+
+
+def test_get_law_rejects_parts_after_law_id(mock_api, client):
+    """Test that a LawId cannot be combined with a bill type and number."""
+    from cdg_python_client import LawId
+
+    with pytest.raises(TypeError, match="a LawId already has a congress, type and number"):
+        client.get_law(118, "hr", "346", id=LawId(118, "pub", 4))
+    assert mock_api.requests == []
+
+
+@pytest.mark.parametrize("args, kwargs, match", [
+    ((118,), {}, "missing congress, type or number"),
+    ((118, "hr"), {}, "missing congress, type or number"),
+    ((), {}, "missing congress, type or number"),
+    ((118, "hr", 1), {"id": "hr1-118"}, "already has a congress, type and number"),
+    ((118,), {"id": "hr1-118"}, "already has a congress, type and number"),
+    ((), {"id": 1.5}, "expected a BillId or citation string, got float"),
+    (("hr1-118",), {}, "argument 'congress'"),
+])
+def test_argument_mistakes_fail_before_sending(mock_api, client, args, kwargs, match):
+    """Test mixing an id with explicit parts, or leaving parts out."""
+    with pytest.raises(TypeError, match=match):
+        client.get_bill(*args, **kwargs)
+    assert mock_api.requests == []
+
+
+def test_async_client_accepts_ids(mock_api, async_client):
+    """Test that the async client takes ids too."""
+    import asyncio
+    from cdg_python_client import BillId
+
+    mock_api.add({"bill": {}})
+
+    async def run():
+        return await async_client.get_bill(id=BillId.parse("hjres7-118"))
+
+    asyncio.run(run())
+    assert mock_api.requests[0]["path"] == "/bill/118/hjres/7"
+
+
+def test_async_get_law_accepts_law_ids(mock_api, async_client):
+    """Test the async client's LawId lookup."""
+    import asyncio
+
+    mock_api.add({"bills": [{"laws": [{"number": "118-4", "type": "Public Law"}], "number": "346", "type": "HR"}]})
+    mock_api.add({"bill": {"number": "346"}})
+
+    async def run():
+        return await async_client.get_law(id="P.L. 118-4")
+
+    assert asyncio.run(run()).number == "346"
+    assert [request["path"] for request in mock_api.requests] == ["/law/118/pub", "/law/118/hr/346"]
@@ -0,0 +1,121 @@
+"""Tests for the committee meeting endpoints and models."""
+
+from datetime import datetime, timezone
+
+import pytest
+
+
+MEETING = {
+    "chamber": "House",
+    "committees": [{"name": "House Natural Resources Subcommittee on Indian and Insular Affairs", "systemCode": "hsii24"}],
+    "congress": 118,
+    "date": "2023-03-24T14:00:00Z",
+    "eventId": "115538",
+    "hearingTranscript": [],
+    "location": {"building": "Longworth House Office Building", "room": "1324"},
+    "meetingDocuments": [
+        {"description": None, "documentType": "Support Document", "format": "PDF", "name": "Hearing Notice", "url": "https://www.congress.gov/118/meeting/house/115538/documents/HHRG-118-II24-20230324-SD001.pdf"},
+    ],
+    "meetingStatus": "Scheduled",
+    "relatedItems": {
+        "bills": [{"congress": 118, "number": "1246", "type": "HR"}, {"congress": 118, "number": 1532, "type": "HR"}],
+        "nominations": [],
+        "treaties": [],
+    },
+    "title": "Legislative hearing on H.R. 1246 and H.R. 1532",
+    "type": "Hearing",
+    "updateDate": "2023-03-29 15:55:08+00:00",
+    "videos": [{"name": "Legislative Hearing on H.R. 1246 and H.R. 1532", "url": "https://www.congress.gov/event/118th-Congress/house-event/115538"}],
+    "witnessDocuments": [{"documentType": "Witness Statement", "format": "PDF", "url": "https://www.congress.gov/118/meeting/house/115538/witnesses/HHRG-118-II24-Wstate-NewlandB-20230324.pdf"}],
+    "witnesses": [{"name": "The Honorable Bryan Newland", "organization": "Bureau of Indian Affairs", "position": "Assistant Secretary"}],
+}
+
+
+def test_get_committee_meeting(mock_api, client):
+    """Test decoding the detail response into CommitteeMeetingDetail."""
+    from cdg_python_client import BillType, Chamber
+
+    mock_api.add({"committeeMeeting": MEETING})
+
+    meeting = client.get_committee_meeting(118, "house", 115538)
+
+    assert mock_api.requests[0]["path"] == "/committee-meeting/118/house/115538"
+    assert meeting.chamber == Chamber.HOUSE
+    assert meeting.event_id == "115538"
+    assert meeting.meeting_status == "Scheduled"
+    assert meeting.meeting_type == "Hearing"
+    assert meeting.date == datetime(2023, 3, 24, 14, tzinfo=timezone.utc)
+    assert meeting.location.room == "1324"
+    assert meeting.committees[0].system_code == "hsii24"
+    assert meeting.witnesses[0].organization == "Bureau of Indian Affairs"
+    assert meeting.witness_documents[0].document_type == "Witness Statement"
+    assert meeting.meeting_documents[0].name == "Hearing Notice"
+    assert meeting.videos[0].url.endswith("/115538")
+    assert [bill.number for bill in meeting.related_items.bills] == [1246, 1532]
+    assert meeting.related_items.bills[0].bill_type == BillType.HR
+    assert repr(meeting.related_items) == "MeetingRelatedItems(bills=2, nominations=0, treaties=0)"
+    assert meeting.raw == MEETING
+
+
+def test_event_id_from_hearing(mock_api, client):
+    """Test passing the event id string a hearing's associated meeting carries."""
+    mock_api.add({"hearing": {"associatedMeeting": {"eventId": "115266"}}})
+    mock_api.add({"committeeMeeting": {"eventId": "115266"}})
+
+    hearing = client.get_hearing(117, "house", 48144)
+    meeting = client.get_committee_meeting(117, "house", hearing.associated_meeting.event_id)
+
+    assert mock_api.requests[1]["path"] == "/committee-meeting/117/house/115266"
+    assert meeting.event_id == "115266"
+
+
+@pytest.mark.parametrize("event_id, error, match", [
+    ("115538/../1", ValueError, "invalid event id"),
+    ("", ValueError, "invalid event id"),
+    (1.5, TypeError, r"expected an event id \(int or str\), got float"),
+])
+def test_bad_event_ids_fail_before_sending(mock_api, client, event_id, error, match):
+    """Test that event ids are checked locally."""
+    with pytest.raises(error, match=match):
+        client.get_committee_meeting(118, "house", event_id)
+    assert mock_api.requests == []
+
+
+def test_list_committee_meetings(mock_api, client):
+    """Test the list endpoints, including paging and counting."""
+    from cdg_python_client import Chamber
+
+    item = {"chamber": "Senate", "congress": 118, "eventId": "335580", "updateDate": "2024-01-04 19:42:18+00:00"}
+    mock_api.add({"committeeMeetings": [item], "pagination": {"count": 1}})
+    mock_api.add({"committeeMeetings": [item], "pagination": {"count": 812, "next": "https://api.congress.gov/v3/committee-meeting/118?offset=1&limit=1"}})
+    mock_api.add({"committeeMeetings": [], "pagination": {"count": 9}})
+
+    meetings = client.list_committee_meetings_by_chamber(118, Chamber.SENATE, from_date_time="2024-01-01")
+    page = client.list_committee_meetings_by_congress_page(118, limit=1)
+    total = client.count_committee_meetings()
+
+    assert meetings[0].event_id == "335580"
+    assert meetings[0].chamber == Chamber.SENATE
+    assert page.count == 812
+    assert page.next_offset == 1
+    assert total == 9
+    assert [request["path"] for request in mock_api.requests] == [
+        "/committee-meeting/118/senate",
+        "/committee-meeting/118",
+        "/committee-meeting",
+    ]
+    assert mock_api.requests[0]["query"]["fromDateTime"] == ["2024-01-01T00:00:00Z"]
+
+
+def test_async_get_committee_meeting(mock_api, async_client):
+    """Test the async client's meeting detail."""
+    import asyncio
+
+    mock_api.add({"committeeMeeting": MEETING})
+
+    async def run():
+        return await async_client.get_committee_meeting(118, "house", "115538")
+
+    meeting = asyncio.run(run())
+    assert mock_api.requests[0]["path"] == "/committee-meeting/118/house/115538"
+    assert meeting.witnesses[0].name == "The Honorable Bryan Newland"
@@ -0,0 +1,73 @@
+"""Tests for the per-committee detail and sub-resource endpoints."""
+
+from datetime import date
+
+import pytest
+
+
+def test_get_committee_by_congress(mock_api, client):
+    """Test the historical committee detail path and its resource links."""
+    mock_api.add({"committee": {
+        "systemCode": "hsif00",
+        "isCurrent": True,
+        "reports": {"count": 1011, "url": "https://api.congress.gov/v3/committee/house/hsif00/reports"},
+        "communications": {"count": 8011, "url": "https://api.congress.gov/v3/committee/house/hsif00/house-communication"},
+    }})
+
+    committee = client.get_committee_by_congress(110, "House", "hsif00")
+
+    assert mock_api.requests[0]["path"] == "/committee/110/house/hsif00"
+    assert committee.system_code == "hsif00"
+    assert committee.reports.count == 1011
+    assert committee.communications.count == 8011
+
+
+@pytest.mark.parametrize("method, args, path, key, item, attr, value", [
+    ("get_committee_reports", ("house", "hsif00"), "/committee/house/hsif00/reports", "reports",
+     {"citation": "H. Rept. 109-570", "congress": 109, "number": "570", "type": "HRPT"}, "citation", "H. Rept. 109-570"),
+    ("get_committee_nominations", ("senate", "ssas00"), "/committee/senate/ssas00/nominations", "nominations",
+     {"citation": "PN2477", "congress": 117, "number": "2477", "receivedDate": "2022-08-03"}, "received_date", date(2022, 8, 3)),
+    ("get_committee_house_communications", ("hsif00",), "/committee/house/hsif00/house-communication", "houseCommunications",
+     {"chamber": "House", "congress": 114, "number": 3262, "referralDate": "2015-10-27"}, "referral_date", date(2015, 10, 27)),
+    ("get_committee_senate_communications", ("ssas00",), "/committee/senate/ssas00/senate-communication", "senateCommunications",
+     {"chamber": "Senate", "congress": 114, "number": 7402, "referralDate": "2015-10-27"}, "number", 7402),
+])
+def test_committee_children(mock_api, client, method, args, path, key, item, attr, value):
+    """Test the reports, nominations and communications children."""
+    mock_api.add({key: [item]})
+
+    items = getattr(client, method)(*args, limit=5)
+
+    assert mock_api.requests[0]["path"] == path
+    assert mock_api.requests[0]["query"]["limit"] == ["5"]
+    assert getattr(items[0], attr) == value
+    assert items[0].raw == item
+
+
+def test_reused_types(mock_api, client):
+    """Test that children decode into the existing list models."""
+    from cdg_python_client import Communication, ReportType
+
+    mock_api.add({"reports": [{"type": "HRPT"}]})
+    mock_api.add({"houseCommunications": [{"communicationType": {"code": "EC"}}]})
+
+    report = client.get_committee_reports("house", "hsif00")[0]
+    communication = client.get_committee_house_communications("hsif00")[0]
+
+    assert type(report).__name__ == "CommitteeReportItem"
+    assert report.report_type == ReportType.HRPT
+    assert isinstance(communication, Communication)
+
+
+def test_async_get_committee_nominations(mock_api, async_client):
+    """Test the async client's committee nominations."""
+    import asyncio
+
+    mock_api.add({"nominations": [{"citation": "PN12", "congress": 118}]})
+
+    async def run():
+        return await async_client.get_committee_nominations("senate", "ssju00")
+
+    nominations = asyncio.run(run())
+    assert mock_api.requests[0]["path"] == "/committee/senate/ssju00/nominations"
+    assert nominations[0].citation == "PN12"
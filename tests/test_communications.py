@@ -0,0 +1,138 @@
+"""Tests for the House and Senate communication endpoints and models."""
+
+from datetime import date
+
+import pytest
+
+
+HOUSE_COMMUNICATION = {
+    "abstract": "A letter from the Director, Regulatory Management Division, Environmental Protection Agency, transmitting the Agency's request for applications.",
+    "chamber": "House",
+    "committees": [{"name": "Energy and Commerce Committee", "referralDate": "2024-03-07", "systemCode": "hsif00", "url": "https://api.congress.gov/v3/committee/house/hsif00"}],
+    "communicationType": {"code": "EC", "name": "Executive Communication"},
+    "congress": 118,
+    "congressionalRecordDate": "2024-03-07",
+    "isRulemaking": "True",
+    "legalAuthority": "5 U.S.C. 801(a)(1)(A); Public Law 104-121, section 251; (110 Stat. 868)",
+    "matchingRequirements": [{"number": "8070", "url": "https://api.congress.gov/v3/house-requirement/8070"}],
+    "number": "3324",
+    "reportNature": "The Agency's request for applications.",
+    "sessionNumber": 2,
+    "submittingAgency": "Environmental Protection Agency",
+    "submittingOfficial": "Director, Regulatory Management Division",
+    "updateDate": "2024-09-03",
+}
+
+
+def test_get_house_communication(mock_api, client):
+    """Test decoding the House detail response into CommunicationDetail."""
+    from cdg_python_client import Chamber, CommunicationType
+
+    mock_api.add({"house-communication": HOUSE_COMMUNICATION})
+
+    communication = client.get_house_communication(118, CommunicationType.EC, 3324)
+
+    assert mock_api.requests[0]["path"] == "/house-communication/118/ec/3324"
+    assert communication.chamber == Chamber.HOUSE
+    assert communication.communication_type.code == CommunicationType.EC
+    assert communication.communication_type.name == "Executive Communication"
+    assert communication.number == 3324
+    assert communication.abstract_text.startswith("A letter from the Director")
+    assert communication.congressional_record_date == date(2024, 3, 7)
+    assert communication.is_rulemaking is True
+    assert communication.session_number == 2
+    assert communication.submitting_agency == "Environmental Protection Agency"
+    assert communication.committees[0].referral_date == date(2024, 3, 7)
+    assert communication.committees[0].system_code == "hsif00"
+    assert communication.matching_requirements[0].number == 8070
+    assert communication.raw == HOUSE_COMMUNICATION
+    assert repr(communication) == (
+        'CommunicationDetail(congress=Some(118), chamber=Some("House"), '
+        'type=Some("EC"), number=Some(3324))'
+    )
+
+
+def test_get_senate_communication(mock_api, client):
+    """Test the Senate detail, which carries fewer fields."""
+    from cdg_python_client import CommunicationType
+
+    mock_api.add({"senateCommunication": {
+        "abstract": "A petition from the Legislature of the State of Texas.",
+        "chamber": "Senate",
+        "communicationType": {"code": "POM", "name": "Petition or Memorial"},
+        "congress": 118,
+        "number": 1,
+    }})
+
+    communication = client.get_senate_communication(118, "POM", 1)
+
+    assert mock_api.requests[0]["path"] == "/senate-communication/118/pom/1"
+    assert communication.communication_type.code == CommunicationType.POM
+    assert communication.is_rulemaking is None
+    assert communication.matching_requirements is None
+
+
+@pytest.mark.parametrize("method, code, match", [
+    ("get_house_communication", "pom", "House communications have no type \"pom\"; expected one of ec, ml, pm, pt"),
+    ("get_senate_communication", "pt", "Senate communications have no type \"pt\"; expected one of ec, pm, pom"),
+    ("get_house_communication", "xx", "unknown communication type"),
+])
+def test_type_is_checked_per_chamber(mock_api, client, method, code, match):
+    """Test that a type the chamber does not use is refused before sending."""
+    with pytest.raises(ValueError, match=match):
+        getattr(client, method)(118, code, 1)
+    assert mock_api.requests == []
+
+
+def test_list_communications(mock_api, client):
+    """Test the list endpoints of both chambers, including paging and counting."""
+    from cdg_python_client import CommunicationType
+
+    mock_api.add({"houseCommunications": [{
+        "chamber": "House",
+        "communicationNumber": 2057,
+        "communicationType": {"code": "EC", "name": "Executive Communication"},
+        "congressNumber": 117,
+        "url": "https://api.congress.gov/v3/house-communication/117/ec/2057",
+    }]})
+    mock_api.add({"senateCommunications": [{
+        "chamber": "Senate",
+        "communicationType": {"code": "PM", "name": "Presidential Message"},
+        "congress": 117,
+        "number": "1615",
+        "updateDate": "2021-09-01",
+    }], "pagination": {"count": 88}})
+    mock_api.add({"houseCommunications": [], "pagination": {"count": 9}})
+
+    house = client.list_house_communications_by_type(117, "ec", limit=1)
+    page = client.list_senate_communications_by_congress_page(117)
+    total = client.count_house_communications()
+
+    assert house[0].congress == 117
+    assert house[0].number == 2057
+    assert repr(house[0]) == (
+        'Communication(congress=Some(117), chamber=Some("House"), type=Some("EC"), number=Some(2057))'
+    )
+    assert page.items[0].communication_type.code == CommunicationType.PM
+    assert page.items[0].number == 1615
+    assert page.count == 88
+    assert total == 9
+    assert [request["path"] for request in mock_api.requests] == [
+        "/house-communication/117/ec",
+        "/senate-communication/117",
+        "/house-communication",
+    ]
+
+
+def test_async_get_senate_communication(mock_api, async_client):
+    """Test the async client's Senate communication detail."""
+    import asyncio
+
+    mock_api.add({"senateCommunication": {"congress": 118, "number": 2, "communicationType": {"code": "EC"}}})
+
+    async def run():
+        return await async_client.get_senate_communication(118, "ec", 2)
+
+    communication = asyncio.run(run())
+    assert mock_api.requests[0]["path"] == "/senate-communication/118/ec/2"
+    assert communication.number == 2
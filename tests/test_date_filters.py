@@ -0,0 +1,70 @@
+"""Tests for normalizing date filter arguments."""
+
+from datetime import date, datetime, timedelta, timezone
+
+import pytest
+
+
+def _sent_range(mock_api, client, **filters):
+    """Call list_bills with the filters and return the query it sent."""
+    mock_api.add({"bills": []})
+    client.list_bills(**filters)
+    query = mock_api.requests[-1]["query"]
+    return query.get("fromDateTime", [None])[0], query.get("toDateTime", [None])[0]
+
+
+@pytest.mark.parametrize("value, expected", [
+    ("2024-01-31T12:00:00Z", "2024-01-31T12:00:00Z"),
+    ("2024-01-31T12:00:00-05:00", "2024-01-31T17:00:00Z"),
+    ("2024-01-31T12:00:00", "2024-01-31T12:00:00Z"),
+    ("2024-01-31", "2024-01-31T00:00:00Z"),
+    (date(2024, 1, 31), "2024-01-31T00:00:00Z"),
+    (datetime(2024, 1, 31, 12, 30), "2024-01-31T12:30:00Z"),
+    (datetime(2024, 1, 31, 12, 30, tzinfo=timezone(timedelta(hours=2))), "2024-01-31T10:30:00Z"),
+])
+def test_from_values_are_normalized(mock_api, client, value, expected):
+    """Test that every accepted form is sent as YYYY-MM-DDTHH:MM:SSZ in UTC."""
+    assert _sent_range(mock_api, client, from_date_time=value) == (expected, None)
+
+
+def test_date_covers_the_whole_day(mock_api, client):
+    """Test that a date passed as the end of a range includes that day."""
+    sent = _sent_range(mock_api, client, from_date_time=date(2024, 1, 1), to_date_time=date(2024, 1, 31))
+
+    assert sent == ("2024-01-01T00:00:00Z", "2024-01-31T23:59:59Z")
+
+
+def test_house_vote_from_date(mock_api, client):
+    """Test the from_date/to_date spelling used by house vote methods."""
+    mock_api.add({"houseRollCallVotes": []})
+    client.list_house_votes(from_date=date(2023, 1, 9))
+
+    assert mock_api.requests[0]["query"]["fromDateTime"] == ["2023-01-09T00:00:00Z"]
+
+
+@pytest.mark.parametrize("filters, error, match", [
+    ({"from_date_time": "01/31/2024"}, ValueError, "invalid date"),
+    ({"from_date_time": "2024-02-30"}, ValueError, "invalid date"),
+    ({"from_date_time": 20240131}, TypeError, "expected a datetime, date or ISO 8601 string"),
+    ({"from_date_time": date(2024, 2, 1), "to_date_time": date(2024, 1, 31)}, ValueError, "empty date range"),
+])
+def test_invalid_filters_fail_before_sending(mock_api, client, filters, error, match):
+    """Test that bad values and reversed ranges never reach the API."""
+    with pytest.raises(error, match=match):
+        client.list_bills(**filters)
+    with pytest.raises(error, match=match):
+        client.iter_bills(**filters)
+    assert mock_api.requests == []
+
+
+def test_async_client_normalizes(mock_api, async_client):
+    """Test that the async client shares the normalization."""
+    import asyncio
+
+    mock_api.add({"bills": []})
+
+    async def run():
+        return await async_client.list_bills(to_date_time=date(2024, 1, 31))
+
+    asyncio.run(run())
+    assert mock_api.requests[0]["query"]["toDateTime"] == ["2024-01-31T23:59:59Z"]
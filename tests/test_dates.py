@@ -0,0 +1,59 @@
+"""Tests for parsing API dates into datetime objects."""
+
+from datetime import date, datetime, timedelta, timezone
+
+
+def test_dates_and_timestamps(mock_api, client):
+    """Test that plain dates become date and timestamps aware datetime."""
+    mock_api.add({
+        "bill": {
+            "introducedDate": "2023-03-14",
+            "updateDate": "2024-07-23T08:05:37Z",
+            "latestAction": {"actionDate": "2023-03-30", "text": "Received in the Senate."},
+        }
+    })
+
+    bill = client.get_bill(118, "hr", 1)
+
+    assert bill.introduced_date == date(2023, 3, 14)
+    assert type(bill.introduced_date) is date
+    assert bill.update_date == datetime(2024, 7, 23, 8, 5, 37, tzinfo=timezone.utc)
+    assert bill.latest_action.action_date == date(2023, 3, 30)
+
+
+def test_offsets_are_kept(mock_api, client):
+    """Test that a timestamp with an offset keeps it."""
+    mock_api.add({"houseRollCallVotes": [{"startDate": "2023-01-09T12:53:00-05:00"}]})
+
+    vote = client.list_house_votes()[0]
+
+    assert vote.start_date.utcoffset() == timedelta(hours=-5)
+    assert vote.start_date == datetime(2023, 1, 9, 17, 53, tzinfo=timezone.utc)
+
+
+def test_unparseable_dates_stay_strings(mock_api, client):
+    """Test the fallback to the original string."""
+    mock_api.add({"bill": {"introducedDate": "sometime in 2023", "updateDate": ""}})
+
+    bill = client.get_bill(118, "hr", 1)
+
+    assert bill.introduced_date == "sometime in 2023"
+    assert bill.update_date == ""
+
+
+def test_repr_shows_api_form(mock_api, client):
+    """Test that reprs print dates as the API sent them."""
+    mock_api.add({"bill": {"latestAction": {"actionDate": "2023-03-30", "text": "Passed"}}})
+
+    bill = client.get_bill(118, "hr", 1)
+
+    assert repr(bill.latest_action) == 'LatestAction(action_date=Some("2023-03-30"), text=Some("Passed"))'
+
+
+def test_xml_dates(mock_api, client):
+    """Test that XML responses get the same date types."""
+    mock_api.add("<api-root><bill><introducedDate>2023-03-14</introducedDate></bill></api-root>")
+
+    bill = client.get_bill(118, "hr", 1)
+
+    assert bill.introduced_date == date(2023, 3, 14)
@@ -0,0 +1,170 @@
+"""Tests for the typed enums used in method arguments and model fields."""
+
+import pytest
+
+
+def test_fields_decode_to_enums(mock_api, client):
+    """Test that known codes become members that still equal the API string."""
+    from cdg_python_client import BillType, Chamber
+
+    mock_api.add({"bill": {"type": "HR", "originChamber": "House", "number": "1"}})
+
+    bill = client.get_bill(118, "hr", 1)
+
+    assert isinstance(bill.bill_type, BillType)
+    assert bill.bill_type == "HR"
+    assert bill.origin_chamber == Chamber.HOUSE
+    assert str(bill.origin_chamber) == "House"
+    assert repr(bill).endswith('type=Some("HR"))')
+
+
+def test_unknown_codes_stay_strings(mock_api, client):
+    """Test the fallback for codes this client does not know yet."""
+    mock_api.add({"bill": {"type": "HXYZ", "originChamber": "Joint Session"}})
+
+    bill = client.get_bill(118, "hr", 1)
+
+    assert bill.bill_type == "HXYZ"
+    assert type(bill.bill_type) is str
+    assert bill.origin_chamber == "Joint Session"
+
+
+def test_vote_cast(mock_api, client):
+    """Test vote casts including the two-word "Not Voting"."""
+    from cdg_python_client import VoteCast
+
+    mock_api.add({
+        "houseRollCallVoteMemberVotes": {
+            "results": [{"voteCast": "Yea"}, {"voteCast": "Not Voting"}],
+        }
+    })
+
+    votes = client.get_house_vote_members(118, 1, 1).results
+
+    assert [vote.vote_cast for vote in votes] == [VoteCast.YEA, VoteCast.NOT_VOTING]
+    assert votes[1].vote_cast == "Not Voting"
+
+
+def test_vote_party(mock_api, client):
+    """Test party codes on member votes and party totals."""
+    from cdg_python_client import PartyCode
+
+    mock_api.add({
+        "houseRollCallVoteMemberVotes": {
+            "results": [{"voteParty": "D"}, {"voteParty": "ID"}, {"voteParty": "X"}],
+        }
+    })
+    mock_api.add({
+        "houseRollCallVote": {
+            "votePartyTotal": [{"party": {"name": "Republican", "type": "R"}, "voteParty": "R"}],
+        }
+    })
+
+    votes = client.get_house_vote_members(118, 1, 1).results
+    total = client.get_house_vote(118, 1, 1).vote_party_total[0]
+
+    assert [vote.vote_party for vote in votes] == [PartyCode.D, PartyCode.ID, "X"]
+    assert type(votes[2].vote_party) is str
+    assert total.vote_party == PartyCode.R
+    assert total.party.party_type == "R"
+    assert PartyCode("Independent Democrat") == PartyCode.ID
+
+
+@pytest.mark.parametrize("bill_type", ["hr", "HR", "Hr"])
+def test_arguments_accept_strings(mock_api, client, bill_type):
+    """Test that strings of any case are sent in the lowercase path form."""
+    mock_api.add({"bill": {}})
+
+    client.get_bill(118, bill_type, 1)
+
+    assert mock_api.requests[0]["path"] == "/bill/118/hr/1"
+
+
+def test_arguments_accept_members(mock_api, client):
+    """Test that enum members are sent in their path form."""
+    from cdg_python_client import BillType, Chamber, LawType
+
+    for _ in range(3):
+        mock_api.add({"bills": [], "committees": []})
+
+    client.list_bills_by_type(118, BillType.HJRES)
+    client.list_committees_by_chamber(Chamber.SENATE)
+    client.list_laws_by_type(118, LawType.PUBLIC)
+
+    assert [request["path"] for request in mock_api.requests] == [
+        "/bill/118/hjres",
+        "/committee/senate",
+        "/law/118/pub",
+    ]
+
+
+@pytest.mark.parametrize("value, error, match", [
+    ("HR ", ValueError, 'unknown bill type "HR "; expected one of hr, s, hjres'),
+    ("house", ValueError, "unknown bill type"),
+    (1, TypeError, "expected a bill type or str, got int"),
+])
+def test_bad_arguments_fail_before_sending(mock_api, client, value, error, match):
+    """Test that typos are caught locally instead of at the server."""
+    with pytest.raises(error, match=match):
+        client.get_bill(118, value, 1)
+    assert mock_api.requests == []
+
+
+def test_members_hash_like_their_value():
+    """Test construction, equality and hashing against plain strings."""
+    from cdg_python_client import BillType, Chamber, LawType
+
+    assert BillType("sjres") == BillType.SJRES
+    assert Chamber("House of Representatives") == Chamber.HOUSE
+    assert LawType("priv").value == "Private Law"
+    assert BillType.HR != "hr"
+    assert BillType.HR != BillType.S
+    assert {BillType.HR: 1}["HR"] == 1
+    assert len({BillType.HR, "HR"}) == 1
+    with pytest.raises(ValueError, match="unknown chamber"):
+        Chamber("Parliament")
+
+
+def test_members_are_str_enums():
+    """Test that the enums are iterable `enum.Enum` classes of strings."""
+    import enum
+    import pickle
+    from cdg_python_client import BillType, Chamber
+
+    assert issubclass(BillType, enum.Enum)
+    assert list(BillType)[:2] == [BillType.HR, BillType.S]
+    assert BillType["HJRES"] is BillType.HJRES
+    assert BillType("hjres") is BillType.HJRES
+    assert isinstance(Chamber.HOUSE, str)
+    assert Chamber.HOUSE.lower() == "house"
+    assert f"{Chamber.SENATE}" == "Senate"
+    assert Chamber.SENATE.name == "SENATE"
+    assert pickle.loads(pickle.dumps(BillType.SRES)) is BillType.SRES
+
+
+def test_fields_hold_the_canonical_spelling(mock_api, client):
+    """Test that a code sent in another case decodes to the canonical member."""
+    from cdg_python_client import BillType
+
+    mock_api.add({"bill": {"type": "hr"}})
+
+    bill = client.get_bill(118, "hr", 1)
+
+    assert bill.bill_type is BillType.HR
+    assert bill.bill_type == "HR"
+    assert bill.bill_type != "hr"
+
+
+def test_async_client_accepts_members(mock_api, async_client):
+    """Test that the async client takes the same arguments."""
+    import asyncio
+    from cdg_python_client import BillType
+
+    mock_api.add({"bill": {"type": "S"}})
+
+    async def run():
+        return await async_client.get_bill(118, BillType.S, 5)
+
+    bill = asyncio.run(run())
+    assert mock_api.requests[0]["path"] == "/bill/118/s/5"
+    assert bill.bill_type == BillType.S
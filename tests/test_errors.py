@@ -0,0 +1,57 @@
+"""Tests for the Python exception hierarchy."""
+
+import socket
+
+import pytest
+
+
+ERROR_CLASSES = [
+    "BadRequestError",
+    "AuthenticationError",
+    "NotFoundError",
+    "RateLimitError",
+    "ServerError",
+    "DecodeError",
+    "NetworkError",
+]
+
+
+def _closed_port():
+    """Return a local port with nothing listening on it."""
+    with socket.socket(socket.AF_INET, socket.SOCK_STREAM) as sock:
+        sock.bind(("127.0.0.1", 0))
+        return sock.getsockname()[1]
+
+
+def test_base_error_is_runtime_error():
+    """Test that the base class stays compatible with RuntimeError handlers."""
+    from cdg_python_client import CongressApiError
+
+    assert issubclass(CongressApiError, RuntimeError)
+
+
+@pytest.mark.parametrize("name", ERROR_CLASSES)
+def test_error_subclasses(name):
+    """Test that every specific error derives from CongressApiError."""
+    import cdg_python_client
+
+    error_class = getattr(cdg_python_client, name)
+    assert issubclass(error_class, cdg_python_client.CongressApiError)
+    assert name in cdg_python_client.__all__
+
+
+def test_network_error_attributes(monkeypatch):
+    """Test that a connection failure raises NetworkError with details."""
+    from cdg_python_client import CDGPythonClient, NetworkError
+
+    monkeypatch.setenv("HTTPS_PROXY", f"http://127.0.0.1:{_closed_port()}")
+    client = CDGPythonClient(api_key="test_key", max_retries=0)
+
+    with pytest.raises(NetworkError) as excinfo:
+        client.get_bill(congress=118, bill_type="hr", bill_number=1)
+
+    error = excinfo.value
+    assert error.status_code is None
+    assert error.endpoint == "/bill/118/hr/1"
+    assert error.body is None
+    assert error.retry_after is None
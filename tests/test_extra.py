@@ -0,0 +1,44 @@
+"""Tests for the extra dict holding undeclared JSON fields."""
+
+
+def test_unknown_fields_land_in_extra(mock_api, client):
+    """Test that undeclared keys are kept and declared ones are not."""
+    mock_api.add({
+        "bill": {
+            "congress": 118,
+            "number": "1",
+            "title": "Lower Energy Costs Act",
+            "cboCostEstimates": [{"title": "H.R. 1"}],
+            "newField": {"nested": True},
+        }
+    })
+
+    bill = client.get_bill(118, "hr", 1)
+
+    assert bill.title == "Lower Energy Costs Act"
+    assert bill.extra == {"cboCostEstimates": [{"title": "H.R. 1"}], "newField": {"nested": True}}
+
+
+def test_nested_models_have_extra(mock_api, client):
+    """Test that models nested in a result keep their own unknown keys."""
+    mock_api.add({
+        "bill": {
+            "number": "1",
+            "latestAction": {"actionDate": "2023-03-30", "text": "Passed", "actionTime": "12:00:00"},
+            "sponsors": [{"bioguideId": "S000522", "isByRequest": "N"}],
+        }
+    })
+
+    bill = client.get_bill(118, "hr", 1)
+
+    assert bill.latest_action.extra == {"actionTime": "12:00:00"}
+    assert bill.sponsors[0].extra == {"isByRequest": "N"}
+
+
+def test_extra_is_empty_without_unknown_fields(mock_api, client):
+    """Test that a fully modelled object has an empty extra dict."""
+    mock_api.add({"bills": [{"congress": 118, "number": "2", "type": "HR"}]})
+
+    bills = client.list_bills(limit=1)
+
+    assert bills[0].extra == {}
@@ -0,0 +1,92 @@
+"""Tests for the House requirement endpoints and HouseRequirement model."""
+
+from datetime import date
+
+
+REQUIREMENT = {
+    "activeRecord": True,
+    "frequency": "[No deadline specified].",
+    "legalAuthority": "5 U.S.C. 801(a)(1)(A); Public Law 104-121, section 251; (110 Stat. 868)",
+    "matchingCommunications": {"count": 90774, "url": "https://api.congress.gov/v3/house-requirement/8070/matching-communications"},
+    "nature": "Congressional review of agency rulemaking.",
+    "number": 8070,
+    "parentAgency": "Multiple Executive Agencies and Departments",
+    "submittingAgency": "Multiple Executive Agencies and Departments",
+    "updateDate": "2021-08-13",
+}
+
+
+def test_get_house_requirement(mock_api, client):
+    """Test decoding the detail response into HouseRequirement."""
+    mock_api.add({"houseRequirement": REQUIREMENT})
+
+    requirement = client.get_house_requirement(8070)
+
+    assert mock_api.requests[0]["path"] == "/house-requirement/8070"
+    assert requirement.number == 8070
+    assert requirement.active_record is True
+    assert requirement.frequency == "[No deadline specified]."
+    assert requirement.legal_authority.startswith("5 U.S.C. 801")
+    assert requirement.nature == "Congressional review of agency rulemaking."
+    assert requirement.submitting_agency == "Multiple Executive Agencies and Departments"
+    assert requirement.parent_agency == "Multiple Executive Agencies and Departments"
+    assert requirement.matching_communications.count == 90774
+    assert requirement.update_date == date(2021, 8, 13)
+    assert requirement.raw == REQUIREMENT
+    assert repr(requirement) == (
+        'HouseRequirement(number=Some(8070), nature=Some("Congressional review of agency rulemaking."))'
+    )
+
+
+def test_matching_communications(mock_api, client):
+    """Test that matching communications decode into Communication."""
+    from cdg_python_client import Chamber, Communication, CommunicationType
+
+    mock_api.add({"matchingCommunications": [{
+        "chamber": "House",
+        "communicationType": {"code": "EC", "name": "Executive Communication"},
+        "congress": 112,
+        "number": 2,
+        "url": "https://api.congress.gov/v3/house-communication/112/EC/2",
+    }], "pagination": {"count": 90774}})
+
+    communications = client.get_house_requirement_matching_communications(8070, limit=1)
+
+    assert mock_api.requests[0]["path"] == "/house-requirement/8070/matching-communications"
+    assert mock_api.requests[0]["query"]["limit"] == ["1"]
+    assert isinstance(communications[0], Communication)
+    assert communications[0].chamber == Chamber.HOUSE
+    assert communications[0].communication_type.code == CommunicationType.EC
+    assert communications[0].number == 2
+
+
+def test_list_house_requirements(mock_api, client):
+    """Test the list endpoint, including paging and counting."""
+    item = {"number": 8070, "updateDate": "2021-08-13", "url": "https://api.congress.gov/v3/house-requirement/8070"}
+    mock_api.add({"houseRequirements": [item], "pagination": {"count": 1}})
+    mock_api.add({"houseRequirements": [item], "pagination": {"count": 3721, "next": "https://api.congress.gov/v3/house-requirement?offset=1&limit=1"}})
+    mock_api.add({"houseRequirements": [], "pagination": {"count": 3721}})
+
+    requirements = client.list_house_requirements(limit=1)
+    page = client.list_house_requirements_page(limit=1)
+    total = client.count_house_requirements()
+
+    assert requirements[0].number == 8070
+    assert requirements[0].frequency is None
+    assert page.next_offset == 1
+    assert total == 3721
+    assert [request["path"] for request in mock_api.requests] == ["/house-requirement"] * 3
+
+
+def test_async_get_house_requirement(mock_api, async_client):
+    """Test the async client's requirement detail."""
+    import asyncio
+
+    mock_api.add({"houseRequirement": REQUIREMENT})
+
+    async def run():
+        return await async_client.get_house_requirement(8070)
+
+    requirement = asyncio.run(run())
+    assert mock_api.requests[0]["path"] == "/house-requirement/8070"
+    assert requirement.parent_agency == "Multiple Executive Agencies and Departments"
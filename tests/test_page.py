@@ -0,0 +1,71 @@
+"""Tests for Page results and count_* helpers."""
+
+
+def _bills_page(numbers, count, next_offset=None):
+    pagination = {"count": count}
+    if next_offset is not None:
+        pagination["next"] = f"https://api.congress.gov/v3/bill?offset={next_offset}&limit=2&format=json"
+    return {
+        "bills": [{"congress": 118, "number": str(n)} for n in numbers],
+        "pagination": pagination,
+        "request": {"contentType": "application/json", "format": "json"},
+    }
+
+
+def test_list_page_exposes_metadata(mock_api, client):
+    """Test that *_page returns items with count, next and request echo."""
+    from cdg_python_client import Bill, Page
+
+    mock_api.add(_bills_page([1, 2], count=17, next_offset=2))
+
+    page = client.list_bills_page(limit=2)
+
+    assert isinstance(page, Page)
+    assert len(page) == 2
+    assert all(isinstance(b, Bill) for b in page.items)
+    assert [b.number for b in page] == ["1", "2"]
+    assert page.count == 17
+    assert page.next.startswith("https://api.congress.gov/v3/bill?offset=2")
+    assert page.prev is None
+    assert page.next_offset == 2
+    assert page.request == {"contentType": "application/json", "format": "json"}
+
+
+def test_last_page_has_no_next(mock_api, client):
+    """Test the metadata of a final page."""
+    mock_api.add(_bills_page([17], count=17))
+
+    page = client.list_bills_by_congress_page(118, offset=16, limit=2)
+
+    assert page.next is None
+    assert page.next_offset is None
+    assert mock_api.requests[0]["query"]["offset"] == ["16"]
+
+
+def test_request_echo_values_are_strings(mock_api, client):
+    """Test that non-string request echo values are stringified."""
+    body = _bills_page([1], count=1)
+    body["request"] = {"congress": 118, "billType": "hr", "extra": None}
+    mock_api.add(body)
+
+    assert client.list_bills_page().request == {"congress": "118", "billType": "hr"}
+
+
+def test_count_requests_a_single_item(mock_api, client):
+    """Test that count_* asks for limit=1 and returns the total."""
+    mock_api.add(_bills_page([1], count=12345, next_offset=1))
+
+    assert client.count_bills_by_type(118, "hr", from_date_time="2024-01-01T00:00:00Z") == 12345
+
+    request = mock_api.requests[0]
+    assert request["path"] == "/bill/118/hr"
+    assert request["query"]["limit"] == ["1"]
+    assert "offset" not in request["query"]
+    assert request["query"]["fromDateTime"] == ["2024-01-01T00:00:00Z"]
+
+
+def test_count_without_pagination_block(mock_api, client):
+    """Test that count falls back to the number of items returned."""
+    mock_api.add({"members": [{"bioguideId": "A000001"}, {"bioguideId": "B000002"}]})
+
+    assert client.count_members_by_state_district("CA", 12) == 2
@@ -0,0 +1,107 @@
+"""Tests for the auto-paginating iter_* methods."""
+
+import pytest
+
+
+def _page(items, next_offset=None, key="bills", count=None):
+    """Build a list response with an optional `next` link."""
+    pagination = {"count": count if count is not None else len(items)}
+    if next_offset is not None:
+        pagination["next"] = f"https://api.congress.gov/v3/bill?offset={next_offset}&limit=2&format=json"
+    return {key: items, "pagination": pagination}
+
+
+def _bills(*numbers):
+    return [{"congress": 118, "number": str(n), "type": "HR"} for n in numbers]
+
+
+def test_iterator_follows_next_links(mock_api, client):
+    """Test that pages are fetched until there is no `next` link."""
+    mock_api.add(_page(_bills(1, 2), next_offset=2))
+    mock_api.add(_page(_bills(3)))
+
+    bills = list(client.iter_bills(page_size=2))
+
+    assert [b.number for b in bills] == ["1", "2", "3"]
+    requests = mock_api.requests
+    assert [r["query"]["offset"] for r in requests] == [["0"], ["2"]]
+    assert all(r["query"]["limit"] == ["2"] for r in requests)
+
+
+def test_iterator_is_lazy(mock_api, client):
+    """Test that no request is sent until the first item is needed."""
+    mock_api.add(_page(_bills(1, 2), next_offset=2))
+
+    iterator = client.iter_bills(page_size=2)
+    assert mock_api.requests == []
+    assert iterator.pages_fetched == 0
+
+    assert next(iterator).number == "1"
+    assert next(iterator).number == "2"
+    assert len(mock_api.requests) == 1
+    assert iterator.pages_fetched == 1
+
+
+def test_max_items_caps_requests(mock_api, client):
+    """Test that max_items stops iteration and shrinks the last page."""
+    mock_api.add(_page(_bills(1, 2), next_offset=2))
+    mock_api.add(_page(_bills(3), next_offset=4))
+
+    bills = list(client.iter_bills(page_size=2, max_items=3))
+
+    assert [b.number for b in bills] == ["1", "2", "3"]
+    assert [r["query"]["limit"] for r in mock_api.requests] == [["2"], ["1"]]
+
+
+def test_filters_and_start_offset_are_forwarded(mock_api, client):
+    """Test that filters are sent on every page and offset sets the start."""
+    mock_api.add(_page(_bills(11)))
+
+    list(client.iter_bills_by_type(
+        118, "hr", offset=10, from_date_time="2024-01-01T00:00:00Z",
+    ))
+
+    request = mock_api.requests[0]
+    assert request["path"] == "/bill/118/hr"
+    assert request["query"]["offset"] == ["10"]
+    assert request["query"]["limit"] == ["250"]
+    assert request["query"]["fromDateTime"] == ["2024-01-01T00:00:00Z"]
+
+
+def test_next_link_without_offset_falls_back_to_counting(mock_api, client):
+    """Test that the offset advances by the page length if `next` lacks one."""
+    first = _page(_bills(1, 2))
+    first["pagination"]["next"] = "https://api.congress.gov/v3/bill?cursor=abc"
+    mock_api.add(first)
+    mock_api.add(_page([]))
+
+    assert len(list(client.iter_bills(page_size=2))) == 2
+    assert [r["query"]["offset"] for r in mock_api.requests] == [["0"], ["2"]]
+
+
+def test_other_list_endpoints_iterate(mock_api, client):
+    """Test an endpoint whose items live under a different key."""
+    from cdg_python_client import HouseVote
+
+    mock_api.add(_page([{"rollCallNumber": 1}, {"rollCallNumber": 2}], key="houseRollCallVotes"))
+
+    votes = list(client.iter_house_votes_by_session(118, 1))
+
+    assert len(votes) == 2
+    assert all(isinstance(v, HouseVote) for v in votes)
+    assert mock_api.requests[0]["path"] == "/house-vote/118/1"
+
+
+def test_errors_propagate_from_next(client):
+    """Test that a failing page raises from next()."""
+    from cdg_python_client import NotFoundError
+
+    with pytest.raises(NotFoundError):
+        next(client.iter_bills())
+
+
+@pytest.mark.parametrize("page_size", [0, 251])
+def test_invalid_page_size_rejected(client, page_size):
+    """Test that page_size outside 1..250 raises ValueError."""
+    with pytest.raises(ValueError):
+        client.iter_bills(page_size=page_size)
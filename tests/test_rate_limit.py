@@ -0,0 +1,75 @@
+"""Tests for quota tracking and the client-side rate limiter."""
+
+import socket
+
+import pytest
+
+
+def _closed_port():
+    """Return a local port with nothing listening on it."""
+    with socket.socket(socket.AF_INET, socket.SOCK_STREAM) as sock:
+        sock.bind(("127.0.0.1", 0))
+        return sock.getsockname()[1]
+
+
+def test_rate_limit_status_before_any_request(client):
+    """Test that no quota is known before the first response."""
+    from cdg_python_client import RateLimitStatus
+
+    status = client.rate_limit_status()
+    assert isinstance(status, RateLimitStatus)
+    assert status.limit is None
+    assert status.remaining is None
+    assert status.seconds_since_update is None
+    assert status.requests_sent == 0
+    assert status.budget is None
+    assert status.budget_remaining is None
+
+
+def test_rate_limit_budget_reported():
+    """Test that a configured budget shows up in the status."""
+    from cdg_python_client import CDGPythonClient
+
+    client = CDGPythonClient(api_key="test_key", rate_limit=100)
+    status = client.rate_limit_status()
+    assert status.budget == 100
+    assert status.budget_remaining == 100
+
+
+@pytest.mark.parametrize("kwargs", [
+    {"rate_limit": 0},
+    {"rate_limit": 10, "rate_limit_period": 0.0},
+    {"rate_limit": 10, "rate_limit_period": 1e300},
+    {"rate_limit": 10, "rate_limit_mode": "sleep"},
+])
+def test_invalid_rate_limit_options_rejected(kwargs):
+    """Test that invalid limiter settings raise ValueError."""
+    from cdg_python_client import CDGPythonClient
+
+    with pytest.raises(ValueError):
+        CDGPythonClient(api_key="test_key", **kwargs)
+
+
+def test_raise_mode_fails_before_sending(monkeypatch):
+    """Test that an exhausted budget fails without another HTTP request."""
+    from cdg_python_client import CDGPythonClient, RateLimitError
+
+    monkeypatch.setenv("HTTPS_PROXY", f"http://127.0.0.1:{_closed_port()}")
+    client = CDGPythonClient(
+        api_key="test_key",
+        max_retries=0,
+        rate_limit=1,
+        rate_limit_mode="raise",
+    )
+
+    # The first request uses the only token (and fails to connect)
+    with pytest.raises(Exception):
+        client.list_bills(limit=1)
+    assert client.rate_limit_status().requests_sent == 1
+
+    with pytest.raises(RateLimitError, match="rate limit") as excinfo:
+        client.list_bills(limit=1)
+    assert excinfo.value.status_code is None
+    assert excinfo.value.retry_after > 0
+    assert client.rate_limit_status().requests_sent == 1
+    assert client.rate_limit_status().budget_remaining == 0
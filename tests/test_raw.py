@@ -0,0 +1,102 @@
+"""Tests for raw JSON access."""
+
+import pytest
+
+
+BILL = {
+    "bill": {
+        "congress": 118,
+        "number": "1",
+        "type": "HR",
+        "title": "Lower Energy Costs Act",
+        "latestAction": {"actionDate": "2023-03-30", "text": "Received in the Senate."},
+        "cboCostEstimates": [{"pubDate": "2023-03-27T19:07:00Z", "title": "H.R. 1"}],
+        "sponsors": [{"bioguideId": "S000522", "fullName": "Rep. Scalise, Steve [R-LA-1]"}],
+    }
+}
+
+
+def test_get_raw_returns_python_objects(mock_api, client):
+    """Test that JSON types map onto native Python types."""
+    mock_api.add({"count": 3, "ratio": 0.5, "ok": True, "none": None, "items": [{"a": "b"}], "big": 2**63})
+
+    data = client.get_raw("/bill/118/hr/1/actions")
+
+    assert data == {"count": 3, "ratio": 0.5, "ok": True, "none": None, "items": [{"a": "b"}], "big": 2**63}
+    assert type(data["count"]) is int and type(data["ok"]) is bool
+    assert mock_api.requests[0]["path"] == "/bill/118/hr/1/actions"
+
+
+def test_get_raw_params(mock_api, client, api_key):
+    """Test the conversion of parameter values to query strings."""
+    mock_api.add({})
+
+    client.get_raw("bill/118", {"limit": 5, "sort": "updateDate+desc", "flag": False, "skipped": None})
+
+    request = mock_api.requests[0]
+    assert request["path"] == "/bill/118"
+    assert request["query"] == {"limit": ["5"], "sort": ["updateDate+desc"], "flag": ["false"]}
+    assert request["headers"]["x-api-key"] == api_key
+
+
+def test_get_raw_raises_api_errors(mock_api, client):
+    """Test that get_raw maps failures onto the usual exceptions."""
+    from cdg_python_client import NotFoundError
+
+    mock_api.add({"error": "Unknown resource"}, status=404)
+
+    with pytest.raises(NotFoundError) as excinfo:
+        client.get_raw("/nope")
+    assert excinfo.value.endpoint == "/nope"
+
+
+def test_get_raw_is_retried(mock_api, make_client):
+    """Test that get_raw goes through the retry loop."""
+    mock_api.add({"error": "unavailable"}, status=503)
+    mock_api.add({"ok": True})
+    client = make_client(backoff_factor=0.0)
+
+    assert client.get_raw("/bill") == {"ok": True}
+    assert client.retry_stats().retries == 1
+
+
+@pytest.mark.parametrize("path", ["https://api.congress.gov/v3/bill", "/bill?limit=5"])
+def test_get_raw_rejects_urls_and_queries(client, path):
+    """Test that the path must be relative and carry no query string."""
+    with pytest.raises(ValueError, match="path must"):
+        client.get_raw(path)
+
+
+def test_typed_results_keep_raw_json(mock_api, client):
+    """Test that raw holds the full object, including unmodelled fields."""
+    mock_api.add(BILL)
+
+    bill = client.get_bill(118, "hr", 1)
+
+    assert bill.raw == BILL["bill"]
+    assert bill.raw["cboCostEstimates"][0]["title"] == "H.R. 1"
+    # Nested models leave the payload to their parent
+    assert bill.sponsors[0].raw is None
+
+
+def test_list_items_keep_raw_json(mock_api, client):
+    """Test that each list item carries its own object."""
+    bills = [{"congress": 118, "number": str(n), "type": "HR", "extra": n} for n in range(3)]
+    mock_api.add({"bills": bills, "pagination": {"count": 3}})
+
+    items = client.list_bills(limit=3)
+
+    assert [bill.raw["extra"] for bill in items] == [0, 1, 2]
+
+
+def test_async_get_raw(mock_api, async_client):
+    """Test get_raw on the async client."""
+    import asyncio
+
+    mock_api.add({"bills": [{"number": "1"}]})
+
+    async def run():
+        return await async_client.get_raw("/bill", {"limit": 1})
+
+    assert asyncio.run(run()) == {"bills": [{"number": "1"}]}
+    assert mock_api.requests[0]["query"] == {"limit": ["1"]}
@@ -0,0 +1,82 @@
+"""Tests for the client's retry configuration and counters."""
+
+import socket
+
+import pytest
+
+
+def _closed_port():
+    """Return a local port with nothing listening on it."""
+    with socket.socket(socket.AF_INET, socket.SOCK_STREAM) as sock:
+        sock.bind(("127.0.0.1", 0))
+        return sock.getsockname()[1]
+
+
+def test_retry_options_accepted():
+    """Test that the retry keyword arguments are accepted."""
+    from cdg_python_client import CDGPythonClient
+
+    client = CDGPythonClient(
+        api_key="test_key",
+        max_retries=5,
+        backoff_factor=0.1,
+        max_backoff=2.0,
+    )
+    assert client is not None
+
+
+@pytest.mark.parametrize("kwargs", [
+    {"backoff_factor": -1.0},
+    {"max_backoff": -0.5},
+    {"max_backoff": 1e300},
+    {"backoff_factor": float("nan")},
+])
+def test_invalid_retry_options_rejected(kwargs):
+    """Test that negative, non-finite or out-of-range delays raise ValueError."""
+    from cdg_python_client import CDGPythonClient
+
+    with pytest.raises(ValueError):
+        CDGPythonClient(api_key="test_key", **kwargs)
+
+
+def test_retry_stats_start_at_zero(client):
+    """Test that a fresh client reports no requests or retries."""
+    from cdg_python_client import RetryStats
+
+    stats = client.retry_stats()
+    assert isinstance(stats, RetryStats)
+    assert stats.requests == 0
+    assert stats.retries == 0
+    assert stats.exhausted == 0
+
+
+def test_connection_errors_are_retried(monkeypatch):
+    """Test that connection failures are retried up to max_retries."""
+    from cdg_python_client import CDGPythonClient, NetworkError
+
+    # Route all traffic through a proxy that refuses connections
+    monkeypatch.setenv("HTTPS_PROXY", f"http://127.0.0.1:{_closed_port()}")
+    client = CDGPythonClient(api_key="test_key", max_retries=2, backoff_factor=0.0)
+
+    with pytest.raises(NetworkError):
+        client.list_bills(limit=1)
+
+    stats = client.retry_stats()
+    assert stats.requests == 1
+    assert stats.retries == 2
+    assert stats.exhausted == 1
+
+
+def test_retries_can_be_disabled(monkeypatch):
+    """Test that max_retries=0 fails on the first attempt."""
+    from cdg_python_client import CDGPythonClient
+
+    monkeypatch.setenv("HTTPS_PROXY", f"http://127.0.0.1:{_closed_port()}")
+    client = CDGPythonClient(api_key="test_key", max_retries=0)
+
+    with pytest.raises(Exception):
+        client.list_bills(limit=1)
+
+    stats = client.retry_stats()
+    assert stats.requests == 1
+    assert stats.retries == 0
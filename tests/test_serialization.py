@@ -0,0 +1,114 @@
+"""Tests for to_dict/to_json, from_dict/from_json, pickling and equality."""
+
+import copy as copy_module
+import json
+import pickle
+
+import pytest
+
+
+BILL = {
+    "congress": 118,
+    "number": "1",
+    "type": "HR",
+    "introducedDate": "2023-03-14",
+    "latestAction": {"actionDate": "2023-03-30", "text": "Received in the Senate."},
+    "sponsors": [{"bioguideId": "S000522", "fullName": "Rep. Scalise, Steve [R-LA-1]"}],
+    "cboCostEstimates": [{"title": "H.R. 1"}],
+}
+
+
+def _bill(mock_api, client):
+    mock_api.add({"bill": BILL})
+    return client.get_bill(118, "hr", 1)
+
+
+def test_to_dict_uses_api_names(mock_api, client):
+    """Test that to_dict mirrors the API's JSON, extra fields included."""
+    data = _bill(mock_api, client).to_dict()
+
+    assert data["type"] == "HR"
+    assert data["introducedDate"] == "2023-03-14"
+    assert data["latestAction"] == {"actionDate": "2023-03-30", "text": "Received in the Senate."}
+    assert data["sponsors"][0]["bioguideId"] == "S000522"
+    assert data["cboCostEstimates"] == [{"title": "H.R. 1"}]
+    assert json.loads(json.dumps(data)) == data
+
+
+def test_round_trips(mock_api, client):
+    """Test that from_dict and from_json rebuild an equal model."""
+    from cdg_python_client import BillDetail
+
+    bill = _bill(mock_api, client)
+
+    assert BillDetail.from_dict(bill.to_dict()) == bill
+    assert BillDetail.from_json(bill.to_json()) == bill
+    assert BillDetail.from_json(bill.to_json(indent=2)) == bill
+    assert BillDetail.from_dict(BILL).raw == BILL
+
+
+def test_pickle_keeps_raw(mock_api, client):
+    """Test that unpickled models are equal and keep the original JSON."""
+    bill = _bill(mock_api, client)
+
+    copy = pickle.loads(pickle.dumps(bill))
+
+    assert copy == bill
+    assert copy.raw == BILL
+    assert pickle.loads(pickle.dumps(bill.latest_action)) == bill.latest_action
+    assert copy_module.deepcopy(bill).raw == BILL
+
+
+@pytest.mark.parametrize("copier", [copy_module.copy, copy_module.deepcopy])
+def test_copy_keeps_extra_and_raw(mock_api, client, copier):
+    """Test that copy.copy and copy.deepcopy keep extra fields and the original JSON."""
+    bill = _bill(mock_api, client)
+
+    copy = copier(bill)
+
+    assert copy == bill
+    assert copy is not bill
+    assert copy.extra == {"cboCostEstimates": [{"title": "H.R. 1"}]}
+    assert copy.raw == BILL
+    assert copier(bill.latest_action) == bill.latest_action
+
+
+def test_getstate_round_trips(mock_api, client):
+    """Test that __setstate__ restores what __getstate__ returned."""
+    from cdg_python_client import BillDetail
+
+    bill = _bill(mock_api, client)
+    empty = BillDetail.from_json("{}")
+
+    empty.__setstate__(bill.__getstate__())
+
+    assert empty == bill
+    assert empty.raw == BILL
+
+
+def test_equality_and_hash(mock_api):
+    """Test value equality, hashing and comparison with other types."""
+    from cdg_python_client import LatestAction
+
+    first = LatestAction.from_dict({"actionDate": "2023-03-30", "text": "Passed"})
+    second = LatestAction.from_json('{"text": "Passed", "actionDate": "2023-03-30"}')
+    other = LatestAction.from_dict({"actionDate": "2023-03-31", "text": "Passed"})
+
+    assert first == second
+    assert hash(first) == hash(second)
+    assert first != other
+    assert first != "Passed"
+    assert len({first, second, other}) == 2
+
+
+@pytest.mark.parametrize("method, data, match", [
+    ("from_dict", {"congress": "one hundred"}, "invalid BillDetail data"),
+    ("from_dict", {"congress": object()}, "cannot convert object to JSON"),
+    ("from_json", "{", "invalid JSON"),
+])
+def test_bad_input(method, data, match):
+    """Test that malformed input raises instead of building a partial model."""
+    from cdg_python_client import BillDetail
+
+    with pytest.raises((ValueError, TypeError), match=match):
+        getattr(BillDetail, method)(data)
@@ -0,0 +1,44 @@
+"""Tests for sharing one client across Python threads."""
+
+
+def test_thread_pool_requests_overlap(mock_api, client):
+    """Test that the GIL is released while a request is in flight."""
+    import time
+    from concurrent.futures import ThreadPoolExecutor
+
+    for _ in range(4):
+        mock_api.add({"bill": {"congress": 118, "number": "1", "type": "HR"}}, delay=0.5)
+
+    start = time.monotonic()
+    with ThreadPoolExecutor(max_workers=4) as pool:
+        bills = list(pool.map(lambda _: client.get_bill(118, "hr", 1), range(4)))
+
+    assert time.monotonic() - start < 1.5
+    assert [b.number for b in bills] == ["1"] * 4
+    assert client.retry_stats().requests == 4
+
+
+def test_other_threads_run_during_request(mock_api, client):
+    """Test that a Python thread makes progress while a call blocks."""
+    import threading
+
+    mock_api.add({"bills": []}, delay=0.5)
+    ticks = []
+    done = threading.Event()
+
+    def spin():
+        while not done.is_set():
+            ticks.append(None)
+            done.wait(0.01)
+
+    spinner = threading.Thread(target=spin)
+    spinner.start()
+    try:
+        before = len(ticks)
+        client.list_bills()
+        during = len(ticks) - before
+    finally:
+        done.set()
+        spinner.join()
+
+    assert during >= 10
@@ -0,0 +1,182 @@
+"""Tests for base URL, timeout, proxy, CA bundle and user agent options."""
+
+import socket
+
+import pytest
+
+
+def _closed_port():
+    """Return a local port with nothing listening on it."""
+    with socket.socket(socket.AF_INET, socket.SOCK_STREAM) as sock:
+        sock.bind(("127.0.0.1", 0))
+        return sock.getsockname()[1]
+
+
+def test_base_url_targets_mock_server(mock_api):
+    """Test that requests go to the configured base URL."""
+    from cdg_python_client import CDGPythonClient
+
+    mock_api.add({"bills": [{"congress": 118, "number": "1", "type": "HR"}]})
+    client = CDGPythonClient(api_key="test_key", base_url=mock_api.url + "/v3/")
+
+    bills = client.list_bills(limit=1)
+
+    assert len(bills) == 1
+    assert bills[0].congress == 118
+    request = mock_api.requests[0]
+    assert request["path"] == "/v3/bill"
+    assert request["query"]["limit"] == ["1"]
+
+
+def test_api_key_sent_as_header(mock_api, client, api_key):
+    """Test that the key travels in X-Api-Key and not in the query string."""
+    mock_api.add({"bills": []})
+    client.list_bills()
+
+    request = mock_api.requests[0]
+    assert request["headers"]["x-api-key"] == api_key
+    assert "api_key" not in request["query"]
+    assert api_key not in request["target"]
+
+
+def test_default_user_agent(mock_api, client):
+    """Test that the client identifies itself by default."""
+    mock_api.add({"bills": []})
+    client.list_bills()
+
+    assert mock_api.requests[0]["headers"]["user-agent"].startswith("cdg_python_client/")
+
+
+def test_custom_user_agent(mock_api):
+    """Test that user_agent overrides the default."""
+    from cdg_python_client import CDGPythonClient
+
+    mock_api.add({"bills": []})
+    client = CDGPythonClient(api_key="test_key", base_url=mock_api.url, user_agent="my-app/2.0")
+    client.list_bills()
+
+    assert mock_api.requests[0]["headers"]["user-agent"] == "my-app/2.0"
+
+
+def test_retry_after_honoured_against_mock(mock_api, client):
+    """Test that a 503 with Retry-After is retried and then succeeds."""
+    mock_api.add({"error": "busy"}, status=503, headers={"Retry-After": "0"})
+    mock_api.add({"bills": []})
+
+    assert client.list_bills() == []
+    assert len(mock_api.requests) == 2
+    assert client.retry_stats().retries == 1
+
+
+def test_timeout_raises_network_error(mock_api):
+    """Test that a slow response is abandoned after `timeout` seconds."""
+    from cdg_python_client import CDGPythonClient, NetworkError
+
+    mock_api.add({"bills": []}, delay=2.0)
+    client = CDGPythonClient(
+        api_key="test_key",
+        base_url=mock_api.url,
+        timeout=0.2,
+        max_retries=0,
+    )
+
+    with pytest.raises(NetworkError):
+        client.list_bills()
+
+
+def test_read_timeout_raises_network_error(mock_api):
+    """Test that a stalled response is abandoned after `read_timeout` seconds."""
+    from cdg_python_client import CDGPythonClient, NetworkError
+
+    mock_api.add({"bills": []}, delay=2.0)
+    client = CDGPythonClient(
+        api_key="test_key",
+        base_url=mock_api.url,
+        timeout=None,
+        read_timeout=0.2,
+        max_retries=0,
+    )
+
+    with pytest.raises(NetworkError):
+        client.list_bills()
+
+
+def test_explicit_proxy_is_used(mock_api):
+    """Test that plain HTTP requests are sent through the given proxy."""
+    from cdg_python_client import CDGPythonClient
+
+    mock_api.add({"bills": []})
+    client = CDGPythonClient(
+        api_key="test_key",
+        base_url="http://api.congress.invalid/v3",
+        proxy=mock_api.url,
+    )
+    client.list_bills()
+
+    # A forward proxy receives the absolute URL as the request target
+    assert mock_api.requests[0]["target"].startswith("http://api.congress.invalid/v3/bill")
+
+
+def test_explicit_proxy_overrides_environment(monkeypatch, mock_api):
+    """Test that the proxy argument takes precedence over HTTP_PROXY."""
+    from cdg_python_client import CDGPythonClient
+
+    monkeypatch.setenv("HTTP_PROXY", f"http://127.0.0.1:{_closed_port()}")
+    mock_api.add({"bills": []})
+    client = CDGPythonClient(
+        api_key="test_key",
+        base_url="http://api.congress.invalid/v3",
+        proxy=mock_api.url,
+        max_retries=0,
+    )
+
+    assert client.list_bills() == []
+
+
+@pytest.mark.parametrize("kwargs", [
+    {"base_url": "not a url"},
+    {"base_url": "ftp://api.congress.gov/v3"},
+    {"base_url": "https://api.congress.gov/v3?format=json"},
+    {"timeout": 0.0},
+    {"timeout": -1.0},
+    {"connect_timeout": float("inf")},
+    {"read_timeout": -1.0},
+    {"timeout": 1e300},
+    {"connect_timeout": 1e300},
+    {"read_timeout": 1e300},
+    {"proxy": "::not a proxy::"},
+    {"user_agent": "bad\nagent"},
+])
+def test_invalid_transport_options_rejected(kwargs):
+    """Test that invalid transport settings raise ValueError."""
+    from cdg_python_client import CDGPythonClient
+
+    with pytest.raises(ValueError):
+        CDGPythonClient(api_key="test_key", **kwargs)
+
+
+def test_timeouts_can_be_disabled():
+    """Test that None disables the timeouts."""
+    from cdg_python_client import CDGPythonClient
+
+    client = CDGPythonClient(api_key="test_key", timeout=None, connect_timeout=None, read_timeout=None)
+    assert client is not None
+
+
+def test_missing_ca_bundle_raises_os_error(tmp_path):
+    """Test that an unreadable CA bundle is reported as OSError."""
+    from cdg_python_client import CDGPythonClient
+
+    with pytest.raises(OSError, match="ca_bundle"):
+        CDGPythonClient(api_key="test_key", ca_bundle=str(tmp_path / "missing.pem"))
+
+
+def test_ca_bundle_without_certificates_rejected(tmp_path):
+    """Test that a CA bundle with no certificates raises ValueError."""
+    from cdg_python_client import CDGPythonClient
+
+    bundle = tmp_path / "empty.pem"
+    bundle.write_text("not a certificate\n")
+
+    with pytest.raises(ValueError, match="ca_bundle"):
+        CDGPythonClient(api_key="test_key", ca_bundle=bundle)
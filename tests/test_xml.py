@@ -0,0 +1,98 @@
+"""Tests decoding XML responses (format="xml") into the same models as JSON."""
+
+from pathlib import Path
+
+import pytest
+
+
+FIXTURES = Path(__file__).with_name("fixtures")
+
+
+def _fields(model):
+    """Declared attributes of a model, recursing into nested models.
+
+    `url` is left out: the API echoes the requested format in it.
+    """
+    if isinstance(model, list):
+        return [_fields(item) for item in model]
+    if not hasattr(model, "extra"):
+        return model
+    names = [n for n in dir(model) if not n.startswith("_") and n not in ("extra", "raw", "url")]
+    return {n: _fields(getattr(model, n)) for n in names if not callable(getattr(model, n))}
+
+
+def _decode_both(mock_api, client, fixture, call):
+    """Serve the JSON then the XML fixture and return both decodes."""
+    mock_api.add((FIXTURES / f"{fixture}.json").read_text())
+    mock_api.add((FIXTURES / f"{fixture}.xml").read_text(), headers={"Content-Type": "application/xml"})
+    return call(client, "json"), call(client, "xml")
+
+
+def test_bill_detail(mock_api, client):
+    """Test a detail response with nested objects, lists and numbers."""
+    from_json, from_xml = _decode_both(mock_api, client, "bill_118_hr_1", lambda c, _: c.get_bill(118, "hr", 1))
+
+    assert _fields(from_xml) == _fields(from_json)
+    assert from_xml.congress == 118
+    assert from_xml.sponsors[0].full_name == "Rep. Scalise, Steve [R-LA-1]"
+    assert from_xml.policy_area.name == "Energy"
+
+
+def test_bill_list(mock_api, client):
+    """Test a list response and its pagination block."""
+    from_json, from_xml = _decode_both(
+        mock_api, client, "bills_118", lambda c, fmt: c.list_bills_by_congress_page(118, format=fmt, limit=2)
+    )
+
+    assert _fields(list(from_xml)) == _fields(list(from_json))
+    assert [b.number for b in from_xml] == ["10545", "5620"]
+    assert from_xml.count == from_json.count == 19315
+    assert from_xml.next_offset == from_json.next_offset == 2
+    assert mock_api.requests[1]["query"]["format"] == ["xml"]
+
+
+def test_item_lists_and_booleans(mock_api, client):
+    """Test <item> lists and True/False text decoding into booleans."""
+    from_json, from_xml = _decode_both(
+        mock_api, client, "cosponsors_118_hr_1", lambda c, fmt: c.get_bill_cosponsors(118, "hr", 1, format=fmt)
+    )
+
+    assert _fields(from_xml) == _fields(from_json)
+    assert [c.is_original_cosponsor for c in from_xml] == [True, False]
+
+
+def test_extra_fields_are_text(mock_api, client):
+    """Test that undeclared XML fields are kept, as strings."""
+    _, from_xml = _decode_both(mock_api, client, "bill_118_hr_1", lambda c, _: c.get_bill(118, "hr", 1))
+
+    assert from_xml.sponsors[0].extra == {"district": "1", "isByRequest": "N"}
+
+
+def test_single_item_and_empty_lists(mock_api, client):
+    """Test that one-element and empty lists are still decoded as lists."""
+    mock_api.add("<api-root><bills><bill><number>7</number></bill></bills></api-root>")
+    mock_api.add("<api-root><bills/></api-root>")
+
+    assert [b.number for b in client.list_bills(format="xml")] == ["7"]
+    assert client.list_bills(format="xml") == []
+
+
+def test_get_raw_xml(mock_api, client):
+    """Test that get_raw converts XML into the JSON layout."""
+    mock_api.add((FIXTURES / "cosponsors_118_hr_1.xml").read_text())
+
+    data = client.get_raw("/bill/118/hr/1/cosponsors", {"format": "xml"})
+
+    assert [c["bioguideId"] for c in data["cosponsors"]] == ["E000246", "D000032"]
+    assert data["pagination"] == {"count": "2"}
+
+
+def test_malformed_xml_raises_decode_error(mock_api, client):
+    """Test that broken XML is reported like broken JSON."""
+    from cdg_python_client import DecodeError
+
+    mock_api.add("<api-root><bill>")
+
+    with pytest.raises(DecodeError, match="invalid XML") as excinfo:
+        client.get_bill(118, "hr", 1)
+    assert excinfo.value.body == "<api-root><bill>"